@@ -0,0 +1,130 @@
+// Copyright (c) 2024 Ken Barker
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"),
+// to deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+// THE SOFTWARE.
+
+//! Benchmarks the chunked batch conversion of nautical miles to metres
+//! against scalar loops that convert one value at a time.
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use icao_units::batch::{convert_in_place, convert_slice};
+use icao_units::non_si::NauticalMiles;
+use icao_units::si::Metres;
+
+const COUNT: u32 = 1_000_000;
+
+fn bench_conversion(c: &mut Criterion) {
+    let ranges: Vec<NauticalMiles> = (0..COUNT)
+        .map(|i| NauticalMiles(f64::from(i) * 0.001))
+        .collect();
+    let mut metres = vec![Metres(0.0); ranges.len()];
+
+    c.bench_function("convert_slice_scalar", |b| {
+        b.iter(|| {
+            for (r, m) in black_box(&ranges).iter().zip(metres.iter_mut()) {
+                *m = Metres::from(*r);
+            }
+        });
+    });
+
+    c.bench_function("convert_slice", |b| {
+        b.iter(|| convert_slice(black_box(&ranges), &mut metres));
+    });
+
+    let mut values: Vec<f64> = ranges.iter().map(|r| r.0).collect();
+    c.bench_function("convert_in_place_scalar", |b| {
+        b.iter(|| {
+            for value in black_box(&mut values).iter_mut() {
+                *value = Metres::from(NauticalMiles(*value)).0;
+            }
+        });
+    });
+
+    c.bench_function("convert_in_place", |b| {
+        b.iter(|| convert_in_place::<NauticalMiles, Metres>(black_box(&mut values)));
+    });
+
+    let small = &ranges[..1024];
+    let mut small_metres = vec![Metres(0.0); small.len()];
+    c.bench_function("convert_slice_scalar_in_cache", |b| {
+        b.iter(|| {
+            for (r, m) in black_box(small).iter().zip(small_metres.iter_mut()) {
+                *m = Metres::from(*r);
+            }
+        });
+    });
+
+    c.bench_function("convert_slice_in_cache", |b| {
+        b.iter(|| convert_slice(black_box(small), &mut small_metres));
+    });
+
+    let mut small_values = values[..1024].to_vec();
+    c.bench_function("convert_in_place_scalar_in_cache", |b| {
+        b.iter(|| {
+            for value in black_box(&mut small_values).iter_mut() {
+                *value = Metres::from(NauticalMiles(*value)).0;
+            }
+        });
+    });
+
+    c.bench_function("convert_in_place_in_cache", |b| {
+        b.iter(|| convert_in_place::<NauticalMiles, Metres>(black_box(&mut small_values)));
+    });
+}
+
+fn bench_division(c: &mut Criterion) {
+    let distances: Vec<f64> = (0..COUNT).map(|i| f64::from(i) * 1.852).collect();
+    let mut ranges = vec![0.0; distances.len()];
+
+    c.bench_function("divide", |b| {
+        b.iter(|| {
+            for (d, r) in black_box(&distances).iter().zip(ranges.iter_mut()) {
+                *r = d / 1852.0;
+            }
+        });
+    });
+
+    c.bench_function("multiply_reciprocal", |b| {
+        b.iter(|| {
+            for (d, r) in black_box(&distances).iter().zip(ranges.iter_mut()) {
+                *r = d * (1.0 / 1852.0);
+            }
+        });
+    });
+
+    let small = &distances[..1024];
+    let mut small_ranges = vec![0.0; small.len()];
+    c.bench_function("divide_in_cache", |b| {
+        b.iter(|| {
+            for (d, r) in black_box(small).iter().zip(small_ranges.iter_mut()) {
+                *r = d / 1852.0;
+            }
+        });
+    });
+
+    c.bench_function("multiply_reciprocal_in_cache", |b| {
+        b.iter(|| {
+            for (d, r) in black_box(small).iter().zip(small_ranges.iter_mut()) {
+                *r = d * (1.0 / 1852.0);
+            }
+        });
+    });
+}
+
+criterion_group!(benches, bench_conversion, bench_division);
+criterion_main!(benches);
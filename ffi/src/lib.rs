@@ -0,0 +1,146 @@
+// Copyright (c) 2024 Ken Barker
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"),
+// to deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+// THE SOFTWARE.
+
+//! A C foreign function interface (FFI) for the `icao-units` conversions.
+//!
+//! The unit types are `#[repr(transparent)]`, so they have the same
+//! layout and ABI as a C `double` and can be passed to and from C directly.
+//!
+//! The functions are in a separate crate because exporting them with
+//! `#[no_mangle]` is not allowed by the `unsafe_code = "forbid"` lint of
+//! the `icao-units` crate.
+//!
+//! A C header file can be generated with [cbindgen](https://crates.io/crates/cbindgen)
+//! using the `cbindgen.toml` file in this directory, e.g.:
+//!
+//! ```sh
+//! cbindgen --config cbindgen.toml --output icao_units.h
+//! ```
+//!
+//! And a static library can be built with:
+//!
+//! ```sh
+//! cargo build --release -p icao-units-ffi
+//! ```
+
+// The functions are not generated by a macro, so that cbindgen can parse them.
+// `#[no_mangle]` is required to export the functions with C linkage.
+#![allow(unsafe_code)]
+
+use icao_units::{non_si, si};
+
+/// Convert `NauticalMiles` to `Metres`.
+#[no_mangle]
+#[must_use]
+pub extern "C" fn icao_units_nautical_miles_to_metres(value: non_si::NauticalMiles) -> si::Metres {
+    si::Metres::from(value)
+}
+
+/// Convert `Metres` to `NauticalMiles`.
+#[no_mangle]
+#[must_use]
+pub extern "C" fn icao_units_metres_to_nautical_miles(value: si::Metres) -> non_si::NauticalMiles {
+    non_si::NauticalMiles::from(value)
+}
+
+/// Convert `Feet` to `Metres`.
+#[no_mangle]
+#[must_use]
+pub extern "C" fn icao_units_feet_to_metres(value: non_si::Feet) -> si::Metres {
+    si::Metres::from(value)
+}
+
+/// Convert `Metres` to `Feet`.
+#[no_mangle]
+#[must_use]
+pub extern "C" fn icao_units_metres_to_feet(value: si::Metres) -> non_si::Feet {
+    non_si::Feet::from(value)
+}
+
+/// Convert `Knots` to `MetresPerSecond`.
+#[no_mangle]
+#[must_use]
+pub extern "C" fn icao_units_knots_to_metres_per_second(
+    value: non_si::Knots,
+) -> si::MetresPerSecond {
+    si::MetresPerSecond::from(value)
+}
+
+/// Convert `MetresPerSecond` to `Knots`.
+#[no_mangle]
+#[must_use]
+pub extern "C" fn icao_units_metres_per_second_to_knots(
+    value: si::MetresPerSecond,
+) -> non_si::Knots {
+    non_si::Knots::from(value)
+}
+
+/// Convert `Degrees` to `Radians`.
+#[no_mangle]
+#[must_use]
+pub extern "C" fn icao_units_degrees_to_radians(value: non_si::Degrees) -> si::Radians {
+    si::Radians::from(value)
+}
+
+/// Convert `Radians` to `Degrees`.
+#[no_mangle]
+#[must_use]
+pub extern "C" fn icao_units_radians_to_degrees(value: si::Radians) -> non_si::Degrees {
+    non_si::Degrees::from(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ffi_conversions() {
+        assert_eq!(
+            si::Metres(1852.0),
+            icao_units_nautical_miles_to_metres(non_si::NauticalMiles(1.0))
+        );
+        assert_eq!(
+            non_si::NauticalMiles(1.0),
+            icao_units_metres_to_nautical_miles(si::Metres(1852.0))
+        );
+        assert_eq!(
+            si::Metres(0.3048),
+            icao_units_feet_to_metres(non_si::Feet(1.0))
+        );
+        assert_eq!(
+            non_si::Feet(0.999_999_999_999_999_9),
+            icao_units_metres_to_feet(si::Metres(0.3048))
+        );
+
+        let speed = icao_units_knots_to_metres_per_second(non_si::Knots(1.0));
+        assert_eq!(
+            si::MetresPerSecond(non_si::METRES_PER_SECOND_TO_KNOTS),
+            speed
+        );
+        assert_eq!(
+            non_si::Knots(1.0),
+            icao_units_metres_per_second_to_knots(speed)
+        );
+
+        let angle = icao_units_degrees_to_radians(non_si::Degrees(180.0));
+        assert_eq!(si::Radians(core::f64::consts::PI), angle);
+        assert_eq!(non_si::Degrees(180.0), icao_units_radians_to_degrees(angle));
+    }
+}
@@ -0,0 +1,138 @@
+// Copyright (c) 2024 Ken Barker
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"),
+// to deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+// THE SOFTWARE.
+
+//! ADS-B navigation quality indicators.
+//!
+//! ADS-B position and velocity reports include integer codes that bound the
+//! accuracy and integrity of the reported position and velocity. The
+//! functions convert the codes to typed bounds, as defined in RTCA DO-260B,
+//! so that surveillance data can be filtered by quality, e.g.:
+//!
+//! ```
+//! use icao_units::adsb::{nacp_accuracy, nic_containment_radius};
+//! use icao_units::si::Metres;
+//!
+//! assert_eq!(Some(Metres(30.0)), nacp_accuracy(9));
+//! assert_eq!(Some(Metres(185.2)), nic_containment_radius(8));
+//! // unknown
+//! assert_eq!(None, nacp_accuracy(0));
+//! ```
+//!
+//! Each bound is the upper limit of its range, e.g. `NACp` 9 means that the
+//! 95% horizontal accuracy bound (EPU) is less than 30 m.
+//! Unknown and reserved codes return `None`.
+
+use crate::si::{Metres, MetresPerSecond};
+
+/// The horizontal position accuracy bound, the 95% estimated position
+/// uncertainty (EPU), of a navigation accuracy category for position (`NACp`).
+#[must_use]
+pub const fn nacp_accuracy(nacp: u8) -> Option<Metres> {
+    match nacp {
+        1 => Some(Metres(18_520.0)), // 10 NM
+        2 => Some(Metres(7408.0)),   // 4 NM
+        3 => Some(Metres(3704.0)),   // 2 NM
+        4 => Some(Metres(1852.0)),   // 1 NM
+        5 => Some(Metres(926.0)),    // 0.5 NM
+        6 => Some(Metres(555.6)),    // 0.3 NM
+        7 => Some(Metres(185.2)),    // 0.1 NM
+        8 => Some(Metres(92.6)),     // 0.05 NM
+        9 => Some(Metres(30.0)),
+        10 => Some(Metres(10.0)),
+        11 => Some(Metres(3.0)),
+        _ => None,
+    }
+}
+
+/// The 95% horizontal velocity error bound of a navigation accuracy
+/// category for velocity (`NACv`).
+#[must_use]
+pub const fn nacv_accuracy(nacv: u8) -> Option<MetresPerSecond> {
+    match nacv {
+        1 => Some(MetresPerSecond(10.0)),
+        2 => Some(MetresPerSecond(3.0)),
+        3 => Some(MetresPerSecond(1.0)),
+        4 => Some(MetresPerSecond(0.3)),
+        _ => None,
+    }
+}
+
+/// The horizontal containment radius (Rc) of a navigation integrity
+/// category (NIC).
+///
+/// The NIC supplement bits reduce the radius of some codes, e.g. NIC 6 may
+/// be less than 0.3 NM. Without them, the largest radius of the code is
+/// returned, so the bound is conservative.
+#[must_use]
+pub const fn nic_containment_radius(nic: u8) -> Option<Metres> {
+    match nic {
+        1 => Some(Metres(37_040.0)), // 20 NM
+        2 => Some(Metres(14_816.0)), // 8 NM
+        3 => Some(Metres(7408.0)),   // 4 NM
+        4 => Some(Metres(3704.0)),   // 2 NM
+        5 => Some(Metres(1852.0)),   // 1 NM
+        6 => Some(Metres(1111.2)),   // 0.6 NM
+        7 => Some(Metres(370.4)),    // 0.2 NM
+        8 => Some(Metres(185.2)),    // 0.1 NM
+        9 => Some(Metres(75.0)),
+        10 => Some(Metres(25.0)),
+        11 => Some(Metres(7.5)),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::float_cmp)]
+mod tests {
+    use super::*;
+    use crate::non_si::NauticalMiles;
+
+    #[test]
+    fn test_nacp_accuracy() {
+        assert_eq!(None, nacp_accuracy(0));
+        assert_eq!(Some(Metres::from(NauticalMiles(10.0))), nacp_accuracy(1));
+        assert_eq!(Some(Metres(92.6)), nacp_accuracy(8));
+        assert_eq!(Some(Metres(3.0)), nacp_accuracy(11));
+        assert_eq!(None, nacp_accuracy(12));
+        // the bounds decrease as the accuracy increases
+        for nacp in 1..11 {
+            assert!(nacp_accuracy(nacp + 1) < nacp_accuracy(nacp));
+        }
+    }
+
+    #[test]
+    fn test_nacv_accuracy() {
+        assert_eq!(None, nacv_accuracy(0));
+        assert_eq!(Some(MetresPerSecond(10.0)), nacv_accuracy(1));
+        assert_eq!(Some(MetresPerSecond(0.3)), nacv_accuracy(4));
+        assert_eq!(None, nacv_accuracy(5));
+    }
+
+    #[test]
+    fn test_nic_containment_radius() {
+        assert_eq!(None, nic_containment_radius(0));
+        assert_eq!(Some(Metres(37_040.0)), nic_containment_radius(1));
+        assert_eq!(Some(Metres(7.5)), nic_containment_radius(11));
+        assert_eq!(None, nic_containment_radius(12));
+        for nic in 1..11 {
+            assert!(nic_containment_radius(nic + 1) < nic_containment_radius(nic));
+        }
+    }
+}
@@ -0,0 +1,366 @@
+// Copyright (c) 2024 Ken Barker
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"),
+// to deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+// THE SOFTWARE.
+
+//! Indicated, calibrated and true airspeeds and ground speed.
+//!
+//! The speeds are distinct types wrapping [`Knots`], so that an API can
+//! state which speed it expects and passing, e.g., an indicated airspeed
+//! where a true airspeed is expected is a type error. There is no `From`
+//! conversion between them: converting one to another depends on the
+//! instrument errors, the atmosphere or the wind, e.g.:
+//!
+//! ```
+//! use icao_units::airspeed::{GroundSpeed, TrueAirspeed};
+//! use icao_units::non_si::Knots;
+//!
+//! let tas = TrueAirspeed::new(Knots(250.0));
+//! let headwind = Knots(30.0);
+//! let gs = GroundSpeed::new(tas.knots() - headwind);
+//! assert_eq!(Knots(220.0), gs.knots());
+//! assert_eq!("TAS 250 kt", tas.to_string());
+//! ```
+//!
+//! A [`MachNumber`] converts to and from a calibrated airspeed at a static
+//! pressure, which gives the crossover altitude of a [`SpeedSchedule`] of a
+//! CAS and a Mach number, e.g.:
+//!
+//! ```
+//! # #[cfg(any(feature = "std", feature = "libm"))]
+//! # {
+//! use icao_units::airspeed::{MachNumber, SpeedSchedule, TargetSpeed};
+//! use icao_units::altitude::PressureAltitude;
+//! use icao_units::non_si::{Feet, Knots};
+//!
+//! let schedule = SpeedSchedule::new(Knots(300.0), MachNumber(0.78));
+//! assert_eq!(29_314.0, schedule.crossover_altitude().feet().0.round());
+//!
+//! let target = schedule.target_speed(PressureAltitude::new(Feet(35_000.0)));
+//! assert_eq!(TargetSpeed::Mach(MachNumber(0.78)), target);
+//! # }
+//! ```
+
+#[cfg(any(feature = "std", feature = "libm"))]
+use crate::altitude::PressureAltitude;
+use crate::non_si::Knots;
+use crate::si::MetresPerSecond;
+#[cfg(any(feature = "std", feature = "libm"))]
+use crate::si::{Kelvin, Pascals};
+#[cfg(any(feature = "std", feature = "libm"))]
+use crate::{isa, math};
+use core::fmt;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Defines a speed type wrapping `Knots`, displayed with the abbreviation
+/// `$abbrev`.
+macro_rules! speed_kind {
+    ($(#[$attr:meta])* $t:ident, $abbrev:literal) => {
+        $(#[$attr])*
+        #[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+        #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+        #[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+        #[repr(transparent)]
+        pub struct $t(pub Knots);
+
+        impl $t {
+            #[doc = concat!("Construct ", $abbrev, " from a speed in knots.")]
+            #[must_use]
+            pub const fn new(speed: Knots) -> Self {
+                Self(speed)
+            }
+
+            /// The speed in knots.
+            #[must_use]
+            pub const fn knots(self) -> Knots {
+                self.0
+            }
+        }
+
+        impl From<$t> for Knots {
+            fn from(a: $t) -> Self {
+                a.0
+            }
+        }
+
+        impl From<$t> for MetresPerSecond {
+            fn from(a: $t) -> Self {
+                Self::from(a.0)
+            }
+        }
+
+        impl fmt::Display for $t {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, concat!($abbrev, " "))?;
+                fmt::Display::fmt(&self.0, f)
+            }
+        }
+    };
+}
+
+speed_kind!(
+    /// Indicated airspeed (IAS): the airspeed shown by the airspeed
+    /// indicator, uncorrected for instrument and position errors.
+    IndicatedAirspeed,
+    "IAS"
+);
+
+speed_kind!(
+    /// Calibrated airspeed (CAS): indicated airspeed corrected for
+    /// instrument and position errors.
+    CalibratedAirspeed,
+    "CAS"
+);
+
+speed_kind!(
+    /// True airspeed (TAS): the speed of the aircraft relative to the air.
+    TrueAirspeed,
+    "TAS"
+);
+
+speed_kind!(
+    /// Ground speed (GS): the speed of the aircraft relative to the ground.
+    GroundSpeed,
+    "GS"
+);
+
+/// A Mach number: the ratio of the true airspeed to the local speed of
+/// sound.
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[repr(transparent)]
+pub struct MachNumber(pub f64);
+
+/// The impact pressure of subsonic flow at `mach` and static `pressure`.
+#[cfg(any(feature = "std", feature = "libm"))]
+fn impact_pressure(mach: f64, pressure: f64) -> f64 {
+    pressure * (math::powf(math::mul_add(0.2 * mach, mach, 1.0), 3.5) - 1.0)
+}
+
+/// The subsonic Mach number of an `impact_pressure` at static `pressure`.
+#[cfg(any(feature = "std", feature = "libm"))]
+fn mach(impact_pressure: f64, pressure: f64) -> f64 {
+    math::sqrt(5.0 * (math::powf(impact_pressure / pressure + 1.0, 2.0 / 7.0) - 1.0))
+}
+
+impl MachNumber {
+    /// The Mach number of a calibrated airspeed `cas` at a static `pressure`.
+    ///
+    /// The conversions assume subsonic flow.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[must_use]
+    pub fn from_cas(cas: CalibratedAirspeed, pressure: Pascals) -> Self {
+        let sea_level_speed_of_sound = isa::speed_of_sound(isa::SEA_LEVEL_TEMPERATURE);
+        let cas_mach = MetresPerSecond::from(cas).0 / sea_level_speed_of_sound.0;
+        let impact_pressure = impact_pressure(cas_mach, isa::SEA_LEVEL_PRESSURE.0);
+        Self(mach(impact_pressure, pressure.0))
+    }
+
+    /// The calibrated airspeed of the Mach number at a static `pressure`.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[must_use]
+    pub fn to_cas(self, pressure: Pascals) -> CalibratedAirspeed {
+        let sea_level_speed_of_sound = isa::speed_of_sound(isa::SEA_LEVEL_TEMPERATURE);
+        let impact_pressure = impact_pressure(self.0, pressure.0);
+        let cas_mach = mach(impact_pressure, isa::SEA_LEVEL_PRESSURE.0);
+        CalibratedAirspeed(Knots::from(MetresPerSecond(
+            cas_mach * sea_level_speed_of_sound.0,
+        )))
+    }
+
+    /// The true airspeed of the Mach number at a static air `temperature`.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[must_use]
+    pub fn to_tas(self, temperature: Kelvin) -> TrueAirspeed {
+        TrueAirspeed(Knots::from(MetresPerSecond(
+            self.0 * isa::speed_of_sound(temperature).0,
+        )))
+    }
+}
+
+impl fmt::Display for MachNumber {
+    /// Format as "M" and the Mach number, e.g. "M0.78".
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("M")?;
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+/// The target speed of a [`SpeedSchedule`] at an altitude.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum TargetSpeed {
+    /// A calibrated airspeed, below the crossover altitude.
+    Cas(CalibratedAirspeed),
+    /// A Mach number, at and above the crossover altitude.
+    Mach(MachNumber),
+}
+
+impl fmt::Display for TargetSpeed {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Cas(cas) => fmt::Display::fmt(cas, f),
+            Self::Mach(mach) => fmt::Display::fmt(mach, f),
+        }
+    }
+}
+
+/// A climb or descent speed schedule of a calibrated airspeed and a Mach
+/// number, e.g. 300 kt / M0.78.
+///
+/// The CAS is flown below the crossover altitude, at which the CAS and the
+/// Mach number give the same true airspeed, and the Mach number above it.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SpeedSchedule {
+    /// The calibrated airspeed.
+    pub cas: Knots,
+    /// The Mach number.
+    pub mach: MachNumber,
+}
+
+impl SpeedSchedule {
+    /// Construct a speed schedule.
+    #[must_use]
+    pub const fn new(cas: Knots, mach: MachNumber) -> Self {
+        Self { cas, mach }
+    }
+
+    /// The pressure altitude at which the CAS and the Mach number give the
+    /// same true airspeed.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[must_use]
+    pub fn crossover_altitude(self) -> PressureAltitude {
+        let sea_level_speed_of_sound = isa::speed_of_sound(isa::SEA_LEVEL_TEMPERATURE);
+        let cas_mach = MetresPerSecond::from(self.cas).0 / sea_level_speed_of_sound.0;
+        let cas_impact_pressure = impact_pressure(cas_mach, isa::SEA_LEVEL_PRESSURE.0);
+        // the static pressure at which the Mach number has the same impact pressure
+        let pressure = cas_impact_pressure / impact_pressure(self.mach.0, 1.0);
+        PressureAltitude::from_pressure(Pascals(pressure))
+    }
+
+    /// The target speed at a pressure `altitude`.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[must_use]
+    pub fn target_speed(self, altitude: PressureAltitude) -> TargetSpeed {
+        if altitude < self.crossover_altitude() {
+            TargetSpeed::Cas(CalibratedAirspeed(self.cas))
+        } else {
+            TargetSpeed::Mach(self.mach)
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::float_cmp)]
+mod tests {
+    use super::*;
+    #[cfg(any(feature = "std", feature = "libm"))]
+    use crate::non_si::Feet;
+    #[cfg(any(feature = "std", feature = "libm"))]
+    use crate::si::Metres;
+    use crate::ConvertTo;
+
+    #[test]
+    fn test_speed_kinds() {
+        let ias = IndicatedAirspeed::new(Knots(140.0));
+        assert_eq!(Knots(140.0), ias.knots());
+        assert_eq!(Knots(140.0), Knots::from(ias));
+        assert_eq!(
+            MetresPerSecond::from(Knots(140.0)),
+            MetresPerSecond::from(ias)
+        );
+        assert!(ias < IndicatedAirspeed::new(Knots(150.0)));
+        assert_eq!("IAS 140 kt", ias.to_string());
+        assert_eq!(
+            "CAS 141.5 kt",
+            format!("{:.1}", CalibratedAirspeed::new(Knots(141.54)))
+        );
+        assert_eq!("GS 0 kt", GroundSpeed::default().to_string());
+
+        // usable where a speed is generic
+        let tas = TrueAirspeed::new(Knots(250.0));
+        let speed: MetresPerSecond = tas.convert();
+        assert_eq!(MetresPerSecond::from(Knots(250.0)), speed);
+
+        #[cfg(feature = "serde")]
+        {
+            let serialized = serde_json::to_string(&tas).unwrap();
+            assert_eq!("250.0", serialized);
+            let deserialized: TrueAirspeed = serde_json::from_str(&serialized).unwrap();
+            assert_eq!(tas, deserialized);
+        }
+    }
+
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[test]
+    fn test_mach_number() {
+        let cas = CalibratedAirspeed::new(Knots(300.0));
+        let pressure = isa::pressure(Metres::from(Feet(25_000.0)));
+        let mach = MachNumber::from_cas(cas, pressure);
+        assert!((mach.0 - 0.716_875).abs() < 1e-6);
+        let result = mach.to_cas(pressure);
+        assert!((result.knots().0 - 300.0).abs() < 1e-9);
+
+        // at sea level in the ISA, the CAS is the TAS
+        let mach = MachNumber::from_cas(cas, isa::SEA_LEVEL_PRESSURE);
+        let tas = mach.to_tas(isa::SEA_LEVEL_TEMPERATURE);
+        assert!((tas.knots().0 - 300.0).abs() < 1e-9);
+
+        assert_eq!("M0.78", MachNumber(0.78).to_string());
+        assert_eq!("M0.8", format!("{:.1}", MachNumber(0.78)));
+    }
+
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[test]
+    fn test_speed_schedule() {
+        let schedule = SpeedSchedule::new(Knots(300.0), MachNumber(0.78));
+        let crossover = schedule.crossover_altitude();
+        assert!((crossover.feet().0 - 29_314.1).abs() < 0.1);
+
+        // the CAS and the Mach number give the same TAS at the crossover altitude
+        let pressure = crossover.pressure();
+        let mach = MachNumber::from_cas(CalibratedAirspeed::new(schedule.cas), pressure);
+        assert!((mach.0 - 0.78).abs() < 1e-12);
+
+        assert_eq!(
+            TargetSpeed::Cas(CalibratedAirspeed::new(Knots(300.0))),
+            schedule.target_speed(PressureAltitude::new(Feet(10_000.0)))
+        );
+        assert_eq!(
+            TargetSpeed::Mach(MachNumber(0.78)),
+            schedule.target_speed(PressureAltitude::new(Feet(29_500.0)))
+        );
+        assert_eq!(
+            "CAS 300 kt",
+            schedule
+                .target_speed(PressureAltitude::new(Feet(29_000.0)))
+                .to_string()
+        );
+        assert_eq!("M0.78", TargetSpeed::Mach(MachNumber(0.78)).to_string());
+
+        #[cfg(feature = "serde")]
+        {
+            let serialized = serde_json::to_string(&schedule).unwrap();
+            assert_eq!(r#"{"cas":300.0,"mach":0.78}"#, serialized);
+            let deserialized: SpeedSchedule = serde_json::from_str(&serialized).unwrap();
+            assert_eq!(schedule, deserialized);
+        }
+    }
+}
@@ -0,0 +1,225 @@
+// Copyright (c) 2024 Ken Barker
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"),
+// to deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+// THE SOFTWARE.
+
+//! Altimeter settings, as reported in METAR and ATIS messages.
+//!
+//! Most states report altimeter settings in hectopascals with a Q code,
+//! e.g. "Q1013", but the United States and Canada report them in inches of
+//! mercury with an A code, e.g. "A2992". An [`AltimeterSetting`] keeps the
+//! unit of its source and converts between them, e.g.:
+//!
+//! ```
+//! use icao_units::altimeter::AltimeterSetting;
+//! use icao_units::non_si::{Hectopascals, InchesOfMercury};
+//!
+//! let setting = AltimeterSetting::from(InchesOfMercury(29.92));
+//! assert_eq!("A2992", setting.to_string());
+//! assert_eq!(1_013.0, setting.hectopascals().0.round());
+//!
+//! let setting = AltimeterSetting::from(Hectopascals(1_013.25));
+//! assert_eq!("Q1013", setting.to_string());
+//! assert_eq!(29.92, (setting.inches_of_mercury().0 * 100.0).round() / 100.0);
+//! ```
+//!
+//! The [`qnh`] and [`qff`] functions reduce the pressure at an aerodrome
+//! (QFE) to mean sea level.
+
+#[cfg(any(feature = "std", feature = "libm"))]
+use crate::isa;
+use crate::math;
+use crate::non_si::{Hectopascals, InchesOfMercury};
+use crate::si::Pascals;
+#[cfg(any(feature = "std", feature = "libm"))]
+use crate::si::{Celsius, Kelvin, Metres, MetresPerSecondSquared};
+use core::fmt;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// An altimeter setting in the unit of its source.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum AltimeterSetting {
+    /// A setting in hectopascals, reported with a Q code.
+    Hectopascals(Hectopascals),
+    /// A setting in inches of mercury, reported with an A code.
+    InchesOfMercury(InchesOfMercury),
+}
+
+impl AltimeterSetting {
+    /// The setting as a pressure.
+    #[must_use]
+    pub fn pressure(self) -> Pascals {
+        match self {
+            Self::Hectopascals(setting) => setting.into(),
+            Self::InchesOfMercury(setting) => setting.into(),
+        }
+    }
+
+    /// Whether the setting is the standard pressure to within half of its
+    /// reporting resolution, i.e. 1 hPa or 0.01 inHg.
+    #[must_use]
+    pub fn is_standard(self) -> bool {
+        match self {
+            Self::Hectopascals(setting) => {
+                math::abs(setting.0 - Hectopascals::STANDARD_PRESSURE.0) < 0.5
+            }
+            Self::InchesOfMercury(setting) => {
+                math::abs(setting.0 - InchesOfMercury::STANDARD_PRESSURE.0) < 0.005
+            }
+        }
+    }
+
+    /// The setting in hectopascals.
+    #[must_use]
+    pub fn hectopascals(self) -> Hectopascals {
+        match self {
+            Self::Hectopascals(setting) => setting,
+            Self::InchesOfMercury(_) => self.pressure().into(),
+        }
+    }
+
+    /// The setting in inches of mercury.
+    #[must_use]
+    pub fn inches_of_mercury(self) -> InchesOfMercury {
+        match self {
+            Self::Hectopascals(_) => self.pressure().into(),
+            Self::InchesOfMercury(setting) => setting,
+        }
+    }
+}
+
+impl From<Hectopascals> for AltimeterSetting {
+    fn from(a: Hectopascals) -> Self {
+        Self::Hectopascals(a)
+    }
+}
+
+impl From<InchesOfMercury> for AltimeterSetting {
+    fn from(a: InchesOfMercury) -> Self {
+        Self::InchesOfMercury(a)
+    }
+}
+
+impl From<AltimeterSetting> for Pascals {
+    fn from(a: AltimeterSetting) -> Self {
+        a.pressure()
+    }
+}
+
+impl fmt::Display for AltimeterSetting {
+    /// Formats the setting as a METAR code: whole hectopascals after a "Q",
+    /// e.g. "Q1013", or hundredths of an inch of mercury after an "A",
+    /// e.g. "A2992".
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Hectopascals(setting) => write!(f, "Q{:04.0}", math::round(setting.0)),
+            Self::InchesOfMercury(setting) => {
+                write!(f, "A{:04.0}", math::round(setting.0 * 100.0))
+            }
+        }
+    }
+}
+
+/// The QNH of an aerodrome at `elevation` with a station pressure `qfe`:
+/// the pressure that an altimeter must be set to in order to indicate the
+/// elevation on the ground, calculated with the ISA.
+#[cfg(any(feature = "std", feature = "libm"))]
+#[must_use]
+pub fn qnh(qfe: Hectopascals, elevation: Metres) -> Hectopascals {
+    let altitude = isa::pressure_altitude(qfe.into()) - elevation;
+    Hectopascals::from(isa::pressure(altitude))
+}
+
+/// The QFF of an aerodrome at `elevation` with a station pressure `qfe` and
+/// air `temperature`, e.g. from a METAR, as used for surface weather charts.
+///
+/// The pressure is reduced to mean sea level through a fictitious column of
+/// air at the station temperature, increasing below the station at the ISA
+/// lapse rate.
+#[cfg(any(feature = "std", feature = "libm"))]
+#[must_use]
+pub fn qff(qfe: Hectopascals, elevation: Metres, temperature: Celsius) -> Hectopascals {
+    let temperature = Kelvin::from(temperature);
+    // the mean temperature of the column, halfway between the station and sea level
+    let mean_temperature =
+        math::mul_add(-0.5 * isa::LAYERS[0].lapse_rate, elevation.0, temperature.0);
+    let exponent = MetresPerSecondSquared::STANDARD_GRAVITY.0 * elevation.0
+        / (isa::SPECIFIC_GAS_CONSTANT * mean_temperature);
+    Hectopascals(qfe.0 * math::exp(exponent))
+}
+
+#[cfg(test)]
+#[allow(clippy::float_cmp)]
+mod tests {
+    use super::*;
+    use crate::non_si::Millibars;
+
+    #[test]
+    fn test_altimeter_setting() {
+        let q = AltimeterSetting::from(Hectopascals(998.0));
+        assert_eq!(Hectopascals(998.0), q.hectopascals());
+        assert_eq!(Pascals(99_800.0), q.pressure());
+        assert_eq!(Pascals(99_800.0), q.into());
+        assert_eq!("Q0998", q.to_string());
+        assert!(!q.is_standard());
+        assert!(AltimeterSetting::from(Hectopascals::STANDARD_PRESSURE).is_standard());
+        assert!(AltimeterSetting::from(InchesOfMercury::STANDARD_PRESSURE).is_standard());
+        assert!(
+            AltimeterSetting::from(Hectopascals::from(Millibars::STANDARD_PRESSURE)).is_standard()
+        );
+        assert!(!AltimeterSetting::from(InchesOfMercury(29.93)).is_standard());
+        assert_eq!(
+            "A2947",
+            AltimeterSetting::from(q.inches_of_mercury()).to_string()
+        );
+
+        let a = AltimeterSetting::from(InchesOfMercury(30.12));
+        assert_eq!(InchesOfMercury(30.12), a.inches_of_mercury());
+        assert_eq!("A3012", a.to_string());
+        assert_eq!(
+            "Q1020",
+            AltimeterSetting::from(a.hectopascals()).to_string()
+        );
+
+        #[cfg(feature = "serde")]
+        {
+            let serialized = serde_json::to_string(&q).unwrap();
+            assert_eq!("{\"Hectopascals\":998.0}", serialized);
+            let deserialized: AltimeterSetting = serde_json::from_str(&serialized).unwrap();
+            assert_eq!(q, deserialized);
+        }
+    }
+
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[test]
+    fn test_qnh_and_qff() {
+        let qfe = Hectopascals(1_000.0);
+        let elevation = Metres(110.0);
+        assert_eq!(1_013.14, (qnh(qfe, elevation).0 * 100.0).round() / 100.0);
+        assert!((qfe - qnh(qfe, Metres(0.0))).0.abs() < 1e-9);
+
+        // QFF is lower than QNH on a warm day and higher on a cold day
+        let warm = qff(qfe, elevation, Celsius(25.0));
+        assert_eq!(1_012.67, (warm.0 * 100.0).round() / 100.0);
+        let cold = qff(qfe, elevation, Celsius(-10.0));
+        assert_eq!(1_014.36, (cold.0 * 100.0).round() / 100.0);
+        assert_eq!(qfe, qff(qfe, Metres(0.0), Celsius(27.0)));
+    }
+}
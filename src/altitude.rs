@@ -0,0 +1,351 @@
+// Copyright (c) 2024 Ken Barker
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"),
+// to deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+// THE SOFTWARE.
+
+//! Pressure, density, indicated and geometric altitudes.
+//!
+//! The altitudes are distinct types wrapping [`Feet`], so that an API can
+//! state which altitude reference it expects and, e.g., a geometric GNSS
+//! height cannot be passed where a pressure altitude is required. They are
+//! converted with explicit functions, using the altimeter setting (QNH) or
+//! the ICAO Standard Atmosphere (ISA), e.g.:
+//!
+//! ```
+//! # #[cfg(any(feature = "std", feature = "libm"))]
+//! # {
+//! use icao_units::altitude::IndicatedAltitude;
+//! use icao_units::non_si::Feet;
+//! use icao_units::si::Pascals;
+//!
+//! // QNH 1013.25 hPa is the ISA mean sea level pressure
+//! let indicated = IndicatedAltitude::new(Feet(5_000.0));
+//! let pressure_altitude = indicated.to_pressure_altitude(Pascals(101_325.0));
+//! assert_eq!(Feet(5_000.0), pressure_altitude.feet());
+//!
+//! // the pressure altitude is higher when QNH is lower
+//! let pressure_altitude = indicated.to_pressure_altitude(Pascals(100_000.0));
+//! assert_eq!(5_364.0, pressure_altitude.feet().0.round());
+//! # }
+//! ```
+//!
+//! The `approximate_` functions convert between pressure and height
+//! differences with the rules of thumb of 27 or 28 ft per hectopascal,
+//! whereas the `isa_` functions use the ISA at a given pressure.
+
+use crate::isa;
+use crate::non_si::{Feet, Hectopascals};
+use crate::si::Metres;
+#[cfg(any(feature = "std", feature = "libm"))]
+use crate::si::{Celsius, Kelvin, KilogramsPerCubicMetre, Pascals};
+use core::fmt;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Defines an altitude type wrapping `Feet`.
+macro_rules! altitude_kind {
+    ($(#[$attr:meta])* $t:ident) => {
+        $(#[$attr])*
+        #[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+        #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+        #[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+        #[repr(transparent)]
+        pub struct $t(pub Feet);
+
+        impl $t {
+            /// Construct the altitude from feet.
+            #[must_use]
+            pub const fn new(altitude: Feet) -> Self {
+                Self(altitude)
+            }
+
+            /// The altitude in feet.
+            #[must_use]
+            pub const fn feet(self) -> Feet {
+                self.0
+            }
+        }
+
+        impl From<$t> for Feet {
+            fn from(a: $t) -> Self {
+                a.0
+            }
+        }
+
+        impl From<$t> for Metres {
+            fn from(a: $t) -> Self {
+                Self::from(a.0)
+            }
+        }
+
+        impl fmt::Display for $t {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::Display::fmt(&self.0, f)
+            }
+        }
+    };
+}
+
+altitude_kind!(
+    /// Pressure altitude: the ISA altitude of the static pressure, shown by
+    /// an altimeter set to 1013.25 hPa. Flight levels are pressure altitudes.
+    PressureAltitude
+);
+
+altitude_kind!(
+    /// Density altitude: the ISA altitude of the air density, which
+    /// determines aircraft performance.
+    DensityAltitude
+);
+
+altitude_kind!(
+    /// Indicated altitude: the altitude shown by an altimeter set to the
+    /// local altimeter setting (QNH).
+    IndicatedAltitude
+);
+
+altitude_kind!(
+    /// Geometric height above mean sea level, e.g. from GNSS.
+    GeometricHeight
+);
+
+impl PressureAltitude {
+    /// The pressure altitude of a static `pressure`.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[must_use]
+    pub fn from_pressure(pressure: Pascals) -> Self {
+        Self(Feet::from(isa::pressure_altitude(pressure)))
+    }
+
+    /// The ISA static pressure at the pressure altitude.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[must_use]
+    pub fn pressure(self) -> Pascals {
+        isa::pressure(Metres::from(self.0))
+    }
+
+    /// The altitude indicated by an altimeter set to `qnh`.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[must_use]
+    pub fn to_indicated_altitude(self, qnh: Pascals) -> IndicatedAltitude {
+        IndicatedAltitude(self.0 - Feet::from(isa::pressure_altitude(qnh)))
+    }
+
+    /// The density altitude at the pressure altitude and the outside air
+    /// `temperature`, e.g. from a METAR.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[must_use]
+    pub fn to_density_altitude(self, temperature: Celsius) -> DensityAltitude {
+        let temperature = Kelvin::from(temperature);
+        let density = self.pressure().0 / (isa::SPECIFIC_GAS_CONSTANT * temperature.0);
+        DensityAltitude(Feet::from(isa::density_altitude(KilogramsPerCubicMetre(
+            density,
+        ))))
+    }
+
+    /// The geometric height of the pressure altitude in the ISA.
+    ///
+    /// The actual geometric height differs with the sea level pressure and
+    /// the temperature of the atmosphere.
+    #[must_use]
+    pub fn to_isa_geometric_height(self) -> GeometricHeight {
+        GeometricHeight(Feet::from(isa::geometric_altitude(Metres::from(self.0))))
+    }
+}
+
+impl IndicatedAltitude {
+    /// The pressure altitude of the indicated altitude of an altimeter
+    /// set to `qnh`.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[must_use]
+    pub fn to_pressure_altitude(self, qnh: Pascals) -> PressureAltitude {
+        PressureAltitude(self.0 + Feet::from(isa::pressure_altitude(qnh)))
+    }
+}
+
+impl GeometricHeight {
+    /// The pressure altitude of the geometric height in the ISA.
+    ///
+    /// The actual pressure altitude differs with the sea level pressure and
+    /// the temperature of the atmosphere.
+    #[must_use]
+    pub fn to_isa_pressure_altitude(self) -> PressureAltitude {
+        PressureAltitude(Feet::from(isa::geopotential_altitude(Metres::from(self.0))))
+    }
+}
+
+/// The rule of thumb height of a hectopascal near mean sea level: 27 ft.
+pub const FEET_PER_HECTOPASCAL_AT_SEA_LEVEL: f64 = 27.0;
+
+/// The rule of thumb height of a hectopascal at low altitudes, about the ISA
+/// value at 1 000 ft: 28 ft.
+pub const FEET_PER_HECTOPASCAL_AT_LOW_ALTITUDE: f64 = 28.0;
+
+/// The approximate height difference of a pressure `difference`, using a
+/// rule of thumb height per hectopascal, e.g.
+/// [`FEET_PER_HECTOPASCAL_AT_SEA_LEVEL`].
+#[must_use]
+pub fn approximate_height_difference(difference: Hectopascals, feet_per_hectopascal: f64) -> Feet {
+    Feet(difference.0 * feet_per_hectopascal)
+}
+
+/// The approximate pressure difference of a height `difference`, using a
+/// rule of thumb height per hectopascal, e.g.
+/// [`FEET_PER_HECTOPASCAL_AT_SEA_LEVEL`].
+#[must_use]
+pub fn approximate_pressure_difference(
+    difference: Feet,
+    feet_per_hectopascal: f64,
+) -> Hectopascals {
+    Hectopascals(difference.0 / feet_per_hectopascal)
+}
+
+/// The ISA height difference from `pressure` to a pressure `difference`
+/// below it, i.e. the height gained by climbing until the pressure has
+/// fallen by `difference`.
+///
+/// The height of a hectopascal increases with altitude, so the result
+/// depends upon `pressure` as well as `difference`.
+#[cfg(any(feature = "std", feature = "libm"))]
+#[must_use]
+pub fn isa_height_difference(pressure: Hectopascals, difference: Hectopascals) -> Feet {
+    Feet::from(
+        isa::pressure_altitude((pressure - difference).into())
+            - isa::pressure_altitude(pressure.into()),
+    )
+}
+
+/// The ISA pressure difference from `pressure` to a height `difference`
+/// above it, the inverse of [`isa_height_difference`].
+#[cfg(any(feature = "std", feature = "libm"))]
+#[must_use]
+pub fn isa_pressure_difference(pressure: Hectopascals, difference: Feet) -> Hectopascals {
+    let altitude = isa::pressure_altitude(pressure.into()) + Metres::from(difference);
+    pressure - Hectopascals::from(isa::pressure(altitude))
+}
+
+#[cfg(test)]
+#[allow(clippy::float_cmp)]
+mod tests {
+    use super::*;
+    #[cfg(any(feature = "std", feature = "libm"))]
+    use crate::si::KelvinDelta;
+
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[test]
+    fn test_pressure_and_indicated_altitude() {
+        let pressure_altitude = PressureAltitude::new(Feet(10_000.0));
+        assert_eq!(Feet(10_000.0), pressure_altitude.feet());
+        assert_eq!(Feet(10_000.0), Feet::from(pressure_altitude));
+        assert_eq!(Metres(3048.0), Metres::from(pressure_altitude));
+        assert_eq!("10000 ft", pressure_altitude.to_string());
+
+        let pressure = pressure_altitude.pressure();
+        assert!((69_681.7 - pressure.0).abs() < 0.1);
+        let result = PressureAltitude::from_pressure(pressure);
+        assert!((result.feet().0 - 10_000.0).abs() < 1e-8);
+
+        // about 27 ft per hPa near sea level
+        let qnh = Pascals(102_325.0);
+        let indicated = pressure_altitude.to_indicated_altitude(qnh);
+        assert_eq!(10_272.0, indicated.feet().0.round());
+        let result = indicated.to_pressure_altitude(qnh);
+        assert!((result.feet().0 - 10_000.0).abs() < 1e-9);
+        assert_eq!(
+            Feet(10_000.0),
+            pressure_altitude
+                .to_indicated_altitude(isa::SEA_LEVEL_PRESSURE)
+                .feet()
+        );
+    }
+
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[test]
+    fn test_density_altitude() {
+        let pressure_altitude = PressureAltitude::new(Feet(5_000.0));
+        let isa_temperature = Celsius::from(isa::temperature(Metres::from(Feet(5_000.0))));
+        let density_altitude = pressure_altitude.to_density_altitude(isa_temperature);
+        assert!((density_altitude.feet().0 - 5_000.0).abs() < 1e-6);
+
+        // ISA + 20 °C adds over 110 ft per °C
+        let hot = isa_temperature + KelvinDelta(20.0);
+        let density_altitude = pressure_altitude.to_density_altitude(hot);
+        assert_eq!(7_272.0, density_altitude.feet().0.round());
+
+        // above the tropopause at 36 089 ft
+        let pressure_altitude = PressureAltitude::new(Feet(41_000.0));
+        let isa_temperature = Celsius::from(isa::temperature(Metres::from(Feet(41_000.0))));
+        let density_altitude = pressure_altitude.to_density_altitude(isa_temperature);
+        assert!((density_altitude.feet().0 - 41_000.0).abs() < 1e-6);
+
+        // ISA + 10 °C adds R T / g ln(226.65 / 216.65) = 286 m in the isothermal layer
+        let hot = isa_temperature + KelvinDelta(10.0);
+        let density_altitude = pressure_altitude.to_density_altitude(hot);
+        assert_eq!(41_939.0, density_altitude.feet().0.round());
+    }
+
+    #[test]
+    fn test_geometric_height() {
+        let height = GeometricHeight::new(Feet(36_000.0));
+        let pressure_altitude = height.to_isa_pressure_altitude();
+        assert!(pressure_altitude < PressureAltitude::new(Feet(36_000.0)));
+        let result = pressure_altitude.to_isa_geometric_height();
+        assert!((result.feet().0 - 36_000.0).abs() < 1e-8);
+
+        #[cfg(feature = "serde")]
+        {
+            let serialized = serde_json::to_string(&height).unwrap();
+            assert_eq!("36000.0", serialized);
+            let deserialized: GeometricHeight = serde_json::from_str(&serialized).unwrap();
+            assert_eq!(height, deserialized);
+        }
+    }
+
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[test]
+    fn test_pressure_lapse() {
+        let difference = Hectopascals(10.0);
+        assert_eq!(
+            Feet(270.0),
+            approximate_height_difference(difference, FEET_PER_HECTOPASCAL_AT_SEA_LEVEL)
+        );
+        assert_eq!(
+            Feet(280.0),
+            approximate_height_difference(difference, FEET_PER_HECTOPASCAL_AT_LOW_ALTITUDE)
+        );
+        assert_eq!(
+            difference,
+            approximate_pressure_difference(Feet(270.0), FEET_PER_HECTOPASCAL_AT_SEA_LEVEL)
+        );
+
+        let standard = Hectopascals::STANDARD_PRESSURE;
+        let height = isa_height_difference(standard, difference);
+        assert_eq!(274.2, (height.0 * 10.0).round() / 10.0);
+        // a hectopascal is higher at lower pressures
+        let height = isa_height_difference(Hectopascals(977.2), Hectopascals(1.0));
+        assert_eq!(28.1, (height.0 * 10.0).round() / 10.0);
+
+        let pressure = isa_pressure_difference(standard, Feet(1_000.0));
+        assert_eq!(36.08, (pressure.0 * 100.0).round() / 100.0);
+        let pressure = isa_pressure_difference(standard, Feet(-1_000.0));
+        assert_eq!(-37.16, (pressure.0 * 100.0).round() / 100.0);
+        let round_trip =
+            isa_height_difference(standard, isa_pressure_difference(standard, Feet(500.0)));
+        assert!((Feet(500.0) - round_trip).0.abs() < 1e-6);
+    }
+}
@@ -0,0 +1,107 @@
+// Copyright (c) 2024 Ken Barker
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"),
+// to deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+// THE SOFTWARE.
+
+//! Conversions between the angle types and the
+//! [angle-sc](https://crates.io/crates/angle-sc) crate types.
+
+use crate::{non_si, si};
+use ::angle_sc::{Angle, Degrees, Radians};
+
+impl From<non_si::Degrees> for Degrees<f64> {
+    fn from(a: non_si::Degrees) -> Self {
+        Self(a.0)
+    }
+}
+
+impl From<Degrees<f64>> for non_si::Degrees {
+    fn from(a: Degrees<f64>) -> Self {
+        Self::new(a.0)
+    }
+}
+
+impl From<si::Radians> for Radians<f64> {
+    fn from(a: si::Radians) -> Self {
+        Self(a.0)
+    }
+}
+
+impl From<Radians<f64>> for si::Radians {
+    fn from(a: Radians<f64>) -> Self {
+        Self::new(a.0)
+    }
+}
+
+impl From<non_si::Degrees> for Angle<f64> {
+    fn from(a: non_si::Degrees) -> Self {
+        Self::from(Degrees(a.0))
+    }
+}
+
+impl From<Angle<f64>> for non_si::Degrees {
+    fn from(a: Angle<f64>) -> Self {
+        Self::from(Degrees::from(a))
+    }
+}
+
+impl From<si::Radians> for Angle<f64> {
+    fn from(a: si::Radians) -> Self {
+        Self::from(Radians(a.0))
+    }
+}
+
+impl From<Angle<f64>> for si::Radians {
+    fn from(a: Angle<f64>) -> Self {
+        Self::from(Radians::from(a))
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::float_cmp)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_degrees() {
+        let degrees = Degrees::from(non_si::Degrees(45.0));
+        assert_eq!(45.0, degrees.0);
+        assert_eq!(non_si::Degrees(45.0), non_si::Degrees::from(degrees));
+    }
+
+    #[test]
+    fn test_radians() {
+        let radians = Radians::from(si::Radians(1.0));
+        assert_eq!(1.0, radians.0);
+        assert_eq!(si::Radians(1.0), si::Radians::from(radians));
+    }
+
+    #[test]
+    fn test_angle() {
+        let angle = Angle::from(non_si::Degrees(90.0));
+        assert_eq!(1.0, angle.sin().0);
+        assert_eq!(non_si::Degrees(90.0), non_si::Degrees::from(angle));
+
+        let angle = Angle::from(si::Radians(core::f64::consts::FRAC_PI_2));
+        assert_eq!(1.0, angle.sin().0);
+        assert_eq!(
+            si::Radians(core::f64::consts::FRAC_PI_2),
+            si::Radians::from(angle)
+        );
+    }
+}
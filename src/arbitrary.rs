@@ -0,0 +1,124 @@
+// Copyright (c) 2024 Ken Barker
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"),
+// to deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+// THE SOFTWARE.
+
+//! [arbitrary](https://crates.io/crates/arbitrary) `Arbitrary` implementations
+//! for the unit types, for fuzzing and property testing.
+//!
+//! The unit types may contain any `f64` value, including `NaN` and infinities.
+//! The [`Finite`] wrapper can be used to generate finite values only.
+
+use crate::quantity::{Quantity, UnitMarker};
+use crate::{non_si, si, Unit};
+use ::arbitrary::{Arbitrary, Result, Unstructured};
+
+/// Implements `Arbitrary` for the type `$t` with the generic parameters `$g`
+/// and the constructor `$new` from an `f64`.
+macro_rules! impl_arbitrary {
+    (impl<$($g:ident: $bound:path),*> $t:ty, $new:path) => {
+        impl<'a, $($g: $bound),*> Arbitrary<'a> for $t {
+            fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+                f64::arbitrary(u).map($new)
+            }
+
+            fn size_hint(depth: usize) -> (usize, Option<usize>) {
+                f64::size_hint(depth)
+            }
+        }
+    };
+    ($t:ty, $new:path) => {
+        impl_arbitrary!(impl<> $t, $new);
+    };
+}
+
+impl_arbitrary!(impl<U: UnitMarker> Quantity<U>, Quantity::new);
+impl_arbitrary!(non_si::Fahrenheit, non_si::Fahrenheit);
+impl_arbitrary!(si::Celsius, si::Celsius);
+
+/// A wrapper for generating unit values with finite values only.
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+#[allow(clippy::derive_partial_eq_without_eq)] // the unit types are not `Eq`
+pub struct Finite<T>(pub T);
+
+/// The most significant bit of an `f64` exponent.
+const EXPONENT_MSB: u64 = 1 << 62;
+
+impl<'a, T: Unit> Arbitrary<'a> for Finite<T> {
+    /// Generates a finite unit value.
+    ///
+    /// If the generated `f64` is not finite, the most significant bit
+    /// of its exponent is cleared to make it finite.
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let value = f64::arbitrary(u)?;
+        let value = if value.is_finite() {
+            value
+        } else {
+            f64::from_bits(value.to_bits() & !EXPONENT_MSB)
+        };
+        Ok(Self(T::new(value)))
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        f64::size_hint(depth)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_arbitrary() {
+        let data = 1852.0_f64.to_le_bytes();
+        let mut u = Unstructured::new(&data);
+        let result = si::Metres::arbitrary(&mut u).unwrap();
+        assert_eq!(si::Metres(1852.0), result);
+        assert_eq!(f64::size_hint(0), non_si::Feet::size_hint(0));
+
+        let data = 59.0_f64.to_le_bytes();
+        let mut u = Unstructured::new(&data);
+        let result = non_si::Fahrenheit::arbitrary(&mut u).unwrap();
+        assert_eq!(non_si::Fahrenheit(59.0), result);
+        assert_eq!(f64::size_hint(0), si::Celsius::size_hint(0));
+    }
+
+    #[test]
+    fn test_arbitrary_finite() {
+        let data = f64::NAN.to_le_bytes();
+        let mut u = Unstructured::new(&data);
+        let result = non_si::Knots::arbitrary(&mut u).unwrap();
+        assert!(result.0.is_nan());
+
+        let mut u = Unstructured::new(&data);
+        let result = Finite::<non_si::Knots>::arbitrary(&mut u).unwrap();
+        assert!(result.0 .0.is_finite());
+
+        let data = f64::NEG_INFINITY.to_le_bytes();
+        let mut u = Unstructured::new(&data);
+        let result = Finite::<si::Pascals>::arbitrary(&mut u).unwrap();
+        assert!(result.0 .0.is_finite());
+        assert!(result.0 .0.is_sign_negative());
+
+        let data = 250.0_f64.to_le_bytes();
+        let mut u = Unstructured::new(&data);
+        let result = Finite::<non_si::Knots>::arbitrary(&mut u).unwrap();
+        assert_eq!(Finite(non_si::Knots(250.0)), result);
+        assert_eq!(f64::size_hint(0), Finite::<non_si::Knots>::size_hint(0));
+    }
+}
@@ -0,0 +1,212 @@
+// Copyright (c) 2024 Ken Barker
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"),
+// to deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+// THE SOFTWARE.
+
+//! Atomic unit types, for flight state shared between threads or
+//! interrupt handlers without locks.
+//!
+//! An [`AtomicUnit`] stores the bit pattern of a unit value in an `AtomicU64`,
+//! e.g.:
+//!
+//! ```
+//! use icao_units::atomic::AtomicFeet;
+//! use icao_units::non_si::Feet;
+//! use core::sync::atomic::Ordering;
+//!
+//! let altitude = AtomicFeet::new(Feet(1000.0));
+//! altitude.store(Feet(1500.0), Ordering::Release);
+//! assert_eq!(Feet(1500.0), altitude.load(Ordering::Acquire));
+//! ```
+//!
+//! The module is only available on targets that support 64 bit atomic
+//! operations.
+
+use crate::{non_si, si, Unit};
+use core::fmt;
+use core::marker::PhantomData;
+use core::sync::atomic::{AtomicU64, Ordering};
+
+/// A unit value that can be shared between threads.
+#[derive(Default)]
+#[repr(transparent)]
+pub struct AtomicUnit<T> {
+    bits: AtomicU64,
+    unit: PhantomData<T>,
+}
+
+impl<T: Unit> AtomicUnit<T> {
+    /// Construct an atomic unit value.
+    #[must_use]
+    pub fn new(value: T) -> Self {
+        Self {
+            bits: AtomicU64::new(value.value().to_bits()),
+            unit: PhantomData,
+        }
+    }
+
+    /// Load the value.
+    #[must_use]
+    pub fn load(&self, order: Ordering) -> T {
+        T::new(f64::from_bits(self.bits.load(order)))
+    }
+
+    /// Store a value.
+    pub fn store(&self, value: T, order: Ordering) {
+        self.bits.store(value.value().to_bits(), order);
+    }
+
+    /// Store a value, returning the previous value.
+    pub fn swap(&self, value: T, order: Ordering) -> T {
+        T::new(f64::from_bits(
+            self.bits.swap(value.value().to_bits(), order),
+        ))
+    }
+
+    /// Store `new` if the current value is `current`, see
+    /// [`AtomicU64::compare_exchange`].
+    ///
+    /// The values are compared by their bit patterns, so `0.0` and `-0.0`
+    /// are not equal and a NaN is equal to the identical NaN.
+    ///
+    /// # Errors
+    ///
+    /// The current value, if it is not `current`.
+    pub fn compare_exchange(
+        &self,
+        current: T,
+        new: T,
+        success: Ordering,
+        failure: Ordering,
+    ) -> Result<T, T> {
+        self.bits
+            .compare_exchange(
+                current.value().to_bits(),
+                new.value().to_bits(),
+                success,
+                failure,
+            )
+            .map(|bits| T::new(f64::from_bits(bits)))
+            .map_err(|bits| T::new(f64::from_bits(bits)))
+    }
+
+    /// Consume the atomic and return the value.
+    #[must_use]
+    pub fn into_inner(self) -> T {
+        T::new(f64::from_bits(self.bits.into_inner()))
+    }
+}
+
+impl<T: Unit> From<T> for AtomicUnit<T> {
+    fn from(value: T) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<T: Unit + fmt::Debug> fmt::Debug for AtomicUnit<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.load(Ordering::Relaxed), f)
+    }
+}
+
+/// An atomic `Metres` value.
+pub type AtomicMetres = AtomicUnit<si::Metres>;
+/// An atomic `MetresPerSecond` value.
+pub type AtomicMetresPerSecond = AtomicUnit<si::MetresPerSecond>;
+/// An atomic `MetresPerSecondSquared` value.
+pub type AtomicMetresPerSecondSquared = AtomicUnit<si::MetresPerSecondSquared>;
+/// An atomic `Kelvin` value.
+pub type AtomicKelvin = AtomicUnit<si::Kelvin>;
+/// An atomic `Pascals` value.
+pub type AtomicPascals = AtomicUnit<si::Pascals>;
+/// An atomic `Kilograms` value.
+pub type AtomicKilograms = AtomicUnit<si::Kilograms>;
+/// An atomic `KilogramsPerCubicMetre` value.
+pub type AtomicKilogramsPerCubicMetre = AtomicUnit<si::KilogramsPerCubicMetre>;
+/// An atomic `Radians` value.
+pub type AtomicRadians = AtomicUnit<si::Radians>;
+/// An atomic `Seconds` value.
+pub type AtomicSeconds = AtomicUnit<si::Seconds>;
+/// An atomic `NauticalMiles` value.
+pub type AtomicNauticalMiles = AtomicUnit<non_si::NauticalMiles>;
+/// An atomic `Feet` value.
+pub type AtomicFeet = AtomicUnit<non_si::Feet>;
+/// An atomic `Knots` value.
+pub type AtomicKnots = AtomicUnit<non_si::Knots>;
+/// An atomic `Degrees` value.
+pub type AtomicDegrees = AtomicUnit<non_si::Degrees>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+
+    #[test]
+    fn test_atomic_unit() {
+        let speed = AtomicKnots::from(non_si::Knots(250.0));
+        assert_eq!(
+            non_si::Knots(250.0),
+            speed.swap(non_si::Knots(260.0), Ordering::AcqRel)
+        );
+        assert_eq!(
+            Err(non_si::Knots(260.0)),
+            speed.compare_exchange(
+                non_si::Knots(250.0),
+                non_si::Knots(270.0),
+                Ordering::AcqRel,
+                Ordering::Acquire
+            )
+        );
+        assert_eq!(
+            Ok(non_si::Knots(260.0)),
+            speed.compare_exchange(
+                non_si::Knots(260.0),
+                non_si::Knots(270.0),
+                Ordering::AcqRel,
+                Ordering::Acquire
+            )
+        );
+        assert_eq!("Knots(270.0)", format!("{speed:?}"));
+        assert_eq!(non_si::Knots(270.0), speed.into_inner());
+        assert_eq!(si::Metres(0.0), AtomicMetres::default().into_inner());
+
+        let altitude = Arc::new(AtomicFeet::new(non_si::Feet(0.0)));
+        let threads: Vec<_> = (0..4)
+            .map(|_| {
+                let altitude = Arc::clone(&altitude);
+                std::thread::spawn(move || {
+                    for _ in 0..1000 {
+                        let mut current = altitude.load(Ordering::Relaxed);
+                        while let Err(actual) = altitude.compare_exchange(
+                            current,
+                            current + non_si::Feet(1.0),
+                            Ordering::AcqRel,
+                            Ordering::Relaxed,
+                        ) {
+                            current = actual;
+                        }
+                    }
+                })
+            })
+            .collect();
+        for thread in threads {
+            thread.join().unwrap();
+        }
+        assert_eq!(non_si::Feet(4000.0), altitude.load(Ordering::Acquire));
+    }
+}
@@ -0,0 +1,165 @@
+// Copyright (c) 2024 Ken Barker
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"),
+// to deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+// THE SOFTWARE.
+
+//! Weight and balance.
+//!
+//! The moment of each item of a load about the datum is its mass multiplied
+//! by its arm, the distance of the item from the datum. The centre of gravity
+//! arm is the total moment divided by the total mass, e.g.:
+//!
+//! ```
+//! use icao_units::balance::{centre_of_gravity, LoadItem};
+//! use icao_units::si::{Kilograms, Metres};
+//!
+//! let load = [
+//!     LoadItem::new(Kilograms(600.0), Metres(2.0)),
+//!     LoadItem::new(Kilograms(200.0), Metres(2.5)),
+//!     LoadItem::new(Kilograms(200.0), Metres(1.5)),
+//! ];
+//! let cg = centre_of_gravity(load).unwrap();
+//! assert_eq!(Metres(2.0), cg);
+//! ```
+//!
+//! Centre of gravity envelopes are usually defined as a [`PercentMac`], a
+//! percentage of the mean aerodynamic chord (MAC) aft of its leading edge
+//! (LEMAC).
+
+use crate::si::{KilogramMetres, Kilograms, Metres};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// An item of a load sheet: a mass at an arm from the datum.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct LoadItem {
+    /// The mass of the item.
+    pub mass: Kilograms,
+    /// The distance of the item from the datum, positive aft.
+    pub arm: Metres,
+}
+
+impl LoadItem {
+    /// Construct a load item.
+    #[must_use]
+    pub const fn new(mass: Kilograms, arm: Metres) -> Self {
+        Self { mass, arm }
+    }
+
+    /// The moment of the item about the datum.
+    #[must_use]
+    pub fn moment(self) -> KilogramMetres {
+        self.mass * self.arm
+    }
+}
+
+/// The total mass and moment of the `items`.
+#[must_use]
+pub fn totals<I: IntoIterator<Item = LoadItem>>(items: I) -> (Kilograms, KilogramMetres) {
+    items.into_iter().fold(
+        (Kilograms::ZERO, KilogramMetres::ZERO),
+        |(mass, moment), item| (mass + item.mass, moment + item.moment()),
+    )
+}
+
+/// The centre of gravity arm of the `items`.
+///
+/// Returns `None` if the total mass is zero.
+#[must_use]
+pub fn centre_of_gravity<I: IntoIterator<Item = LoadItem>>(items: I) -> Option<Metres> {
+    let (mass, moment) = totals(items);
+    (mass != Kilograms::ZERO).then(|| moment / mass)
+}
+
+/// A centre of gravity position as a percentage of the mean aerodynamic
+/// chord (MAC), e.g. 25.0 for 25% MAC.
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[repr(transparent)]
+pub struct PercentMac(pub f64);
+
+impl PercentMac {
+    /// Construct the %MAC of an `arm` from the datum, given the arm of the
+    /// leading edge of the MAC, `lemac`, and the length of the MAC, `mac`.
+    #[must_use]
+    pub fn from_arm(arm: Metres, lemac: Metres, mac: Metres) -> Self {
+        Self(100.0 * (arm - lemac).0 / mac.0)
+    }
+
+    /// The arm from the datum of the %MAC, given the arm of the leading
+    /// edge of the MAC, `lemac`, and the length of the MAC, `mac`.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[must_use]
+    pub fn arm(self, lemac: Metres, mac: Metres) -> Metres {
+        Metres(crate::math::mul_add(self.0 / 100.0, mac.0, lemac.0))
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::float_cmp)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_centre_of_gravity() {
+        let item = LoadItem::new(Kilograms(80.0), Metres(-0.5));
+        assert_eq!(KilogramMetres(-40.0), item.moment());
+
+        let load = [
+            LoadItem::new(Kilograms(1000.0), Metres(2.0)),
+            LoadItem::new(Kilograms(200.0), Metres(3.0)),
+            item,
+        ];
+        let (mass, moment) = totals(load);
+        assert_eq!(Kilograms(1280.0), mass);
+        assert_eq!(KilogramMetres(2560.0), moment);
+        assert_eq!(Some(Metres(2.0)), centre_of_gravity(load));
+
+        assert_eq!(None, centre_of_gravity([]));
+        assert_eq!(
+            None,
+            centre_of_gravity([LoadItem::new(Kilograms::ZERO, Metres(1.0))])
+        );
+    }
+
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[test]
+    fn test_percent_mac() {
+        let lemac = Metres(16.0);
+        let mac = Metres(4.0);
+        assert_eq!(
+            PercentMac(25.0),
+            PercentMac::from_arm(Metres(17.0), lemac, mac)
+        );
+        assert_eq!(PercentMac(0.0), PercentMac::from_arm(lemac, lemac, mac));
+        assert_eq!(
+            PercentMac(-12.5),
+            PercentMac::from_arm(Metres(15.5), lemac, mac)
+        );
+        assert_eq!(Metres(17.0), PercentMac(25.0).arm(lemac, mac));
+        assert_eq!(Metres(20.0), PercentMac(100.0).arm(lemac, mac));
+
+        #[cfg(feature = "serde")]
+        {
+            let serialized = serde_json::to_string(&PercentMac(25.0)).unwrap();
+            assert_eq!("25.0", serialized);
+            let deserialized: PercentMac = serde_json::from_str(&serialized).unwrap();
+            assert_eq!(PercentMac(25.0), deserialized);
+        }
+    }
+}
@@ -0,0 +1,259 @@
+// Copyright (c) 2024 Ken Barker
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"),
+// to deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+// THE SOFTWARE.
+
+//! Conversion of slices of unit values, e.g. radar plots.
+//!
+//! The conversions use the `From` implementations between the unit types.
+//! The slices are converted in chunks of [`LANES`] values copied into
+//! fixed-width arrays, so the compiler can convert each chunk with SIMD
+//! instructions without bounds checks; the values after the last whole
+//! chunk are converted one at a time. The chunked conversions are faster
+//! than loops converting one value at a time when the slices are in cache,
+//! and converting raw values in place does not need a second buffer, so it
+//! is about twice as fast for large slices, see `benches/batch.rs`, e.g.:
+//!
+//! ```
+//! use icao_units::batch::{convert_in_place, convert_slice};
+//! use icao_units::non_si::NauticalMiles;
+//! use icao_units::si::Metres;
+//!
+//! let ranges = [NauticalMiles(1.0), NauticalMiles(2.5)];
+//! let mut metres = [Metres(0.0); 2];
+//! convert_slice(&ranges, &mut metres);
+//! assert_eq!([Metres(1852.0), Metres(4630.0)], metres);
+//!
+//! let mut values = [1.0, 2.5];
+//! convert_in_place::<NauticalMiles, Metres>(&mut values);
+//! assert_eq!([1852.0, 4630.0], values);
+//! ```
+//!
+//! The `rayon` feature adds parallel versions of the functions for very
+//! large slices, e.g. multi-million point trajectory datasets.
+
+use crate::Unit;
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+/// The number of values in a chunk: 16 `f64` values fill two AVX-512
+/// registers or four AVX2 or eight SSE2 or NEON registers.
+///
+/// It is twice the width of the widest registers, so the compiler can
+/// interleave the conversions, see `benches/batch.rs`.
+pub const LANES: usize = 16;
+
+/// Convert a raw value from unit `T` to unit `U`.
+#[inline]
+fn convert_value<T: Unit, U: Unit + From<T>>(value: f64) -> f64 {
+    U::from(T::new(value)).value()
+}
+
+/// Convert a chunk of raw values from unit `T` to unit `U`.
+#[inline]
+fn convert_lanes<T: Unit, U: Unit + From<T>>(lanes: &mut [f64; LANES]) {
+    for value in lanes {
+        *value = convert_value::<T, U>(*value);
+    }
+}
+
+/// Convert a slice of `T` unit values into a slice of `U` unit values.
+///
+/// # Panics
+///
+/// If the slices have different lengths.
+pub fn convert_slice<T, U>(src: &[T], dst: &mut [U])
+where
+    T: Unit,
+    U: Unit + From<T>,
+{
+    assert_eq!(src.len(), dst.len(), "slices must have the same length");
+
+    let mut src_chunks = src.chunks_exact(LANES);
+    let mut dst_chunks = dst.chunks_exact_mut(LANES);
+    for (a, b) in src_chunks.by_ref().zip(dst_chunks.by_ref()) {
+        for (a, b) in a[..LANES].iter().zip(&mut b[..LANES]) {
+            *b = U::from(*a);
+        }
+    }
+
+    for (a, b) in src_chunks
+        .remainder()
+        .iter()
+        .zip(dst_chunks.into_remainder())
+    {
+        *b = U::from(*a);
+    }
+}
+
+/// Convert a slice of raw values in unit `T` to unit `U` in place, e.g.
+/// `convert_in_place::<NauticalMiles, Metres>(&mut values)`.
+pub fn convert_in_place<T, U>(values: &mut [f64])
+where
+    T: Unit,
+    U: Unit + From<T>,
+{
+    let mut chunks = values.chunks_exact_mut(LANES);
+    for chunk in chunks.by_ref() {
+        let mut lanes = [0.0; LANES];
+        lanes.copy_from_slice(chunk);
+        convert_lanes::<T, U>(&mut lanes);
+        chunk.copy_from_slice(&lanes);
+    }
+
+    for value in chunks.into_remainder() {
+        *value = convert_value::<T, U>(*value);
+    }
+}
+
+/// The number of values converted by each parallel task, a multiple of
+/// [`LANES`].
+#[cfg(feature = "rayon")]
+const PAR_CHUNK: usize = 512 * LANES;
+
+/// Convert a slice of `T` unit values into a slice of `U` unit values
+/// in parallel.
+///
+/// # Panics
+///
+/// If the slices have different lengths.
+#[cfg(feature = "rayon")]
+pub fn par_convert_slice<T, U>(src: &[T], dst: &mut [U])
+where
+    T: Unit + Sync,
+    U: Unit + From<T> + Send,
+{
+    assert_eq!(src.len(), dst.len(), "slices must have the same length");
+
+    src.par_chunks(PAR_CHUNK)
+        .zip(dst.par_chunks_mut(PAR_CHUNK))
+        .for_each(|(a, b)| convert_slice(a, b));
+}
+
+/// Convert a slice of raw values in unit `T` to unit `U` in place,
+/// in parallel.
+#[cfg(feature = "rayon")]
+pub fn par_convert_in_place<T, U>(values: &mut [f64])
+where
+    T: Unit,
+    U: Unit + From<T>,
+{
+    values
+        .par_chunks_mut(PAR_CHUNK)
+        .for_each(convert_in_place::<T, U>);
+}
+
+#[cfg(test)]
+#[allow(clippy::float_cmp)]
+mod tests {
+    use super::*;
+    use crate::{non_si, si};
+
+    #[test]
+    fn test_convert_slice() {
+        let src: Vec<non_si::Feet> = (0..21)
+            .map(|i| non_si::Feet(f64::from(i) * 100.3))
+            .collect();
+        let mut dst = vec![si::Metres(0.0); src.len()];
+        convert_slice(&src, &mut dst);
+        for (s, d) in src.iter().zip(&dst) {
+            assert_eq!(si::Metres::from(*s), *d);
+        }
+
+        let mut back = vec![non_si::Feet(0.0); dst.len()];
+        convert_slice(&dst, &mut back);
+        for (m, f) in dst.iter().zip(&back) {
+            assert_eq!(non_si::Feet::from(*m), *f);
+        }
+    }
+
+    crate::declare_unit!(pub Furlongs, FurlongsUnit, "fur", 201.168);
+
+    impl From<Furlongs> for si::Metres {
+        fn from(a: Furlongs) -> Self {
+            Self::new(a.0 * 201.168)
+        }
+    }
+
+    #[test]
+    fn test_convert_slice_uses_from() {
+        let src = [Furlongs(1.0), Furlongs(8.0)];
+        let mut dst = [si::Metres(0.0); 2];
+        convert_slice(&src, &mut dst);
+        assert_eq!([si::Metres(201.168), si::Metres(1_609.344)], dst);
+
+        let mut values = [1.0, 8.0];
+        convert_in_place::<Furlongs, si::Metres>(&mut values);
+        assert_eq!([201.168, 1_609.344], values);
+    }
+
+    #[test]
+    #[should_panic(expected = "slices must have the same length")]
+    fn test_convert_slice_length() {
+        let mut dst = [si::Metres(0.0); 1];
+        convert_slice(&[non_si::NauticalMiles(1.0); 2], &mut dst);
+    }
+
+    #[test]
+    fn test_convert_in_place() {
+        let original: Vec<f64> = (0..19).map(|i| f64::from(i) * 0.7).collect();
+        let mut values = original.clone();
+        convert_in_place::<si::MetresPerSecond, non_si::Knots>(&mut values);
+        for (o, v) in original.iter().zip(&values) {
+            assert_eq!(non_si::Knots::from(si::MetresPerSecond(*o)).0, *v);
+        }
+    }
+
+    #[test]
+    fn test_chunks_and_remainders() {
+        // empty, remainder only, whole chunks and whole chunks with a remainder
+        for length in [0, 1, LANES - 1, LANES, 2 * LANES, 2 * LANES + 3] {
+            let src: Vec<non_si::NauticalMiles> = (0..length)
+                .map(|i| non_si::NauticalMiles(f64::from(u32::try_from(i).unwrap()) + 1.0))
+                .collect();
+            let mut dst = vec![si::Metres(0.0); length];
+            convert_slice(&src, &mut dst);
+            let mut values: Vec<f64> = src.iter().map(|a| a.0).collect();
+            convert_in_place::<non_si::NauticalMiles, si::Metres>(&mut values);
+            for (i, (d, v)) in dst.iter().zip(&values).enumerate() {
+                let expected = 1852.0 * f64::from(u32::try_from(i).unwrap() + 1);
+                assert_eq!(si::Metres(expected), *d);
+                assert_eq!(expected, *v);
+            }
+        }
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn test_par_convert() {
+        let src: Vec<non_si::NauticalMiles> = (0..10_001)
+            .map(|i| non_si::NauticalMiles(f64::from(i) * 0.01))
+            .collect();
+        let mut dst = vec![si::Metres(0.0); src.len()];
+        par_convert_slice(&src, &mut dst);
+        let mut expected = vec![si::Metres(0.0); src.len()];
+        convert_slice(&src, &mut expected);
+        assert_eq!(expected, dst);
+
+        let mut values: Vec<f64> = src.iter().map(|a| a.0).collect();
+        par_convert_in_place::<non_si::NauticalMiles, si::Metres>(&mut values);
+        for (e, v) in expected.iter().zip(&values) {
+            assert_eq!(e.0, *v);
+        }
+    }
+}
@@ -0,0 +1,142 @@
+// Copyright (c) 2024 Ken Barker
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"),
+// to deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+// THE SOFTWARE.
+
+//! Zero-cost reinterpretation between `f64` values and unit values.
+//!
+//! The unit types are `#[repr(transparent)]` over `f64` and implement the
+//! [bytemuck](https://crates.io/crates/bytemuck) `Pod` trait, so slices and
+//! vectors of `f64` values can be viewed or converted as slices and vectors
+//! of unit values without copying, e.g. data loaded from a binary file:
+//!
+//! ```
+//! use icao_units::cast;
+//! use icao_units::si::Metres;
+//!
+//! let values = vec![1.0, 2.0, 3.0];
+//! let distances: &[Metres] = cast::from_f64_slice(&values);
+//! assert_eq!(Metres(2.0), distances[1]);
+//! ```
+
+use crate::quantity::{Quantity, UnitMarker};
+use crate::Unit;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use bytemuck::Pod;
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// A unit type that is `#[repr(transparent)]` over `f64`.
+///
+/// This trait is sealed: it cannot be implemented outside of this crate.
+pub trait TransparentF64: Unit + Pod + sealed::Sealed {}
+
+impl<U: UnitMarker + Pod> sealed::Sealed for Quantity<U> {}
+impl<U: UnitMarker + Pod> TransparentF64 for Quantity<U> {}
+
+/// View a slice of `f64` values as a slice of unit values.
+#[must_use]
+pub fn from_f64_slice<T: TransparentF64>(values: &[f64]) -> &[T] {
+    bytemuck::cast_slice(values)
+}
+
+/// View a mutable slice of `f64` values as a mutable slice of unit values.
+#[must_use]
+pub fn from_f64_slice_mut<T: TransparentF64>(values: &mut [f64]) -> &mut [T] {
+    bytemuck::cast_slice_mut(values)
+}
+
+/// View a slice of unit values as a slice of `f64` values.
+#[must_use]
+pub fn to_f64_slice<T: TransparentF64>(values: &[T]) -> &[f64] {
+    bytemuck::cast_slice(values)
+}
+
+/// View a mutable slice of unit values as a mutable slice of `f64` values.
+#[must_use]
+pub fn to_f64_slice_mut<T: TransparentF64>(values: &mut [T]) -> &mut [f64] {
+    bytemuck::cast_slice_mut(values)
+}
+
+/// Convert a vector of `f64` values into a vector of unit values without
+/// copying, e.g.:
+///
+/// ```
+/// use icao_units::cast;
+/// use icao_units::si::Metres;
+///
+/// let distances: Vec<Metres> = cast::from_f64_vec(vec![1.0, 2.0, 3.0]);
+/// assert_eq!(Metres(2.0), distances[1]);
+/// assert_eq!(vec![1.0, 2.0, 3.0], cast::into_f64_vec(distances));
+/// ```
+#[cfg(feature = "alloc")]
+#[must_use]
+pub fn from_f64_vec<T: TransparentF64>(values: Vec<f64>) -> Vec<T> {
+    bytemuck::allocation::cast_vec(values)
+}
+
+/// Convert a vector of unit values into a vector of `f64` values without
+/// copying.
+#[cfg(feature = "alloc")]
+#[must_use]
+pub fn into_f64_vec<T: TransparentF64>(values: Vec<T>) -> Vec<f64> {
+    bytemuck::allocation::cast_vec(values)
+}
+
+#[cfg(test)]
+#[allow(clippy::float_cmp)]
+mod tests {
+    use super::*;
+    use crate::{non_si, si};
+
+    #[test]
+    fn test_slices() {
+        let mut values = [1.0, 2.0, 3.0];
+        let distances: &[si::Metres] = from_f64_slice(&values);
+        assert_eq!(
+            &[si::Metres(1.0), si::Metres(2.0), si::Metres(3.0)],
+            distances
+        );
+        assert_eq!(&values, to_f64_slice(distances));
+
+        let altitudes: &mut [non_si::Feet] = from_f64_slice_mut(&mut values);
+        altitudes[0] += non_si::Feet(100.0);
+        to_f64_slice_mut(altitudes)[1] = 50.0;
+        assert_eq!([101.0, 50.0, 3.0], values);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_vecs() {
+        let mut values = Vec::with_capacity(8);
+        values.extend([1.0, 2.0, 3.0]);
+        let speeds: Vec<non_si::Knots> = from_f64_vec(values);
+        assert_eq!(
+            vec![non_si::Knots(1.0), non_si::Knots(2.0), non_si::Knots(3.0)],
+            speeds
+        );
+        assert_eq!(8, speeds.capacity());
+
+        let values = into_f64_vec(speeds);
+        assert_eq!(vec![1.0, 2.0, 3.0], values);
+        assert_eq!(8, values.capacity());
+    }
+}
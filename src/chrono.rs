@@ -0,0 +1,66 @@
+// Copyright (c) 2024 Ken Barker
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"),
+// to deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+// THE SOFTWARE.
+
+//! Conversions and speed/time operators for the
+//! [chrono](https://crates.io/crates/chrono) `TimeDelta` (`Duration`) type.
+
+use crate::{non_si, si};
+use ::chrono::TimeDelta;
+use core::ops::Mul;
+
+impl From<TimeDelta> for si::Seconds {
+    fn from(a: TimeDelta) -> Self {
+        Self::new(a.as_seconds_f64())
+    }
+}
+
+impl Mul<TimeDelta> for si::MetresPerSecond {
+    type Output = si::Metres;
+
+    fn mul(self, rhs: TimeDelta) -> si::Metres {
+        self * si::Seconds::from(rhs)
+    }
+}
+
+impl Mul<TimeDelta> for non_si::Knots {
+    type Output = non_si::NauticalMiles;
+
+    fn mul(self, rhs: TimeDelta) -> non_si::NauticalMiles {
+        self * si::Seconds::from(rhs)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::float_cmp)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_time_delta() {
+        let delta = TimeDelta::milliseconds(-1500);
+        assert_eq!(si::Seconds(-1.5), si::Seconds::from(delta));
+
+        let speed = si::MetresPerSecond(100.0);
+        assert_eq!(si::Metres(150.0), speed * TimeDelta::milliseconds(1500));
+
+        let speed = non_si::Knots(120.0);
+        assert_eq!(non_si::NauticalMiles(2.0), speed * TimeDelta::minutes(1));
+    }
+}
@@ -0,0 +1,184 @@
+// Copyright (c) 2024 Ken Barker
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"),
+// to deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+// THE SOFTWARE.
+
+//! Helpers for reading and writing unit values in flat CSV records.
+//!
+//! With the `serde` feature, the unit types serialize as plain numbers, so a struct of unit fields
+//! can be written to, or read from, a CSV file directly.
+//! However, the units are then lost from the file, so the [`header`] function
+//! generates column header names with a unit suffix, e.g. "`altitude_ft`", "`gs_kt`".
+
+use crate::{non_si, si};
+use core::fmt;
+
+/// A trait for unit types to define the suffix used in CSV column headers.
+pub trait CsvSuffix {
+    /// The suffix appended to a column header name, e.g. "ft".
+    const SUFFIX: &'static str;
+}
+
+macro_rules! impl_csv_suffix {
+    ($($t:ty => $suffix:literal),*) => {
+        $(
+            impl CsvSuffix for $t {
+                const SUFFIX: &'static str = $suffix;
+            }
+        )*
+    };
+}
+
+impl_csv_suffix!(
+    si::Metres => "m",
+    si::MetresPerSecond => "mps",
+    si::MetresPerSecondSquared => "mps2",
+    si::Kelvin => "k",
+    si::Pascals => "pa",
+    si::Kilograms => "kg",
+    si::KilogramsPerCubicMetre => "kgpm3",
+    non_si::NauticalMiles => "nm",
+    non_si::Feet => "ft",
+    non_si::Knots => "kt",
+    non_si::FeetPerMinute => "fpm",
+    si::Radians => "rad",
+    non_si::Degrees => "deg",
+    si::RadiansPerSecond => "radps",
+    non_si::DegreesPerSecond => "degps",
+    non_si::ArcMinutes => "arcmin",
+    non_si::ArcSeconds => "arcsec",
+    si::Seconds => "s",
+    non_si::Hours => "h",
+    si::KilogramsPerSecond => "kgps",
+    non_si::KilogramsPerHour => "kgph",
+    si::MetresPerKilogram => "mpkg",
+    non_si::NauticalMilesPerKilogram => "nmpkg",
+    non_si::KilogramsPerLitre => "kgpl",
+    si::CubicMetres => "m3",
+    si::CubicMetresPerSecond => "m3ps",
+    si::KilogramMetres => "kgm",
+    non_si::Litres => "l",
+    non_si::USGallons => "usgal",
+    non_si::LitresPerHour => "lph",
+    non_si::USGallonsPerHour => "usgph",
+    non_si::Psi => "psi",
+    non_si::Hectopascals => "hpa",
+    non_si::Millibars => "mb",
+    non_si::InchesOfMercury => "inhg",
+    si::PascalSeconds => "pas",
+    si::SquareMetresPerSecond => "m2ps",
+    si::JoulesPerKilogramKelvin => "jpkgk",
+    si::PascalsPerSecond => "paps",
+    non_si::HectopascalsPerHour => "hpaph",
+    si::MetresPerSecondPerMetre => "mpspm",
+    non_si::KnotsPerHundredFeet => "ktp100ft",
+    si::Amperes => "a",
+    si::Volts => "v",
+    si::Ohms => "ohm",
+    si::Watts => "w",
+    si::Candela => "cd",
+    si::Lumens => "lm",
+    si::Lux => "lx",
+    si::Becquerels => "bq",
+    si::Sieverts => "sv",
+    si::SievertsPerSecond => "svps",
+    non_si::SievertsPerHour => "svph"
+);
+
+/// A CSV column header name with a unit suffix.
+///
+/// Displays as the name and unit suffix separated by an underscore.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Header<'a> {
+    /// The column name.
+    pub name: &'a str,
+    /// The unit suffix.
+    pub suffix: &'static str,
+}
+
+impl fmt::Display for Header<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}_{}", self.name, self.suffix)
+    }
+}
+
+/// Create a CSV column header name for a field of unit type `T`.
+///
+/// * `name` - the field name.
+///
+/// returns the header, e.g. `header::<Feet>("altitude")` displays as "`altitude_ft`".
+#[must_use]
+pub const fn header<T: CsvSuffix>(name: &str) -> Header<'_> {
+    Header {
+        name,
+        suffix: T::SUFFIX,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "serde")]
+    #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+    struct Record {
+        altitude: non_si::Feet,
+        gs: non_si::Knots,
+        distance: si::Metres,
+    }
+
+    #[test]
+    fn test_header() {
+        let altitude = header::<non_si::Feet>("altitude");
+        assert_eq!("altitude", altitude.name);
+        assert_eq!("ft", altitude.suffix);
+        assert_eq!("altitude_ft", altitude.to_string());
+        assert_eq!("gs_kt", header::<non_si::Knots>("gs").to_string());
+        assert_eq!("distance_m", header::<si::Metres>("distance").to_string());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_csv_record() {
+        let mut writer = ::csv::WriterBuilder::new()
+            .has_headers(false)
+            .from_writer(vec![]);
+        writer
+            .write_record([
+                header::<non_si::Feet>("altitude").to_string(),
+                header::<non_si::Knots>("gs").to_string(),
+                header::<si::Metres>("distance").to_string(),
+            ])
+            .unwrap();
+        let record = Record {
+            altitude: non_si::Feet(35_000.0),
+            gs: non_si::Knots(450.5),
+            distance: si::Metres(1_852.0),
+        };
+        writer.serialize(&record).unwrap();
+        let text = String::from_utf8(writer.into_inner().unwrap()).unwrap();
+        assert_eq!("altitude_ft,gs_kt,distance_m\n35000.0,450.5,1852.0\n", text);
+
+        let mut reader = ::csv::ReaderBuilder::new()
+            .has_headers(true)
+            .from_reader(text.as_bytes());
+        let row = reader.records().next().unwrap().unwrap();
+        let result: Record = row.deserialize(None).unwrap();
+        assert_eq!(record, result);
+    }
+}
@@ -0,0 +1,216 @@
+// Copyright (c) 2024 Ken Barker
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"),
+// to deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+// THE SOFTWARE.
+
+//! Decimal mass and volume unit types, for fuel and mass accounting.
+//!
+//! The types in this module store a [rust_decimal](https://crates.io/crates/rust_decimal)
+//! `Decimal` value, so that quantities such as fuel uplifts can be summed
+//! and converted without binary floating point rounding.
+//!
+//! The conversion factors are exact: a pound is exactly 0.453 592 37 kg and
+//! a US gallon is exactly 3.785 411 784 litres. Conversion to the larger unit
+//! divides by the factor and is rounded to the 28 significant digits of a
+//! `Decimal`, e.g.:
+//!
+//! ```
+//! use icao_units::decimal::{Kilograms, Pounds};
+//! use rust_decimal::Decimal;
+//!
+//! let fuel = Pounds(Decimal::from(10_000));
+//! assert_eq!(Kilograms(Decimal::new(4_535_923_700, 6)), Kilograms::try_from(fuel).unwrap());
+//! ```
+//!
+//! The conversions return a `rust_decimal::Error` if the result is too large
+//! for a `Decimal`.
+
+use crate::si;
+use rust_decimal::{Decimal, Error};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The mass of a pound (lb) in kilograms (kg): 0.453 592 37.
+pub const KILOGRAMS_PER_POUND: Decimal = Decimal::from_parts(45_359_237, 0, 0, false, 8);
+
+/// The volume of a US gallon (US gal) in litres (L): 3.785 411 784.
+pub const LITRES_PER_US_GALLON: Decimal = Decimal::from_parts(3_785_411_784, 0, 0, false, 9);
+
+/// Defines a decimal unit type with checked arithmetic.
+///
+/// The types do not implement `Add` or `Sub`, since `Decimal` addition and
+/// subtraction panic on overflow.
+macro_rules! decimal_unit {
+    ($(#[$attr:meta])* $t:ident) => {
+        $(#[$attr])*
+        #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+        #[repr(transparent)]
+        pub struct $t(pub Decimal);
+
+        impl $t {
+            /// Zero.
+            pub const ZERO: Self = Self(Decimal::ZERO);
+
+            /// Checked addition, `None` on overflow.
+            #[must_use]
+            pub fn checked_add(self, rhs: Self) -> Option<Self> {
+                self.0.checked_add(rhs.0).map(Self)
+            }
+
+            /// Checked subtraction, `None` on overflow.
+            #[must_use]
+            pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+                self.0.checked_sub(rhs.0).map(Self)
+            }
+        }
+
+        impl core::ops::Neg for $t {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                Self(-self.0)
+            }
+        }
+    };
+}
+
+decimal_unit!(
+    /// A decimal Kilograms `newtype` for representing mass.
+    Kilograms
+);
+
+decimal_unit!(
+    /// A decimal Pounds `newtype` for representing mass.
+    Pounds
+);
+
+decimal_unit!(
+    /// A decimal Litres `newtype` for representing volume.
+    Litres
+);
+
+decimal_unit!(
+    /// A decimal US gallons `newtype` for representing volume.
+    USGallons
+);
+
+/// Implements the conversions between a decimal unit type and a larger
+/// decimal unit type of the same quantity, using an exact conversion factor.
+macro_rules! impl_decimal_conversion {
+    ($small:ty, $large:ty, $factor:expr) => {
+        impl TryFrom<$large> for $small {
+            type Error = Error;
+
+            fn try_from(a: $large) -> Result<Self, Self::Error> {
+                a.0.checked_mul($factor)
+                    .map(Self)
+                    .ok_or(Error::ExceedsMaximumPossibleValue)
+            }
+        }
+
+        impl TryFrom<$small> for $large {
+            type Error = Error;
+
+            fn try_from(a: $small) -> Result<Self, Self::Error> {
+                a.0.checked_div($factor)
+                    .map(Self)
+                    .ok_or(Error::ExceedsMaximumPossibleValue)
+            }
+        }
+    };
+}
+
+impl_decimal_conversion!(Kilograms, Pounds, KILOGRAMS_PER_POUND);
+impl_decimal_conversion!(Litres, USGallons, LITRES_PER_US_GALLON);
+
+impl TryFrom<si::Kilograms> for Kilograms {
+    type Error = Error;
+
+    /// Convert `si::Kilograms` to the nearest decimal value.
+    /// Fails if the value is not finite or is too large for a `Decimal`.
+    fn try_from(a: si::Kilograms) -> Result<Self, Self::Error> {
+        Decimal::try_from(a.0).map(Self)
+    }
+}
+
+impl TryFrom<Kilograms> for si::Kilograms {
+    type Error = Error;
+
+    /// Convert decimal `Kilograms` to the nearest `f64` value.
+    fn try_from(a: Kilograms) -> Result<Self, Self::Error> {
+        f64::try_from(a.0).map(Self::new)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::float_cmp)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decimal_units() {
+        let fuel = Pounds(Decimal::from(10_000));
+        let kilograms = Kilograms::try_from(fuel).unwrap();
+        assert_eq!(Kilograms(Decimal::new(4_535_923_700, 6)), kilograms);
+        assert_eq!(fuel, Pounds::try_from(kilograms).unwrap());
+
+        let gallons = USGallons(Decimal::from(1_000));
+        let litres = Litres::try_from(gallons).unwrap();
+        assert_eq!(Litres(Decimal::new(3_785_411_784, 6)), litres);
+        assert_eq!(gallons, USGallons::try_from(litres).unwrap());
+
+        // 0.1 + 0.2 is exactly 0.3
+        let a = Kilograms(Decimal::new(1, 1));
+        let b = Kilograms(Decimal::new(2, 1));
+        let sum = a.checked_add(b).unwrap();
+        assert_eq!(Kilograms(Decimal::new(3, 1)), sum);
+        assert_eq!(Some(a), sum.checked_sub(b));
+        assert_eq!(
+            None,
+            Kilograms(Decimal::MAX).checked_add(Kilograms(Decimal::ONE))
+        );
+        assert_eq!(
+            None,
+            Kilograms(Decimal::MIN).checked_sub(Kilograms(Decimal::ONE))
+        );
+        assert_eq!(
+            Err(Error::ExceedsMaximumPossibleValue),
+            Pounds::try_from(Kilograms(Decimal::MAX))
+        );
+        assert_eq!(Kilograms::ZERO, -Kilograms::ZERO);
+
+        assert_eq!(
+            Ok(Kilograms(Decimal::new(15, 1))),
+            Kilograms::try_from(si::Kilograms(1.5))
+        );
+        assert_eq!(
+            Ok(si::Kilograms(1.5)),
+            si::Kilograms::try_from(Kilograms(Decimal::new(15, 1)))
+        );
+        assert!(Kilograms::try_from(si::Kilograms(f64::NAN)).is_err());
+
+        #[cfg(feature = "serde")]
+        {
+            let serialized = serde_json::to_string(&a).unwrap();
+            assert_eq!("\"0.1\"", serialized);
+            let deserialized: Kilograms = serde_json::from_str(&serialized).unwrap();
+            assert_eq!(a, deserialized);
+        }
+    }
+}
@@ -0,0 +1,68 @@
+// Copyright (c) 2024 Ken Barker
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"),
+// to deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+// THE SOFTWARE.
+
+//! [defmt](https://crates.io/crates/defmt) `Format` implementations for the
+//! unit types.
+//!
+//! The units are formatted as their value followed by their symbol, e.g. "1000 ft".
+
+use crate::quantity::{Quantity, UnitMarker};
+use crate::{non_si, si};
+use ::defmt::{Format, Formatter};
+
+impl<U: UnitMarker> Format for Quantity<U> {
+    fn format(&self, f: Formatter<'_>) {
+        ::defmt::write!(f, "{=f64} {=str}", self.0, U::SYMBOL);
+    }
+}
+
+impl Format for non_si::Fahrenheit {
+    fn format(&self, f: Formatter<'_>) {
+        ::defmt::write!(f, "{=f64}°F", self.0);
+    }
+}
+
+impl Format for si::Celsius {
+    fn format(&self, f: Formatter<'_>) {
+        ::defmt::write!(f, "{=f64}°C", self.0);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Formatting needs a `defmt` global logger, so just check the bounds.
+    const fn assert_format<T: Format>(_: &T) {}
+
+    #[test]
+    fn test_format() {
+        assert_format(&si::Metres(1852.0));
+        assert_format(&si::MetresPerSecond(10.0));
+        assert_format(&non_si::Feet(1000.0));
+        assert_format(&non_si::Knots(250.0));
+        assert_format(&non_si::NauticalMiles(1.0));
+        assert_format(&si::Celsius(15.0));
+        assert_format(&non_si::Fahrenheit(59.0));
+
+        assert_eq!("ft", non_si::FeetUnit::SYMBOL);
+        assert_eq!("kt", non_si::KnotsUnit::SYMBOL);
+    }
+}
@@ -0,0 +1,108 @@
+// Copyright (c) 2024 Ken Barker
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"),
+// to deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+// THE SOFTWARE.
+
+//! Descent planning for a fixed flight path angle.
+//!
+//! The functions relate the altitude to lose, the distance flown and the
+//! vertical speed of a descent at a constant path angle, for example to find
+//! top of descent, e.g.:
+//!
+//! ```
+//! # #[cfg(any(feature = "std", feature = "libm"))]
+//! # {
+//! use icao_units::descent::{distance_for_descent, vertical_speed_for, THREE_DEGREES};
+//! use icao_units::non_si::{Feet, Knots};
+//!
+//! let distance = distance_for_descent(Feet(35_000.0), THREE_DEGREES);
+//! assert_eq!(110.0, distance.0.round());
+//! let vertical_speed = vertical_speed_for(Knots(300.0), THREE_DEGREES);
+//! assert_eq!(1592.0, vertical_speed.0.round());
+//! # }
+//! ```
+//!
+//! The path angle and the results are positive for a descent.
+
+#[cfg(any(feature = "std", feature = "libm"))]
+use crate::gradient::Gradient;
+use crate::non_si::Degrees;
+#[cfg(any(feature = "std", feature = "libm"))]
+use crate::non_si::{Feet, FeetPerMinute, NauticalMiles};
+#[cfg(any(feature = "std", feature = "libm"))]
+use crate::si::{Metres, MetresPerSecond};
+#[cfg(any(feature = "std", feature = "libm"))]
+use crate::ConvertTo;
+
+/// The standard 3 degree descent path angle.
+pub const THREE_DEGREES: Degrees = Degrees(3.0);
+
+/// The horizontal distance to lose `altitude` at a path `angle`.
+#[cfg(any(feature = "std", feature = "libm"))]
+#[must_use]
+pub fn distance_for_descent<A: ConvertTo<Metres>>(altitude: A, angle: Degrees) -> NauticalMiles {
+    NauticalMiles::from(Metres(altitude.convert().0 / Gradient::from_angle(angle).0))
+}
+
+/// The altitude lost over a horizontal `distance` at a path `angle`.
+#[cfg(any(feature = "std", feature = "libm"))]
+#[must_use]
+pub fn altitude_to_lose<D: ConvertTo<Metres>>(distance: D, angle: Degrees) -> Feet {
+    Feet::from(Metres(distance.convert().0 * Gradient::from_angle(angle).0))
+}
+
+/// The rate of descent to fly a path `angle` at a `ground_speed`.
+#[cfg(any(feature = "std", feature = "libm"))]
+#[must_use]
+pub fn vertical_speed_for<G: ConvertTo<MetresPerSecond>>(
+    ground_speed: G,
+    angle: Degrees,
+) -> FeetPerMinute {
+    Gradient::from_angle(angle).vertical_speed(ground_speed)
+}
+
+#[cfg(test)]
+#[allow(clippy::float_cmp)]
+mod tests {
+    #[cfg(any(feature = "std", feature = "libm"))]
+    use super::*;
+    #[cfg(any(feature = "std", feature = "libm"))]
+    use crate::non_si::Knots;
+
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[test]
+    fn test_descent() {
+        // the 3 × rule of thumb: 3 NM per 1000 ft
+        let distance = distance_for_descent(Feet(10_000.0), THREE_DEGREES);
+        assert!((distance.0 - 31.4).abs() < 0.05);
+        let altitude = altitude_to_lose(distance, THREE_DEGREES);
+        assert!((altitude.0 - 10_000.0).abs() < 1e-9);
+        assert!((altitude_to_lose(NauticalMiles(1.0), THREE_DEGREES).0 - 318.4).abs() < 0.05);
+
+        let metres = distance_for_descent(Metres(1000.0), Degrees(45.0));
+        assert!((Metres::from(metres).0 - 1000.0).abs() < 1e-9);
+
+        // the 5 × ground speed rule of thumb
+        let vertical_speed = vertical_speed_for(Knots(140.0), THREE_DEGREES);
+        assert!((vertical_speed.0 - 743.0).abs() < 0.05);
+        assert_eq!(
+            FeetPerMinute(0.0),
+            vertical_speed_for(Knots(0.0), THREE_DEGREES)
+        );
+    }
+}
@@ -0,0 +1,104 @@
+// Copyright (c) 2024 Ken Barker
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"),
+// to deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+// THE SOFTWARE.
+
+//! [diesel](https://crates.io/crates/diesel) `ToSql` and `FromSql`
+//! implementations for the unit types.
+//!
+//! The units are stored in `Double` columns, e.g. `DOUBLE PRECISION` in a
+//! `PostgreSQL` database.
+
+use crate::quantity::{Quantity, UnitMarker};
+use crate::{non_si, si};
+use ::diesel::{
+    backend::Backend,
+    deserialize::{self, FromSql},
+    serialize::{self, Output, ToSql},
+    sql_types::Double,
+};
+
+/// Implements the `diesel` traits for the type `$t` with the generic
+/// parameters `$g` and the constructor `$new` from an `f64`.
+macro_rules! impl_diesel {
+    (impl<$($g:ident: $bound:path),*> $t:ty, $new:path) => {
+        impl<$($g: $bound,)* DB: Backend> ToSql<Double, DB> for $t
+        where
+            f64: ToSql<Double, DB>,
+        {
+            fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, DB>) -> serialize::Result {
+                <f64 as ToSql<Double, DB>>::to_sql(&self.0, out)
+            }
+        }
+
+        impl<$($g: $bound,)* DB: Backend> FromSql<Double, DB> for $t
+        where
+            f64: FromSql<Double, DB>,
+        {
+            fn from_sql(bytes: DB::RawValue<'_>) -> deserialize::Result<Self> {
+                <f64 as FromSql<Double, DB>>::from_sql(bytes).map($new)
+            }
+        }
+    };
+    ($t:ty, $new:path) => {
+        impl_diesel!(impl<> $t, $new);
+    };
+}
+
+impl_diesel!(impl<U: UnitMarker> Quantity<U>, Quantity::new);
+impl_diesel!(non_si::Fahrenheit, non_si::Fahrenheit);
+impl_diesel!(si::Celsius, si::Celsius);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ::diesel::{
+        dsl::select, sqlite::Sqlite, Connection, IntoSql, RunQueryDsl, SqliteConnection,
+    };
+
+    fn assert_diesel<T: ToSql<Double, Sqlite> + FromSql<Double, Sqlite>>() {}
+
+    #[test]
+    fn test_diesel_traits() {
+        assert_diesel::<si::Metres>();
+        assert_diesel::<si::Kelvin>();
+        assert_diesel::<non_si::Feet>();
+        assert_diesel::<non_si::Knots>();
+        assert_diesel::<non_si::Fahrenheit>();
+        assert_diesel::<si::Celsius>();
+    }
+
+    #[test]
+    fn test_diesel_round_trip() {
+        let mut conn = SqliteConnection::establish(":memory:").unwrap();
+        let altitude = select(non_si::Feet(35_000.0).into_sql::<Double>())
+            .get_result::<non_si::Feet>(&mut conn)
+            .unwrap();
+        assert_eq!(non_si::Feet(35_000.0), altitude);
+
+        let speed = select(non_si::Knots(250.0).into_sql::<Double>())
+            .get_result::<non_si::Knots>(&mut conn)
+            .unwrap();
+        assert_eq!(non_si::Knots(250.0), speed);
+
+        let temperature = select(si::Celsius(-56.5).into_sql::<Double>())
+            .get_result::<si::Celsius>(&mut conn)
+            .unwrap();
+        assert_eq!(si::Celsius(-56.5), temperature);
+    }
+}
@@ -0,0 +1,309 @@
+// Copyright (c) 2024 Ken Barker
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"),
+// to deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+// THE SOFTWARE.
+
+//! Quantities with compile-time dimensional analysis.
+//!
+//! A [`Quantity`] stores a value in SI units together with the exponents of
+//! its length (L), mass (M), time (T) and temperature (Θ) dimensions as
+//! [typenum](https://crates.io/crates/typenum) type-level integers.
+//! Multiplying or dividing quantities derives the dimension of the result at
+//! compile time, while adding or subtracting quantities of different
+//! dimensions fails to compile, e.g.:
+//!
+//! ```
+//! use icao_units::dimensions::{Length, Speed, Time};
+//! use icao_units::si::{Metres, MetresPerSecond, Seconds};
+//!
+//! let speed = Speed::from(MetresPerSecond(100.0));
+//! let time = Time::from(Seconds(60.0));
+//! let distance: Length = speed * time;
+//! assert_eq!(Metres(6000.0), Metres::from(distance));
+//! assert_eq!(speed, distance / time);
+//! ```
+
+use crate::{non_si, si};
+use core::{
+    marker::PhantomData,
+    ops::{Add, AddAssign, Div, Mul, Neg, Sub, SubAssign},
+};
+use typenum::{Diff, Sum, N1, N2, N3, P1, P2, P3, Z0};
+
+/// A quantity with the dimension `L^L M^M T^T Θ^Th`, stored in SI units.
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+#[repr(transparent)]
+pub struct Quantity<L, M, T, Th> {
+    value: f64,
+    dimension: PhantomData<(L, M, T, Th)>,
+}
+
+impl<L, M, T, Th> Quantity<L, M, T, Th> {
+    /// Construct a quantity from a value in SI units.
+    #[must_use]
+    pub const fn new(value: f64) -> Self {
+        Self {
+            value,
+            dimension: PhantomData,
+        }
+    }
+
+    /// The value of the quantity in SI units.
+    #[must_use]
+    pub const fn value(self) -> f64 {
+        self.value
+    }
+}
+
+/// A quantity without dimension, e.g. a ratio.
+pub type Dimensionless = Quantity<Z0, Z0, Z0, Z0>;
+/// A length in metres.
+pub type Length = Quantity<P1, Z0, Z0, Z0>;
+/// A mass in kilograms.
+pub type Mass = Quantity<Z0, P1, Z0, Z0>;
+/// A time in seconds.
+pub type Time = Quantity<Z0, Z0, P1, Z0>;
+/// A thermodynamic temperature in kelvin.
+pub type Temperature = Quantity<Z0, Z0, Z0, P1>;
+/// An area in square metres.
+pub type Area = Quantity<P2, Z0, Z0, Z0>;
+/// A volume in cubic metres.
+pub type Volume = Quantity<P3, Z0, Z0, Z0>;
+/// A speed in metres per second.
+pub type Speed = Quantity<P1, Z0, N1, Z0>;
+/// An acceleration in metres per second squared.
+pub type Acceleration = Quantity<P1, Z0, N2, Z0>;
+/// A density in kilograms per cubic metre.
+pub type Density = Quantity<N3, P1, Z0, Z0>;
+/// A force in newtons.
+pub type Force = Quantity<P1, P1, N2, Z0>;
+/// A pressure in pascals.
+pub type Pressure = Quantity<N1, P1, N2, Z0>;
+/// A mass flow in kilograms per second.
+pub type MassFlow = Quantity<Z0, P1, N1, Z0>;
+/// A specific range in metres per kilogram.
+pub type SpecificRange = Quantity<P1, N1, Z0, Z0>;
+/// A volume flow in cubic metres per second.
+pub type VolumeFlow = Quantity<P3, Z0, N1, Z0>;
+/// A moment of a mass about a datum in kilogram metres.
+pub type Moment = Quantity<P1, P1, Z0, Z0>;
+/// A dynamic viscosity in pascal seconds.
+pub type DynamicViscosity = Quantity<N1, P1, N1, Z0>;
+/// A kinematic viscosity in square metres per second.
+pub type KinematicViscosity = Quantity<P2, Z0, N1, Z0>;
+/// A specific heat capacity or specific gas constant in joules per kilogram kelvin.
+pub type SpecificHeatCapacity = Quantity<P2, Z0, N2, N1>;
+/// A rate of change of pressure in pascals per second.
+pub type PressureRate = Quantity<N1, P1, N3, Z0>;
+/// A rate of change of speed with height, e.g. wind shear, in metres per second per metre.
+pub type VelocityGradient = Quantity<Z0, Z0, N1, Z0>;
+/// A power in watts.
+pub type Power = Quantity<P2, P1, N3, Z0>;
+
+impl<L, M, T, Th> Add for Quantity<L, M, T, Th> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self::new(self.value + rhs.value)
+    }
+}
+
+impl<L, M, T, Th> AddAssign for Quantity<L, M, T, Th> {
+    fn add_assign(&mut self, rhs: Self) {
+        self.value += rhs.value;
+    }
+}
+
+impl<L, M, T, Th> Sub for Quantity<L, M, T, Th> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self::new(self.value - rhs.value)
+    }
+}
+
+impl<L, M, T, Th> SubAssign for Quantity<L, M, T, Th> {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.value -= rhs.value;
+    }
+}
+
+impl<L, M, T, Th> Neg for Quantity<L, M, T, Th> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.value)
+    }
+}
+
+impl<L, M, T, Th> Mul<f64> for Quantity<L, M, T, Th> {
+    type Output = Self;
+
+    fn mul(self, rhs: f64) -> Self {
+        Self::new(self.value * rhs)
+    }
+}
+
+impl<L, M, T, Th> Div<f64> for Quantity<L, M, T, Th> {
+    type Output = Self;
+
+    fn div(self, rhs: f64) -> Self {
+        Self::new(self.value / rhs)
+    }
+}
+
+impl<L1, M1, T1, Th1, L2, M2, T2, Th2> Mul<Quantity<L2, M2, T2, Th2>> for Quantity<L1, M1, T1, Th1>
+where
+    L1: Add<L2>,
+    M1: Add<M2>,
+    T1: Add<T2>,
+    Th1: Add<Th2>,
+{
+    type Output = Quantity<Sum<L1, L2>, Sum<M1, M2>, Sum<T1, T2>, Sum<Th1, Th2>>;
+
+    fn mul(self, rhs: Quantity<L2, M2, T2, Th2>) -> Self::Output {
+        Quantity::new(self.value * rhs.value)
+    }
+}
+
+impl<L1, M1, T1, Th1, L2, M2, T2, Th2> Div<Quantity<L2, M2, T2, Th2>> for Quantity<L1, M1, T1, Th1>
+where
+    L1: Sub<L2>,
+    M1: Sub<M2>,
+    T1: Sub<T2>,
+    Th1: Sub<Th2>,
+{
+    type Output = Quantity<Diff<L1, L2>, Diff<M1, M2>, Diff<T1, T2>, Diff<Th1, Th2>>;
+
+    fn div(self, rhs: Quantity<L2, M2, T2, Th2>) -> Self::Output {
+        Quantity::new(self.value / rhs.value)
+    }
+}
+
+impl From<Dimensionless> for f64 {
+    fn from(a: Dimensionless) -> Self {
+        a.value
+    }
+}
+
+/// Implements `From` conversions between unit types and quantities.
+macro_rules! impl_quantity_conversion {
+    ($($unit:ty => $quantity:ty),*) => {
+        $(
+            impl From<$unit> for $quantity {
+                fn from(a: $unit) -> Self {
+                    Self::new(crate::Unit::si_value(a))
+                }
+            }
+
+            impl From<$quantity> for $unit {
+                fn from(a: $quantity) -> Self {
+                    crate::Unit::from_si_value(a.value)
+                }
+            }
+        )*
+    };
+}
+
+impl_quantity_conversion!(
+    si::Metres => Length,
+    si::MetresPerSecond => Speed,
+    si::MetresPerSecondSquared => Acceleration,
+    si::Kelvin => Temperature,
+    si::Pascals => Pressure,
+    si::Kilograms => Mass,
+    si::KilogramsPerCubicMetre => Density,
+    si::Seconds => Time,
+    si::KilogramsPerSecond => MassFlow,
+    si::MetresPerKilogram => SpecificRange,
+    si::CubicMetres => Volume,
+    si::CubicMetresPerSecond => VolumeFlow,
+    si::KilogramMetres => Moment,
+    non_si::NauticalMiles => Length,
+    non_si::Feet => Length,
+    non_si::Knots => Speed,
+    non_si::FeetPerMinute => Speed,
+    non_si::Hours => Time,
+    non_si::KilogramsPerHour => MassFlow,
+    non_si::NauticalMilesPerKilogram => SpecificRange,
+    non_si::KilogramsPerLitre => Density,
+    non_si::Litres => Volume,
+    non_si::USGallons => Volume,
+    non_si::LitresPerHour => VolumeFlow,
+    non_si::USGallonsPerHour => VolumeFlow,
+    non_si::Psi => Pressure,
+    non_si::Hectopascals => Pressure,
+    non_si::Millibars => Pressure,
+    non_si::InchesOfMercury => Pressure,
+    si::PascalSeconds => DynamicViscosity,
+    si::SquareMetresPerSecond => KinematicViscosity,
+    si::JoulesPerKilogramKelvin => SpecificHeatCapacity,
+    si::PascalsPerSecond => PressureRate,
+    non_si::HectopascalsPerHour => PressureRate,
+    si::MetresPerSecondPerMetre => VelocityGradient,
+    non_si::KnotsPerHundredFeet => VelocityGradient,
+    si::Watts => Power
+);
+
+#[cfg(test)]
+#[allow(clippy::float_cmp)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dimensions() {
+        let speed = Speed::from(si::MetresPerSecond(100.0));
+        let time = Time::from(si::Seconds(60.0));
+        let distance: Length = speed * time;
+        assert_eq!(si::Metres(6000.0), si::Metres::from(distance));
+        assert_eq!(speed, distance / time);
+        assert_eq!(
+            Length::new(1852.0),
+            Length::from(non_si::NauticalMiles(1.0))
+        );
+        assert_eq!(
+            non_si::Feet(999.999_999_999_999_9),
+            non_si::Feet::from(Length::new(304.8))
+        );
+
+        let area: Area = distance * distance;
+        assert_eq!(36_000_000.0, area.value());
+        let volume: Volume = area * distance;
+        let density: Density = Mass::from(si::Kilograms(1.225)) / Volume::new(1.0);
+        assert_eq!(si::KilogramsPerCubicMetre(1.225), density.into());
+        assert_eq!(216.0, f64::from(volume / Volume::new(1e9)));
+
+        let acceleration: Acceleration = speed / time;
+        let force: Force = Mass::new(2.0) * acceleration;
+        let pressure: Pressure = force / Area::new(0.5);
+        assert_eq!(si::Pascals(20.0 / 3.0), pressure.into());
+
+        let mut total = Length::new(1.0) + Length::new(2.0);
+        total -= Length::new(0.5);
+        total += Length::new(1.0);
+        assert_eq!(Length::new(3.5), total);
+        assert_eq!(Length::new(-1.5), Length::new(1.0) - Length::new(2.5));
+        assert_eq!(Length::new(-3.5), -total);
+        assert_eq!(Length::new(7.0), total * 2.0);
+        assert_eq!(Length::new(1.75), total / 2.0);
+        assert!(Length::new(1.0) < total);
+
+        let temperature = Temperature::from(si::Kelvin(288.15));
+        assert_eq!(si::Kelvin(288.15), temperature.into());
+    }
+}
@@ -0,0 +1,299 @@
+// Copyright (c) 2024 Ken Barker
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"),
+// to deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+// THE SOFTWARE.
+
+//! Integer (fixed-point) unit types, for deterministic calculations.
+//!
+//! Safety cases and replay systems may require calculations that give the
+//! same result on every platform, which floating point arithmetic cannot
+//! guarantee. The types in this module store an integer number of a small
+//! unit, e.g. `Millimetres(i64)`, with checked arithmetic that returns `None`
+//! on overflow instead of wrapping, e.g.:
+//!
+//! ```
+//! use icao_units::exact::Millimetres;
+//! use icao_units::si::Metres;
+//!
+//! let a = Millimetres(1_500);
+//! assert_eq!(Some(Millimetres(3_000)), a.checked_add(a));
+//! assert_eq!(None, Millimetres::MAX.checked_add(a));
+//! assert_eq!(Metres(1.5), Metres::from(a));
+//! ```
+//!
+//! Each type can be converted to its `f64` equivalent with the `From` trait
+//! and from it with the `TryFrom` trait, which rounds to the nearest integer
+//! and fails if the value is not finite or out of range.
+//!
+//! The `FeetI32` and `KnotsI16` types match the ranges and resolutions of the
+//! altitude and speed values transmitted on avionics data buses.
+
+#![allow(
+    clippy::cast_lossless,
+    clippy::cast_possible_truncation,
+    clippy::cast_precision_loss
+)]
+
+use crate::{non_si, si};
+use core::fmt;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The error returned when converting a value that is not finite or is out
+/// of range to an integer unit type.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct OutOfRange(pub f64);
+
+impl fmt::Display for OutOfRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "value out of range: {}", self.0)
+    }
+}
+
+impl core::error::Error for OutOfRange {}
+
+/// Defines an integer unit type with checked arithmetic and the conversions
+/// to and from its `f64` equivalent, where `$scale` is the number of integer units
+/// in one unit of the `f64` type.
+macro_rules! exact_unit {
+    ($(#[$attr:meta])* $t:ident, $int:ty, $f64_t:ty, $scale:expr) => {
+        $(#[$attr])*
+        #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+        #[repr(transparent)]
+        pub struct $t(pub $int);
+
+        impl $t {
+            /// Zero.
+            pub const ZERO: Self = Self(0);
+
+            /// The smallest value.
+            pub const MIN: Self = Self(<$int>::MIN);
+
+            /// The largest value.
+            pub const MAX: Self = Self(<$int>::MAX);
+
+            /// The number of integer units in one unit of the `f64` type.
+            pub const SCALE: $int = $scale;
+
+            /// Checked addition, `None` on overflow.
+            #[must_use]
+            pub const fn checked_add(self, rhs: Self) -> Option<Self> {
+                match self.0.checked_add(rhs.0) {
+                    Some(value) => Some(Self(value)),
+                    None => None,
+                }
+            }
+
+            /// Checked subtraction, `None` on overflow.
+            #[must_use]
+            pub const fn checked_sub(self, rhs: Self) -> Option<Self> {
+                match self.0.checked_sub(rhs.0) {
+                    Some(value) => Some(Self(value)),
+                    None => None,
+                }
+            }
+
+            /// Checked negation, `None` on overflow.
+            #[must_use]
+            pub const fn checked_neg(self) -> Option<Self> {
+                match self.0.checked_neg() {
+                    Some(value) => Some(Self(value)),
+                    None => None,
+                }
+            }
+
+            /// Checked multiplication by an integer, `None` on overflow.
+            #[must_use]
+            pub const fn checked_mul(self, rhs: $int) -> Option<Self> {
+                match self.0.checked_mul(rhs) {
+                    Some(value) => Some(Self(value)),
+                    None => None,
+                }
+            }
+
+            /// Checked division by an integer, rounded towards zero,
+            /// `None` if `rhs` is zero or on overflow.
+            #[must_use]
+            pub const fn checked_div(self, rhs: $int) -> Option<Self> {
+                match self.0.checked_div(rhs) {
+                    Some(value) => Some(Self(value)),
+                    None => None,
+                }
+            }
+        }
+
+        impl From<$t> for $f64_t {
+            fn from(a: $t) -> Self {
+                Self::new(a.0 as f64 / $t::SCALE as f64)
+            }
+        }
+
+        impl TryFrom<$f64_t> for $t {
+            type Error = OutOfRange;
+
+            /// Convert to the nearest integer value.
+            /// Fails if the value is not finite or is out of range.
+            fn try_from(a: $f64_t) -> Result<Self, Self::Error> {
+                let value = crate::math::round(a.0 * $t::SCALE as f64);
+                // -MIN is a power of two, so it is exact as an f64, unlike MAX
+                if (<$int>::MIN as f64 <= value) && (value < -(<$int>::MIN as f64)) {
+                    Ok(Self(value as $int))
+                } else {
+                    Err(OutOfRange(a.0))
+                }
+            }
+        }
+    };
+}
+
+exact_unit!(
+    /// An integer number of millimetres, for representing distance.
+    Millimetres,
+    i64,
+    si::Metres,
+    1_000
+);
+
+exact_unit!(
+    /// An integer number of milliseconds, for representing time.
+    Milliseconds,
+    i64,
+    si::Seconds,
+    1_000
+);
+
+exact_unit!(
+    /// An integer number of hundredths of a knot, for representing speed.
+    CentiKnots,
+    i64,
+    non_si::Knots,
+    100
+);
+
+exact_unit!(
+    /// An integer number of feet, for representing altitude.
+    FeetI32,
+    i32,
+    non_si::Feet,
+    1
+);
+
+exact_unit!(
+    /// An integer number of knots, for representing speed.
+    KnotsI16,
+    i16,
+    non_si::Knots,
+    1
+);
+
+#[cfg(test)]
+#[allow(clippy::float_cmp)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_checked_arithmetic() {
+        let a = Millimetres(1_500);
+        assert_eq!(Some(Millimetres(3_000)), a.checked_add(a));
+        assert_eq!(Some(Millimetres::ZERO), a.checked_sub(a));
+        assert_eq!(Some(Millimetres(-1_500)), a.checked_neg());
+        assert_eq!(Some(Millimetres(4_500)), a.checked_mul(3));
+        assert_eq!(Some(Millimetres(750)), a.checked_div(2));
+
+        assert_eq!(None, Millimetres::MAX.checked_add(a));
+        assert_eq!(None, Millimetres::MIN.checked_sub(a));
+        assert_eq!(None, Millimetres::MIN.checked_neg());
+        assert_eq!(None, Millimetres::MAX.checked_mul(2));
+        assert_eq!(None, a.checked_div(0));
+        assert!(Millimetres::MIN < Millimetres::ZERO);
+    }
+
+    #[test]
+    fn test_conversions() {
+        assert_eq!(si::Metres(1.5), si::Metres::from(Millimetres(1_500)));
+        assert_eq!(si::Seconds(0.25), si::Seconds::from(Milliseconds(250)));
+        assert_eq!(
+            non_si::Knots(250.5),
+            non_si::Knots::from(CentiKnots(25_050))
+        );
+        assert_eq!(1_000, Millimetres::SCALE);
+        assert_eq!(100, CentiKnots::SCALE);
+
+        assert_eq!(
+            Ok(Millimetres(1_500)),
+            Millimetres::try_from(si::Metres(1.5))
+        );
+        assert_eq!(
+            Ok(CentiKnots(-25_050)),
+            CentiKnots::try_from(non_si::Knots(-250.504))
+        );
+        assert!(Millimetres::try_from(si::Metres(f64::NAN)).is_err());
+        assert_eq!(
+            Err(OutOfRange(1e16)),
+            Millimetres::try_from(si::Metres(1e16))
+        );
+
+        #[cfg(feature = "serde")]
+        {
+            let serialized = serde_json::to_string(&CentiKnots(25_050)).unwrap();
+            assert_eq!("25050", serialized);
+            let deserialized: CentiKnots = serde_json::from_str(&serialized).unwrap();
+            assert_eq!(CentiKnots(25_050), deserialized);
+        }
+    }
+
+    #[test]
+    fn test_avionics_types() {
+        assert_eq!(
+            Ok(FeetI32(35_000)),
+            FeetI32::try_from(non_si::Feet(34_999.6))
+        );
+        assert_eq!(non_si::Feet(35_000.0), non_si::Feet::from(FeetI32(35_000)));
+        assert_eq!(
+            Ok(KnotsI16(i16::MAX)),
+            KnotsI16::try_from(non_si::Knots(32_767.0))
+        );
+        assert_eq!(
+            Ok(KnotsI16(i16::MIN)),
+            KnotsI16::try_from(non_si::Knots(-32_768.0))
+        );
+        assert_eq!(
+            Err(OutOfRange(32_767.5)),
+            KnotsI16::try_from(non_si::Knots(32_767.5))
+        );
+        assert_eq!(
+            Err(OutOfRange(f64::INFINITY)),
+            FeetI32::try_from(non_si::Feet(f64::INFINITY))
+        );
+        assert_eq!(
+            "value out of range: 32767.5",
+            OutOfRange(32_767.5).to_string()
+        );
+        assert_eq!(None, KnotsI16(i16::MAX).checked_add(KnotsI16(1)));
+
+        #[cfg(feature = "serde")]
+        {
+            let serialized = serde_json::to_string(&FeetI32(-1_000)).unwrap();
+            assert_eq!("-1000", serialized);
+            let deserialized: FeetI32 = serde_json::from_str(&serialized).unwrap();
+            assert_eq!(FeetI32(-1_000), deserialized);
+            assert!(serde_json::from_str::<KnotsI16>("32768").is_err());
+        }
+    }
+}
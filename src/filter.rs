@@ -0,0 +1,235 @@
+// Copyright (c) 2024 Ken Barker
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"),
+// to deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+// THE SOFTWARE.
+
+//! Filters for smoothing noisy unit values, e.g. altitude and speed feeds in
+//! display systems.
+//!
+//! [`RateLimiter`] limits the rate of change of its output, e.g. to a vertical
+//! speed in `FeetPerMinute`, and [`LowPassFilter`] is a first order low-pass
+//! filter with a time constant in `Seconds`, e.g.:
+//!
+//! ```
+//! # #[cfg(any(feature = "std", feature = "libm"))]
+//! # {
+//! use icao_units::filter::{LowPassFilter, RateLimiter};
+//! use icao_units::non_si::{Feet, FeetPerMinute};
+//! use icao_units::si::Seconds;
+//!
+//! let mut limiter = RateLimiter::new(FeetPerMinute(6_000.0));
+//! assert_eq!(Feet(1_000.0), limiter.update(Feet(1_000.0), Seconds(1.0)));
+//! assert_eq!(Feet(1_100.0), limiter.update(Feet(2_000.0), Seconds(1.0)));
+//!
+//! let mut filter = LowPassFilter::new(Seconds(2.0));
+//! assert_eq!(Feet(1_000.0), filter.update(Feet(1_000.0), Seconds(1.0)));
+//! let altitude = filter.update(Feet(2_000.0), Seconds(1.0));
+//! assert_eq!(1_393.0, altitude.0.round());
+//! # }
+//! ```
+//!
+//! The first value passed to a filter initialises its output.
+
+use crate::si::Seconds;
+use crate::{math, Unit};
+use core::ops::{Add, Mul, Sub};
+
+/// Limits the rate of change of a unit value.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[allow(clippy::derive_partial_eq_without_eq)] // the unit types are not `Eq`
+pub struct RateLimiter<U, R> {
+    /// The maximum rate of change.
+    limit: R,
+    /// The output value, if any.
+    output: Option<U>,
+}
+
+impl<U, R> RateLimiter<U, R>
+where
+    U: Unit + Add<Output = U> + Sub<Output = U>,
+    R: Unit + Mul<Seconds, Output = U>,
+{
+    /// Construct a rate limiter with the maximum rate of change `limit`.
+    #[must_use]
+    pub const fn new(limit: R) -> Self {
+        Self {
+            limit,
+            output: None,
+        }
+    }
+
+    /// The maximum rate of change.
+    #[must_use]
+    pub const fn limit(&self) -> R {
+        self.limit
+    }
+
+    /// The output value, `None` before the first update.
+    #[must_use]
+    pub const fn output(&self) -> Option<U> {
+        self.output
+    }
+
+    /// Clear the output value, so that the next input initialises it.
+    pub const fn reset(&mut self) {
+        self.output = None;
+    }
+
+    /// Move the output towards `input` by no more than the limit over
+    /// `interval`, the time since the previous update.
+    ///
+    /// The change is not limited if the maximum change over `interval` is
+    /// NaN, e.g. if `interval` is NaN.
+    pub fn update(&mut self, input: U, interval: Seconds) -> U {
+        let output = self.output.map_or(input, |output| {
+            let max_change = math::abs((self.limit * interval).value());
+            // unlike clamp, max and min ignore a NaN max_change
+            let change = (input - output).value().max(-max_change).min(max_change);
+            output + U::new(change)
+        });
+        self.output = Some(output);
+        output
+    }
+}
+
+/// A first order low-pass filter of a unit value.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LowPassFilter<U> {
+    /// The time constant of the filter.
+    time_constant: Seconds,
+    /// The output value, if any.
+    output: Option<U>,
+}
+
+impl<U> LowPassFilter<U>
+where
+    U: Unit + Add<Output = U> + Sub<Output = U>,
+{
+    /// Construct a low-pass filter with `time_constant`, the time taken
+    /// to reach 63% of a step change in the input.
+    #[must_use]
+    pub const fn new(time_constant: Seconds) -> Self {
+        Self {
+            time_constant,
+            output: None,
+        }
+    }
+
+    /// The time constant of the filter.
+    #[must_use]
+    pub const fn time_constant(&self) -> Seconds {
+        self.time_constant
+    }
+
+    /// The output value, `None` before the first update.
+    #[must_use]
+    pub const fn output(&self) -> Option<U> {
+        self.output
+    }
+
+    /// Clear the output value, so that the next input initialises it.
+    pub const fn reset(&mut self) {
+        self.output = None;
+    }
+
+    /// Move the output towards `input` over `interval`, the time since the
+    /// previous update.
+    ///
+    /// The filter is discretised exactly for a constant input over the
+    /// interval, so it is stable for any interval. A zero time constant
+    /// passes the input through unfiltered.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    pub fn update(&mut self, input: U, interval: Seconds) -> U {
+        let output = self.output.map_or(input, |output| {
+            let gain = if self.time_constant.0 > 0.0 {
+                1.0 - math::exp(-interval.0 / self.time_constant.0)
+            } else {
+                1.0
+            };
+            output + U::new(gain * (input - output).value())
+        });
+        self.output = Some(output);
+        output
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::float_cmp)]
+mod tests {
+    use super::*;
+    #[cfg(any(feature = "std", feature = "libm"))]
+    use crate::non_si::Knots;
+    use crate::non_si::{Feet, FeetPerMinute};
+    use crate::si::{MetresPerSecond, MetresPerSecondSquared};
+
+    #[test]
+    fn test_rate_limiter() {
+        let mut limiter = RateLimiter::new(FeetPerMinute(3_000.0));
+        assert_eq!(FeetPerMinute(3_000.0), limiter.limit());
+        assert_eq!(None, limiter.output());
+        assert_eq!(Feet(5_000.0), limiter.update(Feet(5_000.0), Seconds(2.0)));
+        assert_eq!(Feet(4_900.0), limiter.update(Feet(4_000.0), Seconds(2.0)));
+        assert_eq!(Feet(4_920.0), limiter.update(Feet(4_920.0), Seconds(2.0)));
+        assert_eq!(Some(Feet(4_920.0)), limiter.output());
+        limiter.reset();
+        assert_eq!(Feet(0.0), limiter.update(Feet(0.0), Seconds(2.0)));
+
+        let mut speed = RateLimiter::new(MetresPerSecondSquared(2.0));
+        speed.update(MetresPerSecond(100.0), Seconds(0.5));
+        assert_eq!(
+            MetresPerSecond(101.0),
+            speed.update(MetresPerSecond(110.0), Seconds(0.5))
+        );
+
+        // a NaN maximum change does not limit the output
+        let mut limiter = RateLimiter::new(FeetPerMinute(0.0));
+        limiter.update(Feet(1_000.0), Seconds(1.0));
+        assert_eq!(Feet(1_000.0), limiter.update(Feet(2_000.0), Seconds(1.0)));
+        assert_eq!(
+            Feet(2_000.0),
+            limiter.update(Feet(2_000.0), Seconds(f64::INFINITY))
+        );
+        assert_eq!(
+            Feet(3_000.0),
+            limiter.update(Feet(3_000.0), Seconds(f64::NAN))
+        );
+    }
+
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[test]
+    fn test_low_pass_filter() {
+        let mut filter = LowPassFilter::new(Seconds(10.0));
+        assert_eq!(Seconds(10.0), filter.time_constant());
+        assert_eq!(None, filter.output());
+        assert_eq!(Knots(250.0), filter.update(Knots(250.0), Seconds(1.0)));
+        // a step change reaches 63% after one time constant
+        let speed = filter.update(Knots(350.0), Seconds(10.0));
+        assert_eq!(313.212, (speed.0 * 1_000.0).round() / 1_000.0);
+
+        filter.reset();
+        filter.update(Knots(0.0), Seconds(1.0));
+        for _ in 0..100 {
+            filter.update(Knots(1.0), Seconds(1.0));
+        }
+        assert!((Knots(1.0) - filter.output().unwrap()).0 < 1e-4);
+
+        let mut unfiltered = LowPassFilter::new(Seconds(0.0));
+        unfiltered.update(Feet(0.0), Seconds(1.0));
+        assert_eq!(Feet(100.0), unfiltered.update(Feet(100.0), Seconds(1.0)));
+    }
+}
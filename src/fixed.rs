@@ -0,0 +1,120 @@
+// Copyright (c) 2024 Ken Barker
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"),
+// to deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+// THE SOFTWARE.
+
+//! Conversions between the unit types and [fixed](https://crates.io/crates/fixed)
+//! point numbers, for embedded systems without floating point hardware.
+//!
+//! The resolution and range of a conversion are defined by the fixed point type, e.g.:
+//!
+//! | Type     | Q format | Resolution | Range                 |
+//! |----------|----------|------------|-----------------------|
+//! | `I16F16` | Q16.16   | 1/65 536   | ±32 768               |
+//! | `I24F8`  | Q24.8    | 1/256      | ±8 388 608            |
+//! | `I32F32` | Q32.32   | 1/2³²      | ±2 147 483 648        |
+//!
+//! So `Metres` stored in an `I24F8` have a resolution of 1/256 m and
+//! `Feet` stored in an `I16F16` have a range of ±32 768 ft.
+//!
+//! Values outside of the range of the fixed point type saturate at its
+//! minimum or maximum value, see [`FixedUnit::saturating_to_fixed`].
+
+use crate::Unit;
+use ::fixed::traits::Fixed;
+
+/// Conversions between a unit type and fixed point numbers.
+///
+/// Implemented for all types that implement [`Unit`].
+pub trait FixedUnit: Unit {
+    /// Convert the unit value to a fixed point number, rounding to the
+    /// nearest value and saturating at the fixed point minimum and maximum
+    /// values. `NaN` is converted to zero.
+    #[must_use]
+    fn saturating_to_fixed<F: Fixed>(self) -> F {
+        let value = self.value();
+        if value.is_nan() {
+            F::ZERO
+        } else {
+            F::saturating_from_num(value)
+        }
+    }
+
+    /// Convert the unit value to a fixed point number, rounding to the
+    /// nearest value.
+    ///
+    /// returns `None` if the value is outside the range of the fixed point
+    /// type, or `NaN`.
+    #[must_use]
+    fn checked_to_fixed<F: Fixed>(self) -> Option<F> {
+        F::checked_from_num(self.value())
+    }
+
+    /// Construct a unit value from a fixed point number.
+    #[must_use]
+    fn from_fixed<F: Fixed>(value: F) -> Self {
+        Self::new(value.to_num::<f64>())
+    }
+}
+
+impl<T: Unit> FixedUnit for T {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{non_si, si};
+    use ::fixed::types::{I16F16, I24F8};
+
+    #[test]
+    fn test_to_fixed() {
+        let result: I24F8 = si::Metres(1.5).saturating_to_fixed();
+        assert_eq!(I24F8::from_num(1.5), result);
+
+        // rounds to the nearest 1/256 m
+        let result: I24F8 = si::Metres(1.0 + 1.0 / 1024.0).saturating_to_fixed();
+        assert_eq!(I24F8::ONE, result);
+        let result: I24F8 = si::Metres(1.0 + 3.0 / 1024.0).saturating_to_fixed();
+        assert_eq!(I24F8::ONE + I24F8::DELTA, result);
+
+        let result: Option<I16F16> = non_si::Feet(1000.25).checked_to_fixed();
+        assert_eq!(Some(I16F16::from_num(1000.25)), result);
+    }
+
+    #[test]
+    fn test_to_fixed_saturation() {
+        let result: I16F16 = non_si::Feet(45_000.0).saturating_to_fixed();
+        assert_eq!(I16F16::MAX, result);
+        let result: I16F16 = non_si::Feet(-45_000.0).saturating_to_fixed();
+        assert_eq!(I16F16::MIN, result);
+        let result: I16F16 = non_si::Feet(f64::NAN).saturating_to_fixed();
+        assert_eq!(I16F16::ZERO, result);
+
+        let result: Option<I16F16> = non_si::Feet(45_000.0).checked_to_fixed();
+        assert!(result.is_none());
+        let result: Option<I16F16> = non_si::Feet(f64::NAN).checked_to_fixed();
+        assert!(result.is_none());
+    }
+
+    #[test]
+    fn test_from_fixed() {
+        let value = I24F8::from_num(1852.5);
+        assert_eq!(si::Metres(1852.5), si::Metres::from_fixed(value));
+        let value = I16F16::from_num(-250.75);
+        assert_eq!(non_si::Knots(-250.75), non_si::Knots::from_fixed(value));
+    }
+}
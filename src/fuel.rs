@@ -0,0 +1,107 @@
+// Copyright (c) 2024 Ken Barker
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"),
+// to deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+// THE SOFTWARE.
+
+//! Nominal densities of aviation fuels.
+//!
+//! The density of a fuel depends on its batch and temperature, so the
+//! measured density should be used where it is known. The nominal densities
+//! are typical values at the reference temperature of the fuel
+//! specification, for planning, e.g.:
+//!
+//! ```
+//! use icao_units::fuel::JET_A1;
+//! use icao_units::non_si::KilogramsPerLitre;
+//! use icao_units::si::KilogramsPerCubicMetre;
+//!
+//! assert_eq!(KilogramsPerCubicMetre(804.0), JET_A1.density);
+//! assert_eq!(KilogramsPerLitre(0.804), JET_A1.kilograms_per_litre());
+//! ```
+
+use crate::non_si::KilogramsPerLitre;
+use crate::si::{Kelvin, KilogramsPerCubicMetre};
+
+/// A nominal fuel density at a reference temperature.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct NominalDensity {
+    /// The density of the fuel.
+    pub density: KilogramsPerCubicMetre,
+    /// The temperature at which the density applies.
+    pub reference_temperature: Kelvin,
+}
+
+impl NominalDensity {
+    /// The density of the fuel in kilograms per litre.
+    #[must_use]
+    pub const fn kilograms_per_litre(self) -> KilogramsPerLitre {
+        KilogramsPerLitre::from_kilograms_per_cubic_metre(self.density)
+    }
+}
+
+/// 15 °C, the reference temperature of the Jet A, Jet A-1 and Avgas
+/// specifications.
+const FIFTEEN_CELSIUS: Kelvin = Kelvin(Kelvin::ICE_POINT.0 + 15.0);
+
+/// 20 °C, the reference temperature of the TS-1 specification.
+const TWENTY_CELSIUS: Kelvin = Kelvin(Kelvin::ICE_POINT.0 + 20.0);
+
+/// Jet A-1 kerosene: 0.804 kg/L at 15 °C,
+/// within the 0.775 to 0.840 kg/L range of DEF STAN 91-091.
+pub const JET_A1: NominalDensity = NominalDensity {
+    density: KilogramsPerCubicMetre(804.0),
+    reference_temperature: FIFTEEN_CELSIUS,
+};
+
+/// Jet A kerosene: 0.820 kg/L at 15 °C,
+/// within the 0.775 to 0.840 kg/L range of ASTM D1655.
+pub const JET_A: NominalDensity = NominalDensity {
+    density: KilogramsPerCubicMetre(820.0),
+    reference_temperature: FIFTEEN_CELSIUS,
+};
+
+/// TS-1 kerosene: 0.787 kg/L at 20 °C,
+/// above the 0.775 kg/L minimum of GOST 10227.
+pub const TS_1: NominalDensity = NominalDensity {
+    density: KilogramsPerCubicMetre(787.0),
+    reference_temperature: TWENTY_CELSIUS,
+};
+
+/// Avgas 100LL: 0.721 kg/L at 15 °C, see ASTM D910.
+pub const AVGAS: NominalDensity = NominalDensity {
+    density: KilogramsPerCubicMetre(721.0),
+    reference_temperature: FIFTEEN_CELSIUS,
+};
+
+#[cfg(test)]
+#[allow(clippy::float_cmp)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_nominal_densities() {
+        assert_eq!(Kelvin(288.15), JET_A1.reference_temperature);
+        assert_eq!(Kelvin(293.15), TS_1.reference_temperature);
+        assert_eq!(KilogramsPerLitre(0.82), JET_A.kilograms_per_litre());
+        assert_eq!(KilogramsPerLitre(0.787), TS_1.kilograms_per_litre());
+        assert_eq!(KilogramsPerLitre(0.721), AVGAS.kilograms_per_litre());
+        for fuel in [JET_A1, JET_A, TS_1, AVGAS] {
+            assert!((0.7..0.85).contains(&fuel.kilograms_per_litre().0));
+        }
+    }
+}
@@ -0,0 +1,153 @@
+// Copyright (c) 2024 Ken Barker
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"),
+// to deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+// THE SOFTWARE.
+
+//! Climb and descent gradients.
+//!
+//! PANS-OPS procedures and AIP publications express gradients as a
+//! percentage, an angle or in feet per nautical mile. A [`Gradient`] can be
+//! constructed from and converted to each of them, and relates ground speed
+//! to vertical speed, e.g.:
+//!
+//! ```
+//! use icao_units::gradient::Gradient;
+//! use icao_units::non_si::Knots;
+//!
+//! // the PANS-OPS minimum climb gradient
+//! let gradient = Gradient::from_percent(3.3);
+//! assert_eq!(201.0, gradient.feet_per_nautical_mile().round());
+//! assert_eq!(501.0, gradient.vertical_speed(Knots(150.0)).0.round());
+//! ```
+
+#[cfg(any(feature = "std", feature = "libm"))]
+use crate::math;
+#[cfg(any(feature = "std", feature = "libm"))]
+use crate::non_si::Degrees;
+use crate::non_si::{FeetPerMinute, Knots, METRES_PER_FOOT, METRES_PER_NAUTICAL_MILE};
+use crate::si::MetresPerSecond;
+use crate::ConvertTo;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The number of feet in a nautical mile.
+const FEET_PER_NAUTICAL_MILE: f64 = METRES_PER_NAUTICAL_MILE / METRES_PER_FOOT;
+
+/// A climb or descent gradient: the ratio of the height gained to the
+/// horizontal distance travelled, negative for a descent.
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[repr(transparent)]
+pub struct Gradient(pub f64);
+
+impl Gradient {
+    /// Construct a gradient from a percentage, e.g. 3.3 for 3.3%.
+    #[must_use]
+    pub fn from_percent(percent: f64) -> Self {
+        Self(percent / 100.0)
+    }
+
+    /// The gradient as a percentage.
+    #[must_use]
+    pub fn percent(self) -> f64 {
+        self.0 * 100.0
+    }
+
+    /// Construct a gradient from a flight path angle.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[must_use]
+    pub fn from_angle(angle: Degrees) -> Self {
+        Self(math::tan(angle.to_radians().0))
+    }
+
+    /// The flight path angle of the gradient.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[must_use]
+    pub fn angle(self) -> Degrees {
+        Degrees(math::atan(self.0).to_degrees())
+    }
+
+    /// Construct a gradient from feet per nautical mile.
+    #[must_use]
+    pub fn from_feet_per_nautical_mile(feet: f64) -> Self {
+        Self(feet / FEET_PER_NAUTICAL_MILE)
+    }
+
+    /// The gradient in feet per nautical mile.
+    #[must_use]
+    pub fn feet_per_nautical_mile(self) -> f64 {
+        self.0 * FEET_PER_NAUTICAL_MILE
+    }
+
+    /// Construct the gradient flown at a `vertical_speed` and `ground_speed`.
+    #[must_use]
+    pub fn from_speeds<V, G>(vertical_speed: V, ground_speed: G) -> Self
+    where
+        V: ConvertTo<MetresPerSecond>,
+        G: ConvertTo<MetresPerSecond>,
+    {
+        Self(vertical_speed.convert().0 / ground_speed.convert().0)
+    }
+
+    /// The vertical speed required to fly the gradient at a `ground_speed`.
+    #[must_use]
+    pub fn vertical_speed<G: ConvertTo<MetresPerSecond>>(self, ground_speed: G) -> FeetPerMinute {
+        FeetPerMinute::from(MetresPerSecond(self.0 * ground_speed.convert().0))
+    }
+
+    /// The ground speed that flies the gradient at a `vertical_speed`.
+    #[must_use]
+    pub fn ground_speed<V: ConvertTo<MetresPerSecond>>(self, vertical_speed: V) -> Knots {
+        Knots::from(MetresPerSecond(vertical_speed.convert().0 / self.0))
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::float_cmp)]
+mod tests {
+    #[cfg(any(feature = "std", feature = "libm"))]
+    use super::*;
+
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[test]
+    fn test_gradient() {
+        let gradient = Gradient::from_percent(5.0);
+        assert_eq!(Gradient(0.05), gradient);
+        assert_eq!(5.0, gradient.percent());
+
+        let gradient = Gradient::from_angle(Degrees(45.0));
+        assert!((gradient.0 - 1.0).abs() < 1e-15);
+        assert!((gradient.angle().0 - 45.0).abs() < 1e-12);
+
+        let gradient = Gradient::from_feet_per_nautical_mile(FEET_PER_NAUTICAL_MILE);
+        assert_eq!(Gradient(1.0), gradient);
+        assert_eq!(FEET_PER_NAUTICAL_MILE, gradient.feet_per_nautical_mile());
+
+        // a 3° glide path is about 318 ft/NM and 5.2%
+        let glide_path = Gradient::from_angle(Degrees(-3.0));
+        assert_eq!(-318.0, glide_path.feet_per_nautical_mile().round());
+        assert_eq!(-5.2, (glide_path.percent() * 10.0).round() / 10.0);
+        // 5 × ground speed rule of thumb
+        let vertical_speed = glide_path.vertical_speed(Knots(140.0));
+        assert_eq!(-743.0, vertical_speed.0.round());
+
+        let gradient = Gradient::from_speeds(vertical_speed, Knots(140.0));
+        assert!((gradient.0 - glide_path.0).abs() < 1e-15);
+        assert!((glide_path.ground_speed(vertical_speed).0 - 140.0).abs() < 1e-12);
+    }
+}
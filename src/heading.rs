@@ -0,0 +1,117 @@
+// Copyright (c) 2024 Ken Barker
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"),
+// to deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+// THE SOFTWARE.
+
+//! True and magnetic headings.
+//!
+//! [`DegreesTrue`] and [`DegreesMagnetic`] are distinct types, so that a
+//! heading referenced to true north cannot be used where a heading
+//! referenced to magnetic north is expected. There is no `From` conversion
+//! between them: a heading can only be converted with a magnetic variation,
+//! which is positive east of true north, e.g.:
+//!
+//! ```
+//! use icao_units::heading::{DegreesMagnetic, DegreesTrue};
+//! use icao_units::non_si::Degrees;
+//!
+//! // variation 10° west
+//! let variation = Degrees(-10.0);
+//! let heading = DegreesTrue(355.0);
+//! assert_eq!(DegreesMagnetic(5.0), heading.to_magnetic(variation));
+//! assert_eq!(heading, DegreesMagnetic(5.0).to_true(variation));
+//! ```
+
+use crate::non_si::Degrees;
+
+quantity!(
+    /// A heading, track or bearing in degrees referenced to true north.
+    pub DegreesTrue,
+    DegreesTrueUnit,
+    "°T",
+    core::f64::consts::PI / 180.0
+);
+
+impl DegreesTrue {
+    /// Convert to a heading referenced to magnetic north, in the range
+    /// [0, 360), given the magnetic `variation`, positive east.
+    #[must_use]
+    pub fn to_magnetic(self, variation: Degrees) -> DegreesMagnetic {
+        DegreesMagnetic((Degrees(self.0) - variation).normalise().0)
+    }
+}
+
+quantity!(
+    /// A heading, track or bearing in degrees referenced to magnetic north.
+    pub DegreesMagnetic,
+    DegreesMagneticUnit,
+    "°M",
+    core::f64::consts::PI / 180.0
+);
+
+impl DegreesMagnetic {
+    /// Convert to a heading referenced to true north, in the range
+    /// [0, 360), given the magnetic `variation`, positive east.
+    #[must_use]
+    pub fn to_true(self, variation: Degrees) -> DegreesTrue {
+        DegreesTrue((Degrees(self.0) + variation).normalise().0)
+    }
+}
+
+impl From<DegreesTrue> for Degrees {
+    fn from(a: DegreesTrue) -> Self {
+        Self::new(a.0)
+    }
+}
+
+impl From<DegreesMagnetic> for Degrees {
+    fn from(a: DegreesMagnetic) -> Self {
+        Self::new(a.0)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::float_cmp)]
+mod tests {
+    use super::*;
+    use crate::Unit;
+
+    #[test]
+    fn test_true_and_magnetic() {
+        // variation 3° east
+        let variation = Degrees(3.0);
+        let heading = DegreesTrue(90.0);
+        let magnetic = heading.to_magnetic(variation);
+        assert_eq!(DegreesMagnetic(87.0), magnetic);
+        assert_eq!(heading, magnetic.to_true(variation));
+        assert_eq!(DegreesTrue(1.0), DegreesMagnetic(358.0).to_true(variation));
+        assert_eq!(Degrees(87.0), Degrees::from(magnetic));
+        assert_eq!(Degrees(90.0), Degrees::from(heading));
+        assert_eq!(core::f64::consts::FRAC_PI_2, heading.si_value());
+        assert_eq!("90°T", heading.to_string());
+        assert_eq!("87°M", magnetic.to_string());
+
+        #[cfg(feature = "serde")]
+        {
+            let serialized = serde_json::to_string(&magnetic).unwrap();
+            assert_eq!("87.0", serialized);
+            let deserialized: DegreesMagnetic = serde_json::from_str(&serialized).unwrap();
+            assert_eq!(magnetic, deserialized);
+        }
+    }
+}
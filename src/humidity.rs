@@ -0,0 +1,165 @@
+// Copyright (c) 2024 Ken Barker
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"),
+// to deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+// THE SOFTWARE.
+
+//! Relative humidity and dew point.
+//!
+//! The saturation vapour pressure of water over a plane surface of water is
+//! calculated with the Magnus approximation, using the coefficients
+//! recommended by the WMO, which is accurate to within 0.5% from -45 °C to
+//! 60 °C. Dew points and temperatures are in Celsius, as in METARs, e.g.:
+//!
+//! ```
+//! # #[cfg(any(feature = "std", feature = "libm"))]
+//! # {
+//! use icao_units::humidity::RelativeHumidity;
+//! use icao_units::si::Celsius;
+//!
+//! // METAR temperature and dew point: 30/20
+//! let temperature = Celsius(30.0);
+//! let dew_point = Celsius(20.0);
+//! let humidity = RelativeHumidity::from_dew_point(temperature, dew_point);
+//! assert_eq!(RelativeHumidity(55.0), humidity.round());
+//!
+//! let result = humidity.dew_point(temperature);
+//! assert!((dew_point.0 - result.0).abs() < 1e-9);
+//! # }
+//! ```
+
+use crate::math;
+#[cfg(any(feature = "std", feature = "libm"))]
+use crate::si::{Celsius, Pascals};
+use core::fmt;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The saturation vapour pressure of water at the ice point: 611.2 Pa.
+#[cfg(any(feature = "std", feature = "libm"))]
+const MAGNUS_PRESSURE: f64 = 611.2;
+
+/// The dimensionless Magnus coefficient: 17.62.
+#[cfg(any(feature = "std", feature = "libm"))]
+const MAGNUS_B: f64 = 17.62;
+
+/// The Magnus temperature coefficient in °C: 243.12.
+#[cfg(any(feature = "std", feature = "libm"))]
+const MAGNUS_C: f64 = 243.12;
+
+/// The saturation vapour pressure of water at a `temperature`, from the
+/// Magnus approximation.
+#[cfg(any(feature = "std", feature = "libm"))]
+#[must_use]
+pub fn saturation_vapour_pressure(temperature: Celsius) -> Pascals {
+    Pascals(MAGNUS_PRESSURE * math::exp(MAGNUS_B * temperature.0 / (MAGNUS_C + temperature.0)))
+}
+
+/// A relative humidity as a percentage, from 0 to 100.
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[repr(transparent)]
+pub struct RelativeHumidity(pub f64);
+
+impl RelativeHumidity {
+    /// Construct a relative humidity from a percentage.
+    #[must_use]
+    pub const fn new(percent: f64) -> Self {
+        Self(percent)
+    }
+
+    /// The relative humidity of air at `temperature` with a `dew_point`.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[must_use]
+    pub fn from_dew_point(temperature: Celsius, dew_point: Celsius) -> Self {
+        Self(
+            100.0 * saturation_vapour_pressure(dew_point).0
+                / saturation_vapour_pressure(temperature).0,
+        )
+    }
+
+    /// The dew point of air at `temperature` with this relative humidity.
+    ///
+    /// The relative humidity must be greater than zero.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[must_use]
+    pub fn dew_point(self, temperature: Celsius) -> Celsius {
+        let gamma =
+            math::ln(self.0 / 100.0) + MAGNUS_B * temperature.0 / (MAGNUS_C + temperature.0);
+        Celsius(MAGNUS_C * gamma / (MAGNUS_B - gamma))
+    }
+
+    /// The partial pressure of water vapour in air at `temperature` with this
+    /// relative humidity, e.g. for `isa::virtual_temperature`.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[must_use]
+    pub fn vapour_pressure(self, temperature: Celsius) -> Pascals {
+        Pascals(self.0 / 100.0 * saturation_vapour_pressure(temperature).0)
+    }
+
+    /// The relative humidity rounded to the nearest whole percent.
+    #[must_use]
+    pub fn round(self) -> Self {
+        Self(math::round(self.0))
+    }
+}
+
+impl fmt::Display for RelativeHumidity {
+    /// Formats the percentage followed by a percent sign, e.g. "72 %".
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        crate::__private::fmt_unit(self.0, "%", f)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::float_cmp)]
+mod tests {
+    #[cfg(any(feature = "std", feature = "libm"))]
+    use super::*;
+
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[test]
+    fn test_saturation_vapour_pressure() {
+        assert_eq!(
+            Pascals(611.2),
+            saturation_vapour_pressure(Celsius::ICE_POINT)
+        );
+        let pressure = saturation_vapour_pressure(Celsius(30.0));
+        assert_eq!(4_234.0, pressure.0.round());
+    }
+
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[test]
+    fn test_relative_humidity() {
+        let temperature = Celsius(15.0);
+        let dew_point = Celsius(10.0);
+        let humidity = RelativeHumidity::from_dew_point(temperature, dew_point);
+        assert_eq!(72.05, (humidity.0 * 100.0).round() / 100.0);
+        assert!((dew_point.0 - humidity.dew_point(temperature).0).abs() < 1e-9);
+        assert_eq!(
+            RelativeHumidity::new(100.0),
+            RelativeHumidity::from_dew_point(temperature, temperature)
+        );
+        assert!(
+            (saturation_vapour_pressure(dew_point).0 - humidity.vapour_pressure(temperature).0)
+                .abs()
+                < 1e-9
+        );
+        assert_eq!("72 %", humidity.round().to_string());
+        assert_eq!("72.0 %", format!("{humidity:.1}"));
+    }
+}
@@ -0,0 +1,150 @@
+// Copyright (c) 2024 Ken Barker
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"),
+// to deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+// THE SOFTWARE.
+
+//! Closed intervals of unit values.
+//!
+//! Airspace volumes and speed restrictions are intervals rather than points.
+//! An [`Interval`] contains its lower and upper limits, e.g.:
+//!
+//! ```
+//! use icao_units::interval::AltitudeBand;
+//! use icao_units::quantized::FlightLevel;
+//!
+//! let band = AltitudeBand::new(FlightLevel(195), FlightLevel(245));
+//! assert!(band.contains(FlightLevel(245)));
+//! assert!(!band.contains(FlightLevel(250)));
+//!
+//! let other = AltitudeBand::new(FlightLevel(215), FlightLevel(660));
+//! assert!(band.overlaps(other));
+//! assert_eq!(
+//!     Some(AltitudeBand::new(FlightLevel(215), FlightLevel(245))),
+//!     band.intersection(other)
+//! );
+//! ```
+
+use crate::non_si::Knots;
+use crate::quantized::FlightLevel;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// A closed interval from `lower` to `upper` inclusive.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Interval<T> {
+    /// The lower limit.
+    pub lower: T,
+    /// The upper limit.
+    pub upper: T,
+}
+
+/// A band of flight levels, e.g. of an airspace volume.
+pub type AltitudeBand = Interval<FlightLevel>;
+
+/// A range of speeds, e.g. of a speed restriction.
+pub type SpeedRange = Interval<Knots>;
+
+impl<T: PartialOrd + Copy> Interval<T> {
+    /// Construct an interval between two limits, in either order.
+    #[must_use]
+    pub fn new(a: T, b: T) -> Self {
+        if b < a {
+            Self { lower: b, upper: a }
+        } else {
+            Self { lower: a, upper: b }
+        }
+    }
+
+    /// Whether the interval contains `value`.
+    #[must_use]
+    pub fn contains(&self, value: T) -> bool {
+        self.lower <= value && value <= self.upper
+    }
+
+    /// Whether the interval and `other` have any values in common.
+    #[must_use]
+    pub fn overlaps(&self, other: Self) -> bool {
+        self.lower <= other.upper && other.lower <= self.upper
+    }
+
+    /// The values that the interval and `other` have in common, if any.
+    #[must_use]
+    pub fn intersection(&self, other: Self) -> Option<Self> {
+        self.overlaps(other).then(|| Self {
+            lower: if other.lower > self.lower {
+                other.lower
+            } else {
+                self.lower
+            },
+            upper: if other.upper < self.upper {
+                other.upper
+            } else {
+                self.upper
+            },
+        })
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::float_cmp)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_altitude_band() {
+        let band = AltitudeBand::new(FlightLevel(245), FlightLevel(195));
+        assert_eq!(FlightLevel(195), band.lower);
+        assert_eq!(FlightLevel(245), band.upper);
+        assert!(band.contains(FlightLevel(195)));
+        assert!(!band.contains(FlightLevel(190)));
+
+        // touching bands overlap at their common limit
+        let above = AltitudeBand::new(FlightLevel(245), FlightLevel(660));
+        assert!(band.overlaps(above));
+        assert_eq!(
+            Some(AltitudeBand::new(FlightLevel(245), FlightLevel(245))),
+            band.intersection(above)
+        );
+        let below = AltitudeBand::new(FlightLevel(0), FlightLevel(100));
+        assert!(!band.overlaps(below));
+        assert_eq!(None, band.intersection(below));
+        assert_eq!(Some(band), band.intersection(band));
+
+        #[cfg(feature = "serde")]
+        {
+            let serialized = serde_json::to_string(&band).unwrap();
+            assert_eq!(r#"{"lower":195,"upper":245}"#, serialized);
+            let deserialized: AltitudeBand = serde_json::from_str(&serialized).unwrap();
+            assert_eq!(band, deserialized);
+        }
+    }
+
+    #[test]
+    fn test_speed_range() {
+        let range = SpeedRange::new(Knots(210.0), Knots(250.0));
+        assert!(range.contains(Knots(230.0)));
+        assert!(!range.contains(Knots(250.1)));
+        let other = SpeedRange::new(Knots(180.0), Knots(220.0));
+        assert_eq!(
+            Some(SpeedRange::new(Knots(210.0), Knots(220.0))),
+            range.intersection(other)
+        );
+        assert!(!range.contains(Knots(f64::NAN)));
+    }
+}
@@ -0,0 +1,687 @@
+// Copyright (c) 2024 Ken Barker
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"),
+// to deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+// THE SOFTWARE.
+
+//! The ICAO [International Standard Atmosphere](https://en.wikipedia.org/wiki/International_Standard_Atmosphere) (ISA).
+//!
+//! See ICAO Doc 7488 Manual of the ICAO Standard Atmosphere.
+//!
+//! The functions take geopotential altitudes and model the ISA layers from
+//! the surface to 32 km; the troposphere is extended below mean sea level.
+//!
+//! The [`Table`] struct provides faster, interpolated pressure and density
+//! values over the operational envelope, e.g.:
+//!
+//! ```
+//! # #[cfg(any(feature = "std", feature = "libm"))]
+//! # {
+//! use icao_units::isa;
+//! use icao_units::si::{Metres, Pascals};
+//!
+//! assert_eq!(Pascals(101_325.0), isa::pressure(Metres(0.0)));
+//!
+//! let table = isa::Table::new();
+//! let pressure = table.pressure(Metres(3_000.0));
+//! assert!((pressure.0 - isa::pressure(Metres(3_000.0)).0).abs() < isa::Table::MAX_PRESSURE_ERROR.0);
+//! # }
+//! ```
+
+#[cfg(any(feature = "std", feature = "libm"))]
+use crate::math;
+use crate::si::{
+    JoulesPerKilogramKelvin, Kelvin, KelvinDelta, KilogramsPerCubicMetre, Metres, Pascals,
+};
+#[cfg(any(feature = "std", feature = "libm"))]
+use crate::si::{MetresPerSecond, MetresPerSecondSquared, PascalSeconds};
+
+/// The ISA mean sea level pressure.
+pub const SEA_LEVEL_PRESSURE: Pascals = Pascals::STANDARD_PRESSURE;
+
+/// The ISA mean sea level temperature.
+pub const SEA_LEVEL_TEMPERATURE: Kelvin = Kelvin(288.15);
+
+/// The ISA mean sea level density.
+pub const SEA_LEVEL_DENSITY: KilogramsPerCubicMetre = KilogramsPerCubicMetre(1.225);
+
+/// The nominal radius of the earth used to convert between geometric and
+/// geopotential altitudes.
+pub const EARTH_RADIUS: Metres = Metres(6_356_766.0);
+
+/// The specific gas constant of dry air in J/(kg·K).
+pub const SPECIFIC_GAS_CONSTANT: f64 = 287.052_87;
+
+/// The specific gas constant of dry air.
+pub const DRY_AIR_GAS_CONSTANT: JoulesPerKilogramKelvin =
+    JoulesPerKilogramKelvin(SPECIFIC_GAS_CONSTANT);
+
+/// The specific gas constant of water vapour.
+pub const WATER_VAPOUR_GAS_CONSTANT: JoulesPerKilogramKelvin = JoulesPerKilogramKelvin(461.5);
+
+/// The ratio of the specific heats of air, γ.
+pub const HEAT_CAPACITY_RATIO: f64 = 1.4;
+
+/// The geopotential altitude of the ISA tropopause, the boundary between
+/// the troposphere and the stratosphere.
+pub const TROPOPAUSE_ALTITUDE: Metres = Metres(11_000.0);
+
+/// The ISA temperature at and above the tropopause, up to 20 km.
+pub const TROPOPAUSE_TEMPERATURE: Kelvin = Kelvin(216.65);
+
+/// The standard acceleration of gravity in m/s².
+#[cfg(any(feature = "std", feature = "libm"))]
+const G0: f64 = MetresPerSecondSquared::STANDARD_GRAVITY.0;
+
+/// An ISA layer, in which the temperature varies linearly with altitude.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Layer {
+    /// The geopotential altitude of the base of the layer.
+    pub base_altitude: Metres,
+    /// The temperature at the base of the layer.
+    pub base_temperature: Kelvin,
+    /// The rate of change of temperature with altitude in K/m.
+    pub lapse_rate: f64,
+    /// The pressure at the base of the layer.
+    pub base_pressure: Pascals,
+}
+
+/// The ISA layers up to 32 km: the troposphere and the lower two layers of
+/// the stratosphere.
+///
+/// The base pressures are calculated from the base altitudes, temperatures
+/// and lapse rates of the layers below, with the ISA mean sea level pressure.
+pub const LAYERS: [Layer; 3] = [
+    Layer {
+        base_altitude: Metres(0.0),
+        base_temperature: SEA_LEVEL_TEMPERATURE,
+        lapse_rate: -0.006_5,
+        base_pressure: SEA_LEVEL_PRESSURE,
+    },
+    Layer {
+        base_altitude: TROPOPAUSE_ALTITUDE,
+        base_temperature: TROPOPAUSE_TEMPERATURE,
+        lapse_rate: 0.0,
+        base_pressure: Pascals(22_632.040_095_007_793),
+    },
+    Layer {
+        base_altitude: Metres(20_000.0),
+        base_temperature: TROPOPAUSE_TEMPERATURE,
+        lapse_rate: 0.001,
+        base_pressure: Pascals(5_474.877_424_281_046),
+    },
+];
+
+impl Layer {
+    /// The temperature at `altitude` in the layer.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn temperature(self, altitude: f64) -> f64 {
+        math::mul_add(
+            self.lapse_rate,
+            altitude - self.base_altitude.0,
+            self.base_temperature.0,
+        )
+    }
+
+    /// The ratio of the pressure at `altitude` to the pressure at the base
+    /// of the layer.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn pressure_ratio(self, altitude: f64) -> f64 {
+        if self.lapse_rate == 0.0 {
+            math::exp(
+                -G0 * (altitude - self.base_altitude.0)
+                    / (SPECIFIC_GAS_CONSTANT * self.base_temperature.0),
+            )
+        } else {
+            math::powf(
+                self.temperature(altitude) / self.base_temperature.0,
+                -G0 / (self.lapse_rate * SPECIFIC_GAS_CONSTANT),
+            )
+        }
+    }
+
+    /// The altitude in the layer at the given pressure ratio.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn altitude(self, pressure_ratio: f64) -> f64 {
+        if self.lapse_rate == 0.0 {
+            math::mul_add(
+                -SPECIFIC_GAS_CONSTANT * self.base_temperature.0 / G0,
+                math::ln(pressure_ratio),
+                self.base_altitude.0,
+            )
+        } else {
+            let temperature_ratio = math::powf(
+                pressure_ratio,
+                -self.lapse_rate * SPECIFIC_GAS_CONSTANT / G0,
+            );
+            self.base_altitude.0
+                + self.base_temperature.0 * (temperature_ratio - 1.0) / self.lapse_rate
+        }
+    }
+
+    /// The density at the base of the layer.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn base_density(self) -> f64 {
+        self.base_pressure.0 / (SPECIFIC_GAS_CONSTANT * self.base_temperature.0)
+    }
+
+    /// The altitude in the layer at the given density ratio.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn density_altitude(self, density_ratio: f64) -> f64 {
+        if self.lapse_rate == 0.0 {
+            // the density ratio is the pressure ratio at a constant temperature
+            self.altitude(density_ratio)
+        } else {
+            // the density ratio is the temperature ratio to the power of this exponent
+            let exponent = -G0 / (self.lapse_rate * SPECIFIC_GAS_CONSTANT) - 1.0;
+            let temperature_ratio = math::powf(density_ratio, 1.0 / exponent);
+            self.base_altitude.0
+                + self.base_temperature.0 * (temperature_ratio - 1.0) / self.lapse_rate
+        }
+    }
+}
+
+/// The index of the layer containing `altitude`.
+#[cfg(any(feature = "std", feature = "libm"))]
+fn layer_index(altitude: f64) -> usize {
+    LAYERS
+        .iter()
+        .rposition(|layer| layer.base_altitude.0 <= altitude)
+        .unwrap_or_default()
+}
+
+/// The ISA temperature at a geopotential altitude.
+#[cfg(any(feature = "std", feature = "libm"))]
+#[must_use]
+pub fn temperature(altitude: Metres) -> Kelvin {
+    Kelvin(LAYERS[layer_index(altitude.0)].temperature(altitude.0))
+}
+
+/// The ISA pressure at a geopotential altitude.
+#[cfg(any(feature = "std", feature = "libm"))]
+#[must_use]
+pub fn pressure(altitude: Metres) -> Pascals {
+    let index = layer_index(altitude.0);
+    Pascals(LAYERS[index].base_pressure.0 * LAYERS[index].pressure_ratio(altitude.0))
+}
+
+/// The ISA density at a geopotential altitude.
+#[cfg(any(feature = "std", feature = "libm"))]
+#[must_use]
+pub fn density(altitude: Metres) -> KilogramsPerCubicMetre {
+    density_from(pressure(altitude), temperature(altitude))
+}
+
+/// The density of dry air at `pressure` and `temperature`, from the ideal
+/// gas law: p / (R T).
+///
+/// Unlike [`density`], the pressure and temperature need not be ISA values,
+/// e.g. they may be from a meteorological observation.
+#[must_use]
+pub fn density_from(pressure: Pascals, temperature: Kelvin) -> KilogramsPerCubicMetre {
+    KilogramsPerCubicMetre(pressure.0 / (SPECIFIC_GAS_CONSTANT * temperature.0))
+}
+
+/// The pressure of dry air of `density` at `temperature`, from the ideal
+/// gas law: ρ R T.
+#[must_use]
+pub fn pressure_from(density: KilogramsPerCubicMetre, temperature: Kelvin) -> Pascals {
+    Pascals(density.0 * SPECIFIC_GAS_CONSTANT * temperature.0)
+}
+
+/// The temperature of dry air at `pressure` and `density`, from the ideal
+/// gas law: p / (ρ R).
+#[must_use]
+pub fn temperature_from(pressure: Pascals, density: KilogramsPerCubicMetre) -> Kelvin {
+    Kelvin(pressure.0 / (density.0 * SPECIFIC_GAS_CONSTANT))
+}
+
+/// The pressure altitude: the geopotential altitude at which the ISA
+/// pressure is `pressure`.
+#[cfg(any(feature = "std", feature = "libm"))]
+#[must_use]
+pub fn pressure_altitude(pressure: Pascals) -> Metres {
+    let (index, base) = (0..LAYERS.len())
+        .rev()
+        .map(|index| (index, LAYERS[index].base_pressure.0))
+        .find(|(_, base)| pressure.0 <= *base)
+        .unwrap_or((0, SEA_LEVEL_PRESSURE.0));
+    Metres(LAYERS[index].altitude(pressure.0 / base))
+}
+
+/// The speed of sound in air at a `temperature`: √(γ R T).
+#[cfg(any(feature = "std", feature = "libm"))]
+#[must_use]
+pub fn speed_of_sound(temperature: Kelvin) -> MetresPerSecond {
+    MetresPerSecond(math::sqrt(
+        HEAT_CAPACITY_RATIO * SPECIFIC_GAS_CONSTANT * temperature.0,
+    ))
+}
+
+/// The factor by which water vapour at `vapour_pressure` in moist air at
+/// `pressure` reduces its density compared with dry air: 1 - (e / p)(1 - ε),
+/// where ε is the ratio of the gas constants of dry air and water vapour.
+#[cfg(any(feature = "std", feature = "libm"))]
+fn moisture_factor(pressure: Pascals, vapour_pressure: Pascals) -> f64 {
+    let epsilon = DRY_AIR_GAS_CONSTANT.0 / WATER_VAPOUR_GAS_CONSTANT.0;
+    math::mul_add(vapour_pressure.0 / pressure.0, epsilon - 1.0, 1.0)
+}
+
+/// The specific gas constant of moist air at `pressure` containing water
+/// vapour at `vapour_pressure`.
+///
+/// Water vapour is lighter than dry air, so the gas constant of moist air
+/// is greater than [`DRY_AIR_GAS_CONSTANT`].
+#[cfg(any(feature = "std", feature = "libm"))]
+#[must_use]
+pub fn moist_air_gas_constant(
+    pressure: Pascals,
+    vapour_pressure: Pascals,
+) -> JoulesPerKilogramKelvin {
+    JoulesPerKilogramKelvin(DRY_AIR_GAS_CONSTANT.0 / moisture_factor(pressure, vapour_pressure))
+}
+
+/// The virtual temperature of moist air at `temperature` and `pressure`
+/// containing water vapour at `vapour_pressure`: the temperature at which
+/// dry air at `pressure` would have the same density.
+///
+/// The density of moist air is [`density_from`] the pressure and its
+/// virtual temperature.
+#[cfg(any(feature = "std", feature = "libm"))]
+#[must_use]
+pub fn virtual_temperature(
+    temperature: Kelvin,
+    pressure: Pascals,
+    vapour_pressure: Pascals,
+) -> Kelvin {
+    Kelvin(temperature.0 / moisture_factor(pressure, vapour_pressure))
+}
+
+/// Sutherland's empirical coefficient, β, in kg/(m·s·K^½).
+pub const SUTHERLAND_COEFFICIENT: f64 = 1.458e-6;
+
+/// Sutherland's constant for air, S, in K.
+pub const SUTHERLAND_CONSTANT: f64 = 110.4;
+
+/// The dynamic viscosity of air at a `temperature`, from Sutherland's law:
+/// β T^(3/2) / (T + S), see ICAO Doc 7488.
+#[cfg(any(feature = "std", feature = "libm"))]
+#[must_use]
+pub fn dynamic_viscosity(temperature: Kelvin) -> PascalSeconds {
+    PascalSeconds(
+        SUTHERLAND_COEFFICIENT * temperature.0 * math::sqrt(temperature.0)
+            / (temperature.0 + SUTHERLAND_CONSTANT),
+    )
+}
+
+/// Whether a geopotential `altitude` is above the ISA tropopause.
+#[must_use]
+pub fn is_above_tropopause(altitude: Metres) -> bool {
+    altitude > TROPOPAUSE_ALTITUDE
+}
+
+/// The tropopause of an atmosphere that is `deviation` warmer than the
+/// ISA at every pressure altitude, e.g. `KelvinDelta(10.0)` for ISA+10.
+///
+/// As in aircraft performance models, the tropopause remains at the ISA
+/// tropopause pressure altitude and its temperature differs by `deviation`.
+#[must_use]
+pub fn tropopause_for(deviation: KelvinDelta) -> Tropopause {
+    Tropopause {
+        pressure_altitude: TROPOPAUSE_ALTITUDE,
+        temperature: TROPOPAUSE_TEMPERATURE + deviation,
+    }
+}
+
+/// The tropopause of a non-standard atmosphere.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Tropopause {
+    /// The pressure altitude of the tropopause.
+    pub pressure_altitude: Metres,
+    /// The temperature at the tropopause.
+    pub temperature: Kelvin,
+}
+
+/// The density altitude: the geopotential altitude at which the ISA
+/// density is `density`.
+///
+/// Like [`pressure_altitude`], it is calculated in the ISA [`LAYERS`], so
+/// it is valid above the tropopause, up to 32 km.
+#[cfg(any(feature = "std", feature = "libm"))]
+#[must_use]
+pub fn density_altitude(density: KilogramsPerCubicMetre) -> Metres {
+    let (index, base) = (0..LAYERS.len())
+        .rev()
+        .map(|index| (index, LAYERS[index].base_density()))
+        .find(|(_, base)| density.0 <= *base)
+        .unwrap_or_else(|| (0, LAYERS[0].base_density()));
+    Metres(LAYERS[index].density_altitude(density.0 / base))
+}
+
+/// The geopotential altitude of a geometric altitude above mean sea level.
+#[must_use]
+pub fn geopotential_altitude(geometric: Metres) -> Metres {
+    Metres(EARTH_RADIUS.0 * geometric.0 / (EARTH_RADIUS.0 + geometric.0))
+}
+
+/// The geometric altitude above mean sea level of a geopotential altitude.
+#[must_use]
+pub fn geometric_altitude(geopotential: Metres) -> Metres {
+    Metres(EARTH_RADIUS.0 * geopotential.0 / (EARTH_RADIUS.0 - geopotential.0))
+}
+
+/// A precomputed table of ISA pressures and densities, which are linearly
+/// interpolated to avoid calling `powf` or `exp` in hot loops.
+///
+/// The table covers geopotential altitudes from [`Table::MIN_ALTITUDE`] to
+/// [`Table::MAX_ALTITUDE`]; values outside of that range are calculated
+/// with the exact functions.
+#[cfg(any(feature = "std", feature = "libm"))]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Table {
+    pressures: [f64; Self::LENGTH],
+    densities: [f64; Self::LENGTH],
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+impl Table {
+    /// The lowest altitude in the table.
+    pub const MIN_ALTITUDE: Metres = Metres(-1_000.0);
+
+    /// The highest altitude in the table.
+    pub const MAX_ALTITUDE: Metres = Metres(20_000.0);
+
+    /// The altitude interval between table entries.
+    pub const STEP: Metres = Metres(100.0);
+
+    /// The maximum error of the interpolated pressures.
+    pub const MAX_PRESSURE_ERROR: Pascals = Pascals(2.0);
+
+    /// The maximum error of the interpolated densities.
+    pub const MAX_DENSITY_ERROR: KilogramsPerCubicMetre = KilogramsPerCubicMetre(2.0e-5);
+
+    /// The maximum error of the interpolated pressure altitudes.
+    pub const MAX_ALTITUDE_ERROR: Metres = Metres(0.25);
+
+    /// The number of table entries.
+    const LENGTH: usize = 211;
+
+    /// Construct the table.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn new() -> Self {
+        let mut table = Self {
+            pressures: [0.0; Self::LENGTH],
+            densities: [0.0; Self::LENGTH],
+        };
+        for i in 0..Self::LENGTH {
+            let altitude = Metres(math::mul_add(i as f64, Self::STEP.0, Self::MIN_ALTITUDE.0));
+            table.pressures[i] = pressure(altitude).0;
+            table.densities[i] = density(altitude).0;
+        }
+        table
+    }
+
+    /// The table index and interpolation fraction for `altitude`,
+    /// or `None` if it is outside of the table.
+    #[allow(
+        clippy::cast_possible_truncation,
+        clippy::cast_precision_loss,
+        clippy::cast_sign_loss
+    )]
+    fn position(altitude: Metres) -> Option<(usize, f64)> {
+        if (Self::MIN_ALTITUDE.0..Self::MAX_ALTITUDE.0).contains(&altitude.0) {
+            let offset = (altitude.0 - Self::MIN_ALTITUDE.0) / Self::STEP.0;
+            let index = offset as usize;
+            Some((index, offset - index as f64))
+        } else {
+            None
+        }
+    }
+
+    /// The interpolated ISA pressure at a geopotential altitude.
+    #[must_use]
+    pub fn pressure(&self, altitude: Metres) -> Pascals {
+        Self::position(altitude).map_or_else(
+            || pressure(altitude),
+            |(i, fraction)| Pascals(interpolate(&self.pressures, i, fraction)),
+        )
+    }
+
+    /// The interpolated ISA density at a geopotential altitude.
+    #[must_use]
+    pub fn density(&self, altitude: Metres) -> KilogramsPerCubicMetre {
+        Self::position(altitude).map_or_else(
+            || density(altitude),
+            |(i, fraction)| KilogramsPerCubicMetre(interpolate(&self.densities, i, fraction)),
+        )
+    }
+
+    /// The interpolated pressure altitude at which the ISA pressure is
+    /// `pressure`.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn pressure_altitude(&self, pressure: Pascals) -> Metres {
+        // the pressures decrease with altitude
+        let i = self.pressures.partition_point(|p| *p > pressure.0);
+        if (1..Self::LENGTH).contains(&i) {
+            let (upper, lower) = (self.pressures[i - 1], self.pressures[i]);
+            let offset = (i - 1) as f64 + (upper - pressure.0) / (upper - lower);
+            Metres(math::mul_add(offset, Self::STEP.0, Self::MIN_ALTITUDE.0))
+        } else {
+            pressure_altitude(pressure)
+        }
+    }
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+impl Default for Table {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Linearly interpolate between `values[i]` and `values[i + 1]`.
+#[cfg(any(feature = "std", feature = "libm"))]
+fn interpolate(values: &[f64], i: usize, fraction: f64) -> f64 {
+    math::mul_add(fraction, values[i + 1] - values[i], values[i])
+}
+
+#[cfg(test)]
+#[allow(clippy::float_cmp)]
+mod tests {
+    use super::*;
+
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[test]
+    fn test_isa() {
+        assert_eq!(SEA_LEVEL_PRESSURE, pressure(Metres(0.0)));
+        assert_eq!(SEA_LEVEL_TEMPERATURE, temperature(Metres(0.0)));
+        assert!((SEA_LEVEL_DENSITY.0 - density(Metres(0.0)).0).abs() < 1e-6);
+        assert_eq!(Kelvin(216.65), temperature(Metres(11_000.0)));
+        assert_eq!(Kelvin(216.65), temperature(Metres(20_000.0)));
+        assert_eq!(Kelvin(228.65), temperature(Metres(32_000.0)));
+        assert_eq!(Kelvin(294.65), temperature(Metres(-1_000.0)));
+
+        // Doc 7488 Table 5 values
+        assert!((22_632.0 - pressure(Metres(11_000.0)).0).abs() < 0.1);
+        assert!((5_474.9 - pressure(Metres(20_000.0)).0).abs() < 0.1);
+        assert!((868.02 - pressure(Metres(32_000.0)).0).abs() < 0.01);
+        assert!((0.363_92 - density(Metres(11_000.0)).0).abs() < 1e-5);
+
+        for altitude in [-1_000.0, 0.0, 1_500.0, 11_000.0, 15_000.0, 25_000.0] {
+            let result = pressure_altitude(pressure(Metres(altitude)));
+            assert!((altitude - result.0).abs() < 1e-8, "{altitude} {result:?}");
+        }
+    }
+
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[test]
+    fn test_ideal_gas() {
+        assert_eq!(
+            density(Metres(0.0)),
+            density_from(SEA_LEVEL_PRESSURE, SEA_LEVEL_TEMPERATURE)
+        );
+        // a hot day at a high aerodrome
+        let pressure = Pascals(95_000.0);
+        let temperature = Kelvin(303.15);
+        let density = density_from(pressure, temperature);
+        assert!((1.091_702 - density.0).abs() < 1e-6);
+        assert!((pressure.0 - pressure_from(density, temperature).0).abs() < 1e-9);
+        assert!((temperature.0 - temperature_from(pressure, density).0).abs() < 1e-12);
+    }
+
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[test]
+    fn test_moist_air() {
+        assert_eq!(SPECIFIC_GAS_CONSTANT, DRY_AIR_GAS_CONSTANT.0);
+        let pressure = SEA_LEVEL_PRESSURE;
+        assert_eq!(
+            DRY_AIR_GAS_CONSTANT,
+            moist_air_gas_constant(pressure, Pascals(0.0))
+        );
+        assert_eq!(
+            SEA_LEVEL_TEMPERATURE,
+            virtual_temperature(SEA_LEVEL_TEMPERATURE, pressure, Pascals(0.0))
+        );
+
+        // saturated air at 30 °C
+        let temperature = Kelvin(303.15);
+        let vapour_pressure = Pascals(4_246.0);
+        let gas_constant = moist_air_gas_constant(pressure, vapour_pressure);
+        assert_eq!(291.7, (gas_constant.0 * 10.0).round() / 10.0);
+        let virtual_temperature = virtual_temperature(temperature, pressure, vapour_pressure);
+        assert_eq!(308.0, (virtual_temperature.0 * 10.0).round() / 10.0);
+        // moist air is less dense than dry air
+        let moist = density_from(pressure, virtual_temperature);
+        assert!(moist < density_from(pressure, temperature));
+        assert!((pressure.0 / (gas_constant.0 * temperature.0) - moist.0).abs() < 1e-12);
+    }
+
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[test]
+    fn test_layers() {
+        for layers in LAYERS.windows(2) {
+            let base_altitude = layers[1].base_altitude;
+            assert_eq!(
+                layers[1].base_pressure.0,
+                layers[0].base_pressure.0 * layers[0].pressure_ratio(base_altitude.0)
+            );
+            assert!(
+                (layers[1].base_temperature.0 - layers[0].temperature(base_altitude.0)).abs()
+                    < 1e-12
+            );
+            assert_eq!(layers[1].base_pressure, pressure(base_altitude));
+        }
+    }
+
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[test]
+    fn test_tropopause() {
+        assert_eq!(TROPOPAUSE_TEMPERATURE, temperature(TROPOPAUSE_ALTITUDE));
+        assert!(!is_above_tropopause(Metres(10_000.0)));
+        assert!(!is_above_tropopause(TROPOPAUSE_ALTITUDE));
+        assert!(is_above_tropopause(Metres(11_001.0)));
+
+        let tropopause = tropopause_for(KelvinDelta(10.0));
+        assert_eq!(TROPOPAUSE_ALTITUDE, tropopause.pressure_altitude);
+        assert_eq!(Kelvin(226.65), tropopause.temperature);
+        assert_eq!(
+            TROPOPAUSE_TEMPERATURE,
+            tropopause_for(KelvinDelta(0.0)).temperature
+        );
+    }
+
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[test]
+    fn test_dynamic_viscosity() {
+        // Doc 7488 Table 5 values
+        let viscosity = dynamic_viscosity(SEA_LEVEL_TEMPERATURE);
+        assert_eq!(1.789_4, (viscosity.0 * 1e9).round() / 1e4);
+        let viscosity = dynamic_viscosity(temperature(Metres(11_000.0)));
+        assert_eq!(1.421_6, (viscosity.0 * 1e9).round() / 1e4);
+    }
+
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[test]
+    fn test_speed_of_sound() {
+        // Doc 7488: 340.294 m/s at sea level and 295.069 m/s at 11 km
+        let sea_level = speed_of_sound(SEA_LEVEL_TEMPERATURE);
+        assert!((340.294 - sea_level.0).abs() < 5e-4);
+        let tropopause = speed_of_sound(temperature(Metres(11_000.0)));
+        assert!((295.069 - tropopause.0).abs() < 5e-4);
+    }
+
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[test]
+    fn test_density_altitude() {
+        for altitude in [
+            -1_000.0, 0.0, 1_500.0, 5_000.0, 11_000.0, 15_000.0, 20_000.0, 25_000.0, 32_000.0,
+        ] {
+            let result = density_altitude(density(Metres(altitude)));
+            assert!((altitude - result.0).abs() < 1e-8, "{altitude} {result:?}");
+        }
+    }
+
+    #[test]
+    fn test_geopotential_altitude() {
+        // Doc 7488: 11 000 m geopotential is 11 019 m geometric
+        let geometric = geometric_altitude(Metres(11_000.0));
+        assert_eq!(11_019.0, geometric.0.round());
+        assert!((11_000.0 - geopotential_altitude(geometric).0).abs() < 1e-9);
+        assert_eq!(Metres(0.0), geopotential_altitude(Metres(0.0)));
+    }
+
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[test]
+    fn test_table() {
+        let table = Table::default();
+        let mut max_pressure_error: f64 = 0.0;
+        let mut max_density_error: f64 = 0.0;
+        let mut max_altitude_error: f64 = 0.0;
+        for i in -10_000..=200_000 {
+            let altitude = Metres(f64::from(i) * 0.1);
+            let p = pressure(altitude);
+            max_pressure_error = max_pressure_error.max((table.pressure(altitude).0 - p.0).abs());
+            max_density_error =
+                max_density_error.max((table.density(altitude).0 - density(altitude).0).abs());
+            max_altitude_error =
+                max_altitude_error.max((table.pressure_altitude(p).0 - altitude.0).abs());
+        }
+        assert!(
+            max_pressure_error < Table::MAX_PRESSURE_ERROR.0,
+            "{max_pressure_error}"
+        );
+        assert!(
+            max_density_error < Table::MAX_DENSITY_ERROR.0,
+            "{max_density_error}"
+        );
+        assert!(
+            max_altitude_error < Table::MAX_ALTITUDE_ERROR.0,
+            "{max_altitude_error}"
+        );
+
+        // outside of the table
+        assert_eq!(pressure(Metres(25_000.0)), table.pressure(Metres(25_000.0)));
+        assert_eq!(density(Metres(-2_000.0)), table.density(Metres(-2_000.0)));
+        assert_eq!(
+            pressure_altitude(Pascals(2_000.0)),
+            table.pressure_altitude(Pascals(2_000.0))
+        );
+    }
+}
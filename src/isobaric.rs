@@ -0,0 +1,130 @@
+// Copyright (c) 2024 Ken Barker
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"),
+// to deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+// THE SOFTWARE.
+
+//! Isobaric surfaces, the pressure levels of upper air forecasts.
+//!
+//! Gridded upper air data, e.g. GRIB winds and temperatures aloft, is
+//! indexed by pressure level. The functions in this module map isobaric
+//! surfaces to their ISA pressure altitudes and back, and label them with
+//! flight levels as in the World Area Forecast System (WAFS), e.g.:
+//!
+//! ```
+//! # #[cfg(any(feature = "std", feature = "libm"))]
+//! # {
+//! use icao_units::isobaric;
+//! use icao_units::non_si::Hectopascals;
+//! use icao_units::quantized::FlightLevel;
+//!
+//! let surface = Hectopascals(500.0);
+//! assert_eq!(18_289.0, isobaric::altitude(surface).0.round());
+//! assert_eq!(Some(FlightLevel(180)), isobaric::flight_level(surface));
+//! assert_eq!(surface, isobaric::nearest_surface(FlightLevel(180)));
+//! # }
+//! ```
+
+#[cfg(any(feature = "std", feature = "libm"))]
+use crate::non_si::Feet;
+use crate::non_si::Hectopascals;
+#[cfg(any(feature = "std", feature = "libm"))]
+use crate::quantized::FlightLevel;
+#[cfg(any(feature = "std", feature = "libm"))]
+use crate::{isa, math};
+
+/// The isobaric surfaces of the WAFS upper air forecasts, from the highest
+/// pressure to the lowest.
+pub const WAFS_SURFACES: [Hectopascals; 14] = [
+    Hectopascals(850.0),
+    Hectopascals(700.0),
+    Hectopascals(600.0),
+    Hectopascals(500.0),
+    Hectopascals(400.0),
+    Hectopascals(350.0),
+    Hectopascals(300.0),
+    Hectopascals(275.0),
+    Hectopascals(250.0),
+    Hectopascals(225.0),
+    Hectopascals(200.0),
+    Hectopascals(175.0),
+    Hectopascals(150.0),
+    Hectopascals(100.0),
+];
+
+/// The ISA pressure altitude of an isobaric `surface`.
+#[cfg(any(feature = "std", feature = "libm"))]
+#[must_use]
+pub fn altitude(surface: Hectopascals) -> Feet {
+    Feet::from(isa::pressure_altitude(surface.into()))
+}
+
+/// The pressure of the isobaric surface at an ISA pressure `altitude`.
+#[cfg(any(feature = "std", feature = "libm"))]
+#[must_use]
+pub fn pressure(altitude: Feet) -> Hectopascals {
+    Hectopascals::from(isa::pressure(altitude.into()))
+}
+
+/// The flight level that labels an isobaric `surface`: its pressure
+/// altitude rounded to the nearest 1 000 ft, e.g. FL180 for 500 hPa.
+///
+/// Returns `None` if the pressure altitude is not finite.
+#[cfg(any(feature = "std", feature = "libm"))]
+#[must_use]
+pub fn flight_level(surface: Hectopascals) -> Option<FlightLevel> {
+    let thousands = math::round(altitude(surface).0 / 1_000.0);
+    FlightLevel::try_from(Feet(thousands * 1_000.0)).ok()
+}
+
+/// The WAFS isobaric surface nearest to a flight `level`.
+#[cfg(any(feature = "std", feature = "libm"))]
+#[must_use]
+pub fn nearest_surface(level: FlightLevel) -> Hectopascals {
+    let target = Feet::from(level).0;
+    WAFS_SURFACES
+        .into_iter()
+        .min_by(|a, b| {
+            math::abs(altitude(*a).0 - target).total_cmp(&math::abs(altitude(*b).0 - target))
+        })
+        .unwrap_or(WAFS_SURFACES[0])
+}
+
+#[cfg(test)]
+#[allow(clippy::float_cmp)]
+mod tests {
+    #[cfg(any(feature = "std", feature = "libm"))]
+    use super::*;
+
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[test]
+    fn test_isobaric_surfaces() {
+        let levels = [
+            50, 100, 140, 180, 240, 270, 300, 320, 340, 360, 390, 410, 450, 530,
+        ];
+        for (surface, level) in WAFS_SURFACES.into_iter().zip(levels) {
+            assert_eq!(Some(FlightLevel(level)), flight_level(surface));
+            assert_eq!(surface, nearest_surface(FlightLevel(level)));
+            assert!((surface - pressure(altitude(surface))).0.abs() < 1e-9);
+        }
+        assert_eq!(Feet(0.0), altitude(Hectopascals::STANDARD_PRESSURE));
+        assert_eq!(Hectopascals::STANDARD_PRESSURE, pressure(Feet(0.0)));
+        assert_eq!(Hectopascals(850.0), nearest_surface(FlightLevel(0)));
+        assert_eq!(Hectopascals(100.0), nearest_surface(FlightLevel(600)));
+        assert_eq!(None, flight_level(Hectopascals(0.0)));
+    }
+}
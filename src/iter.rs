@@ -0,0 +1,362 @@
+// Copyright (c) 2024 Ken Barker
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"),
+// to deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+// THE SOFTWARE.
+
+//! Lazy conversion of iterators of unit values.
+//!
+//! The [`ConvertIterator`] extension trait converts each value of an
+//! iterator as it is read, without allocating an intermediate collection, e.g.:
+//!
+//! ```
+//! use icao_units::iter::ConvertIterator;
+//! use icao_units::non_si::NauticalMiles;
+//! use icao_units::si::Metres;
+//!
+//! let ranges = [NauticalMiles(1.0), NauticalMiles(2.5)];
+//! let total: f64 = ranges.into_iter().map_to::<Metres>().map(|m| m.0).sum();
+//! assert_eq!(6482.0, total);
+//! ```
+//!
+//! The [`CompensatedSum`] extension trait sums long sequences of additive
+//! unit values without the loss of precision of a naive sum.
+//!
+//! The [`steps`] function iterates over evenly spaced unit values, e.g. the
+//! altitudes of a vertical profile grid:
+//!
+//! ```
+//! # #[cfg(any(feature = "std", feature = "libm"))]
+//! # {
+//! use icao_units::iter::steps;
+//! use icao_units::non_si::Feet;
+//!
+//! let altitudes: Vec<Feet> = steps(Feet(0.0), Feet(3_000.0), Feet(1_000.0)).collect();
+//! assert_eq!(vec![Feet(0.0), Feet(1_000.0), Feet(2_000.0), Feet(3_000.0)], altitudes);
+//! # }
+//! ```
+
+use crate::Unit;
+use core::iter::FusedIterator;
+use core::marker::PhantomData;
+use core::ops::Add;
+
+/// An iterator that converts the values of another iterator to unit `U`.
+///
+/// It is created by the [`ConvertIterator::map_to`] method.
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct MapTo<I, U> {
+    iter: I,
+    unit: PhantomData<U>,
+}
+
+impl<I, U> Iterator for MapTo<I, U>
+where
+    I: Iterator,
+    U: From<I::Item>,
+{
+    type Item = U;
+
+    fn next(&mut self) -> Option<U> {
+        self.iter.next().map(U::from)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, U> DoubleEndedIterator for MapTo<I, U>
+where
+    I: DoubleEndedIterator,
+    U: From<I::Item>,
+{
+    fn next_back(&mut self) -> Option<U> {
+        self.iter.next_back().map(U::from)
+    }
+}
+
+impl<I, U> ExactSizeIterator for MapTo<I, U>
+where
+    I: ExactSizeIterator,
+    U: From<I::Item>,
+{
+}
+
+impl<I, U> FusedIterator for MapTo<I, U>
+where
+    I: FusedIterator,
+    U: From<I::Item>,
+{
+}
+
+/// An extension trait to convert the values of an iterator to another unit.
+pub trait ConvertIterator: Iterator + Sized {
+    /// Convert each value of the iterator to unit `U`,
+    /// e.g. `iter.map_to::<Metres>()`.
+    fn map_to<U: From<Self::Item>>(self) -> MapTo<Self, U> {
+        MapTo {
+            iter: self,
+            unit: PhantomData,
+        }
+    }
+}
+
+impl<I: Iterator> ConvertIterator for I {}
+
+/// An extension trait to sum the additive unit values of an iterator using
+/// [Neumaier](https://en.wikipedia.org/wiki/Kahan_summation_algorithm#Further_enhancements)
+/// compensated summation.
+pub trait CompensatedSum: Iterator + Sized
+where
+    Self::Item: Unit + Add<Output = Self::Item>,
+{
+    /// The compensated sum of the values of the iterator, e.g. the total of
+    /// many small distances or fuel increments.
+    #[must_use]
+    fn sum_compensated(self) -> Self::Item {
+        let mut sum = 0.0;
+        let mut compensation = 0.0;
+        for item in self {
+            let value = item.value();
+            let total = sum + value;
+            compensation += if crate::math::abs(sum) >= crate::math::abs(value) {
+                (sum - total) + value
+            } else {
+                (value - total) + sum
+            };
+            sum = total;
+        }
+        Self::Item::new(sum + compensation)
+    }
+}
+
+impl<I> CompensatedSum for I
+where
+    I: Iterator,
+    I::Item: Unit + Add<Output = I::Item>,
+{
+}
+
+/// An iterator over evenly spaced unit values.
+///
+/// It is created by the [`steps`] function.
+#[cfg(any(feature = "std", feature = "libm"))]
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Steps<U> {
+    start: f64,
+    step: f64,
+    front: usize,
+    back: usize,
+    unit: PhantomData<U>,
+}
+
+/// Iterate from `start` to `end` inclusive in increments of `step`.
+///
+/// Each value is calculated from `start`, so rounding errors do not
+/// accumulate, and `end` is included if it is within rounding error of a
+/// step. The iterator is empty if `step` is zero, not finite or does not
+/// move from `start` towards `end`. The number of values is limited to
+/// `usize::MAX`.
+#[cfg(any(feature = "std", feature = "libm"))]
+#[allow(
+    clippy::cast_possible_truncation,
+    clippy::cast_precision_loss,
+    clippy::cast_sign_loss
+)]
+pub fn steps<U: Unit>(start: U, end: U, step: U) -> Steps<U> {
+    let count = (end.value() - start.value()) / step.value();
+    let rounded = crate::math::round(count);
+    let count = if crate::math::abs(count - rounded) <= 1e-9 * crate::math::abs(rounded) {
+        rounded
+    } else {
+        count
+    };
+    let len = if count.is_finite() && count >= 0.0 {
+        // the cast saturates, so very large counts are limited to usize::MAX
+        (count as usize).saturating_add(1)
+    } else {
+        0
+    };
+    Steps {
+        start: start.value(),
+        step: step.value(),
+        front: 0,
+        back: len,
+        unit: PhantomData,
+    }
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+impl<U: Unit> Steps<U> {
+    /// The value of step `i`.
+    #[allow(clippy::cast_precision_loss)]
+    fn value(&self, i: usize) -> U {
+        U::new(crate::math::mul_add(i as f64, self.step, self.start))
+    }
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+impl<U: Unit> Iterator for Steps<U> {
+    type Item = U;
+
+    fn next(&mut self) -> Option<U> {
+        (self.front < self.back).then(|| {
+            self.front += 1;
+            self.value(self.front - 1)
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.front;
+        (len, Some(len))
+    }
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+impl<U: Unit> DoubleEndedIterator for Steps<U> {
+    fn next_back(&mut self) -> Option<U> {
+        (self.front < self.back).then(|| {
+            self.back -= 1;
+            self.value(self.back)
+        })
+    }
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+impl<U: Unit> ExactSizeIterator for Steps<U> {}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+impl<U: Unit> FusedIterator for Steps<U> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{non_si, si};
+
+    #[test]
+    fn test_map_to() {
+        let altitudes = [
+            non_si::Feet(1000.0),
+            non_si::Feet(2000.0),
+            non_si::Feet(3000.0),
+        ];
+        let expected: Vec<si::Metres> = altitudes.iter().map(|a| si::Metres::from(*a)).collect();
+        let metres: Vec<si::Metres> = altitudes.iter().copied().map_to().collect();
+        assert_eq!(expected, metres);
+
+        let mut iter = altitudes.into_iter().map_to::<si::Metres>();
+        assert_eq!(3, iter.len());
+        assert_eq!(Some(expected[2]), iter.next_back());
+        assert_eq!(Some(expected[0]), iter.next());
+        assert_eq!(Some(expected[1]), iter.next());
+        assert_eq!(None, iter.next());
+
+        let speeds = [si::MetresPerSecond(non_si::METRES_PER_SECOND_TO_KNOTS)];
+        let knots: Vec<non_si::Knots> = speeds.into_iter().map_to().collect();
+        assert_eq!(vec![non_si::Knots(1.0)], knots);
+    }
+
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[test]
+    fn test_steps() {
+        let levels: Vec<non_si::Feet> = steps(
+            non_si::Feet(0.0),
+            non_si::Feet(1_000.0),
+            non_si::Feet(250.0),
+        )
+        .collect();
+        assert_eq!(
+            vec![
+                non_si::Feet(0.0),
+                non_si::Feet(250.0),
+                non_si::Feet(500.0),
+                non_si::Feet(750.0),
+                non_si::Feet(1_000.0)
+            ],
+            levels
+        );
+
+        // the end is included despite rounding errors
+        let mut iter = steps(si::Metres(0.0), si::Metres(0.3), si::Metres(0.1));
+        assert_eq!(4, iter.len());
+        assert_eq!(Some(si::Metres(3.0 * 0.1)), iter.next_back());
+        assert_eq!(Some(si::Metres(0.0)), iter.next());
+        assert_eq!(2, iter.len());
+
+        // descending, not reaching the end
+        let descending: Vec<non_si::Feet> = steps(
+            non_si::Feet(1_000.0),
+            non_si::Feet(0.0),
+            non_si::Feet(-400.0),
+        )
+        .collect();
+        assert_eq!(
+            vec![
+                non_si::Feet(1_000.0),
+                non_si::Feet(600.0),
+                non_si::Feet(200.0)
+            ],
+            descending
+        );
+
+        assert_eq!(
+            0,
+            steps(non_si::Feet(0.0), non_si::Feet(1.0), non_si::Feet(0.0)).len()
+        );
+        assert_eq!(
+            0,
+            steps(non_si::Feet(0.0), non_si::Feet(1.0), non_si::Feet(-1.0)).len()
+        );
+        assert_eq!(
+            0,
+            steps(non_si::Feet(1.0), non_si::Feet(1.0), non_si::Feet(0.0)).len()
+        );
+        assert_eq!(
+            1,
+            steps(non_si::Feet(1.0), non_si::Feet(1.0), non_si::Feet(1.0)).len()
+        );
+
+        // a very large finite span
+        let mut iter = steps(non_si::Feet(0.0), non_si::Feet(1e30), non_si::Feet(1e-10));
+        assert_eq!(usize::MAX, iter.len());
+        assert_eq!(Some(non_si::Feet(0.0)), iter.next());
+        assert_eq!(Some(non_si::Feet(1e-10)), iter.next());
+    }
+
+    #[test]
+    fn test_sum_compensated() {
+        let increments = vec![si::Metres(0.1); 1_000_000];
+        let naive = increments.iter().fold(si::Metres(0.0), |acc, &x| acc + x);
+        assert_ne!(si::Metres(100_000.0), naive);
+        assert_eq!(
+            si::Metres(100_000.0),
+            increments.into_iter().sum_compensated()
+        );
+
+        let values = [
+            non_si::Feet(1.0),
+            non_si::Feet(1e100),
+            non_si::Feet(1.0),
+            non_si::Feet(-1e100),
+        ];
+        assert_eq!(non_si::Feet(2.0), values.into_iter().sum_compensated());
+        assert_eq!(non_si::Feet(0.0), core::iter::empty().sum_compensated());
+    }
+}
@@ -0,0 +1,339 @@
+// Copyright (c) 2024 Ken Barker
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"),
+// to deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+// THE SOFTWARE.
+
+//! Runtime selection of units, e.g. from a user interface or a configuration
+//! file.
+//!
+//! [`UnitKind`] identifies a unit type at runtime and [`UnitKind::convert`]
+//! converts values between units of the same [`QuantityKind`], e.g.:
+//!
+//! ```
+//! use icao_units::kind::UnitKind;
+//!
+//! let metres = UnitKind::convert(1000.0, UnitKind::Feet, UnitKind::Metres).unwrap();
+//! assert_eq!(304.8, metres);
+//! assert!(UnitKind::convert(1000.0, UnitKind::Feet, UnitKind::Knots).is_err());
+//! ```
+
+use crate::{non_si, si, Unit};
+use core::fmt;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The physical quantity measured by a unit.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum QuantityKind {
+    Length,
+    Speed,
+    Acceleration,
+    Temperature,
+    Pressure,
+    Mass,
+    Density,
+    Angle,
+    AngularVelocity,
+    Time,
+    MassFlow,
+    SpecificRange,
+    Volume,
+    VolumeFlow,
+    Moment,
+    DynamicViscosity,
+    KinematicViscosity,
+    SpecificHeatCapacity,
+    PressureRate,
+    VelocityGradient,
+    Current,
+    Voltage,
+    Resistance,
+    Power,
+    LuminousIntensity,
+    LuminousFlux,
+    Illuminance,
+    Activity,
+    DoseEquivalent,
+    DoseEquivalentRate,
+}
+
+/// Defines the `UnitKind` enum with a variant for each unit type.
+macro_rules! unit_kinds {
+    ($($module:ident::$t:ident => $quantity:ident),*) => {
+        /// A unit type, selected at runtime.
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+        #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+        pub enum UnitKind {
+            $(
+                #[doc = concat!("[`", stringify!($module), "::", stringify!($t), "`]")]
+                $t,
+            )*
+        }
+
+        impl UnitKind {
+            /// Every unit kind.
+            pub const ALL: &'static [Self] = &[$(Self::$t),*];
+
+            /// The quantity measured by the unit.
+            #[must_use]
+            pub const fn quantity(self) -> QuantityKind {
+                match self {
+                    $(Self::$t => QuantityKind::$quantity,)*
+                }
+            }
+
+            /// The unit symbol, e.g. "ft".
+            #[must_use]
+            pub const fn symbol(self) -> &'static str {
+                match self {
+                    $(Self::$t => <$module::$t as Unit>::SYMBOL,)*
+                }
+            }
+
+            /// The factor to multiply a value of the unit by to convert it
+            /// to the coherent SI unit of the same quantity.
+            #[must_use]
+            pub const fn si_factor(self) -> f64 {
+                match self {
+                    $(Self::$t => <$module::$t as Unit>::SI_FACTOR,)*
+                }
+            }
+        }
+    };
+}
+
+unit_kinds!(
+    si::Metres => Length,
+    si::MetresPerSecond => Speed,
+    si::MetresPerSecondSquared => Acceleration,
+    si::Kelvin => Temperature,
+    si::Pascals => Pressure,
+    si::Kilograms => Mass,
+    si::KilogramsPerCubicMetre => Density,
+    si::Radians => Angle,
+    si::RadiansPerSecond => AngularVelocity,
+    si::Seconds => Time,
+    si::KilogramsPerSecond => MassFlow,
+    si::MetresPerKilogram => SpecificRange,
+    si::CubicMetres => Volume,
+    si::CubicMetresPerSecond => VolumeFlow,
+    si::KilogramMetres => Moment,
+    non_si::NauticalMiles => Length,
+    non_si::Feet => Length,
+    non_si::Knots => Speed,
+    non_si::FeetPerMinute => Speed,
+    non_si::Degrees => Angle,
+    non_si::DegreesPerSecond => AngularVelocity,
+    non_si::ArcMinutes => Angle,
+    non_si::ArcSeconds => Angle,
+    non_si::Hours => Time,
+    non_si::KilogramsPerHour => MassFlow,
+    non_si::NauticalMilesPerKilogram => SpecificRange,
+    non_si::KilogramsPerLitre => Density,
+    non_si::Litres => Volume,
+    non_si::USGallons => Volume,
+    non_si::LitresPerHour => VolumeFlow,
+    non_si::USGallonsPerHour => VolumeFlow,
+    non_si::Psi => Pressure,
+    non_si::Hectopascals => Pressure,
+    non_si::Millibars => Pressure,
+    non_si::InchesOfMercury => Pressure,
+    si::PascalSeconds => DynamicViscosity,
+    si::SquareMetresPerSecond => KinematicViscosity,
+    si::JoulesPerKilogramKelvin => SpecificHeatCapacity,
+    si::PascalsPerSecond => PressureRate,
+    non_si::HectopascalsPerHour => PressureRate,
+    si::MetresPerSecondPerMetre => VelocityGradient,
+    non_si::KnotsPerHundredFeet => VelocityGradient,
+    si::Amperes => Current,
+    si::Volts => Voltage,
+    si::Ohms => Resistance,
+    si::Watts => Power,
+    si::Candela => LuminousIntensity,
+    si::Lumens => LuminousFlux,
+    si::Lux => Illuminance,
+    si::Becquerels => Activity,
+    si::Sieverts => DoseEquivalent,
+    si::SievertsPerSecond => DoseEquivalentRate,
+    non_si::SievertsPerHour => DoseEquivalentRate
+);
+
+impl UnitKind {
+    /// The coherent SI unit of the same quantity, e.g. `Metres` for `Feet`.
+    #[must_use]
+    pub const fn si_unit(self) -> Self {
+        match self.quantity() {
+            QuantityKind::Length => Self::Metres,
+            QuantityKind::Speed => Self::MetresPerSecond,
+            QuantityKind::Acceleration => Self::MetresPerSecondSquared,
+            QuantityKind::Temperature => Self::Kelvin,
+            QuantityKind::Pressure => Self::Pascals,
+            QuantityKind::Mass => Self::Kilograms,
+            QuantityKind::Density => Self::KilogramsPerCubicMetre,
+            QuantityKind::Angle => Self::Radians,
+            QuantityKind::AngularVelocity => Self::RadiansPerSecond,
+            QuantityKind::Time => Self::Seconds,
+            QuantityKind::MassFlow => Self::KilogramsPerSecond,
+            QuantityKind::SpecificRange => Self::MetresPerKilogram,
+            QuantityKind::Volume => Self::CubicMetres,
+            QuantityKind::VolumeFlow => Self::CubicMetresPerSecond,
+            QuantityKind::Moment => Self::KilogramMetres,
+            QuantityKind::DynamicViscosity => Self::PascalSeconds,
+            QuantityKind::KinematicViscosity => Self::SquareMetresPerSecond,
+            QuantityKind::SpecificHeatCapacity => Self::JoulesPerKilogramKelvin,
+            QuantityKind::PressureRate => Self::PascalsPerSecond,
+            QuantityKind::VelocityGradient => Self::MetresPerSecondPerMetre,
+            QuantityKind::Current => Self::Amperes,
+            QuantityKind::Voltage => Self::Volts,
+            QuantityKind::Resistance => Self::Ohms,
+            QuantityKind::Power => Self::Watts,
+            QuantityKind::LuminousIntensity => Self::Candela,
+            QuantityKind::LuminousFlux => Self::Lumens,
+            QuantityKind::Illuminance => Self::Lux,
+            QuantityKind::Activity => Self::Becquerels,
+            QuantityKind::DoseEquivalent => Self::Sieverts,
+            QuantityKind::DoseEquivalentRate => Self::SievertsPerSecond,
+        }
+    }
+
+    /// Find the unit kind with the given symbol.
+    #[must_use]
+    pub fn from_symbol(symbol: &str) -> Option<Self> {
+        Self::ALL
+            .iter()
+            .copied()
+            .find(|kind| kind.symbol() == symbol)
+    }
+
+    /// Convert a value from one unit to another.
+    ///
+    /// The value is multiplied by the ratio of the SI factors, so the result
+    /// is the same as the `From` conversion between the unit types.
+    ///
+    /// # Errors
+    ///
+    /// `IncompatibleUnits` if the units measure different quantities.
+    pub fn convert(value: f64, from: Self, to: Self) -> Result<f64, IncompatibleUnits> {
+        if from.quantity() == to.quantity() {
+            Ok(if from == to {
+                value
+            } else {
+                value * (from.si_factor() / to.si_factor())
+            })
+        } else {
+            Err(IncompatibleUnits { from, to })
+        }
+    }
+}
+
+impl fmt::Display for UnitKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.symbol())
+    }
+}
+
+/// The error returned when converting between units of different quantities.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct IncompatibleUnits {
+    /// The unit converted from.
+    pub from: UnitKind,
+    /// The unit converted to.
+    pub to: UnitKind,
+}
+
+impl fmt::Display for IncompatibleUnits {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "cannot convert {:?} ({}) to {:?} ({})",
+            self.from.quantity(),
+            self.from,
+            self.to.quantity(),
+            self.to
+        )
+    }
+}
+
+impl core::error::Error for IncompatibleUnits {}
+
+#[cfg(test)]
+#[allow(clippy::float_cmp)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unit_kind() {
+        assert_eq!(53, UnitKind::ALL.len());
+        assert_eq!(QuantityKind::Length, UnitKind::Feet.quantity());
+        assert_eq!("kt", UnitKind::Knots.symbol());
+        assert_eq!("kt", UnitKind::Knots.to_string());
+        assert_eq!(Some(UnitKind::NauticalMiles), UnitKind::from_symbol("NM"));
+        assert_eq!(None, UnitKind::from_symbol("fur"));
+        for kind in UnitKind::ALL {
+            assert_eq!(Some(*kind), UnitKind::from_symbol(kind.symbol()));
+            assert_eq!(kind.quantity(), kind.si_unit().quantity());
+            assert_eq!(1.0, kind.si_unit().si_factor());
+        }
+    }
+
+    #[test]
+    fn test_convert() {
+        assert_eq!(
+            si::Metres::from(non_si::Feet(1000.0)).0,
+            UnitKind::convert(1000.0, UnitKind::Feet, UnitKind::Metres).unwrap()
+        );
+        assert_eq!(
+            non_si::Knots::from(si::MetresPerSecond(100.0)).0,
+            UnitKind::convert(100.0, UnitKind::MetresPerSecond, UnitKind::Knots).unwrap()
+        );
+        assert_eq!(
+            999.999_999_999_999_9,
+            UnitKind::convert(304.8, UnitKind::Metres, UnitKind::Feet).unwrap()
+        );
+        assert_eq!(
+            1852.0 / 0.3048,
+            UnitKind::convert(1.0, UnitKind::NauticalMiles, UnitKind::Feet).unwrap()
+        );
+        assert_eq!(
+            0.1,
+            UnitKind::convert(0.1, UnitKind::Kelvin, UnitKind::Kelvin).unwrap()
+        );
+
+        let error = UnitKind::convert(1.0, UnitKind::Feet, UnitKind::Knots).unwrap_err();
+        assert_eq!(
+            IncompatibleUnits {
+                from: UnitKind::Feet,
+                to: UnitKind::Knots
+            },
+            error
+        );
+        assert_eq!(
+            "cannot convert Length (ft) to Speed (kt)",
+            error.to_string()
+        );
+
+        #[cfg(feature = "serde")]
+        {
+            let serialized = serde_json::to_string(&UnitKind::Feet).unwrap();
+            assert_eq!("\"Feet\"", serialized);
+            let deserialized: UnitKind = serde_json::from_str(&serialized).unwrap();
+            assert_eq!(UnitKind::Feet, deserialized);
+        }
+    }
+}
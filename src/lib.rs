@@ -32,17 +32,610 @@
 //! - the [SI](https://en.wikipedia.org/wiki/International_System_of_Units)
 //!   units used in the [International Standard Atmosphere](https://en.wikipedia.org/wiki/International_Standard_Atmosphere) (ISA),
 //! - the non-SI units defined in `ICAO Annex 5` Table 3-3,
-//! - and conversions between SI and non-SI units.
+//! - conversions between SI and non-SI units,
+//! - and the ICAO Standard Atmosphere in the `isa` module.
 //!
 //! The library uses the [newtype](https://rust-unofficial.github.io/patterns/patterns/behavioural/newtype.html)
 //! idiom to represent ICAO units and the [From](https://doc.rust-lang.org/core/convert/trait.From.html)
 //! trait to convert between SI and non-SI units using the conversion factors
 //! defined in `ICAO Annex 5` Table 3-3.
+//! The `f64` unit types are aliases of the generic `quantity::Quantity` type
+//! for a unit marker type, e.g. `Metres` is `Quantity<MetresUnit>`, so they
+//! share the same trait implementations and can be used in generic code.
+//!
+//! Single precision (`f32`) variants of the unit types are defined in the
+//! `single` module, e.g. `Metres32`, and integer variants for deterministic
+//! calculations are defined in the `exact` module, e.g. `Millimetres`.
+//! Altitudes, flight levels and headings at the resolution that aircraft
+//! report them are defined in the `quantized` module.
+//!
+//! Every unit type is declared `#[repr(transparent)]`, so it is guaranteed to
+//! have the same size, alignment and ABI as its `f64` (or `f32`) value.
+//! This guarantee is part of the public API, so unit values may be passed to
+//! C functions and slices of unit values may be reinterpreted as slices of
+//! floating point numbers.
+//!
+//! Project specific unit types can be declared with the `declare_unit!` macro,
+//! e.g. `declare_unit!(pub Furlongs, FurlongsUnit, "fur", 201.168)`.
+//!
+//! Units can be selected at runtime with the `kind::UnitKind` enum, which
+//! converts values between units of the same quantity.
+//!
+//! Unit values are displayed with their symbol, e.g. `1000 ft`, and can be
+//! written to a fixed size buffer without allocating with `Unit::write_to`.
+//!
+//! The common unit types, traits and constants can be imported with
+//! `use icao_units::prelude::*`.
 //!
 //! The library is declared [no_std](https://docs.rust-embedded.org/book/intro/no-std.html)
-//! so it can be used in embedded applications.
+//! when the default `std` feature is disabled, so it can be used in embedded
+//! applications. The unit types and their conversions only require `core`,
+//! but the functions that use floating point functions which are not in
+//! `core`, e.g. the `isa` functions, also require the `libm` feature.
+//!
+//! ## Features
+//!
+//! The library has the following optional features, all of which are `no_std`
+//! compatible except for `arbitrary`, `diesel`, `rayon`, `sqlx` and `wasm`:
+//!
+//! - `alloc`: functions that use `Vec`, for `no_std` targets with an allocator,
+//!   enabled by `std`.
+//! - `angle-sc`: conversions between the `Degrees` and `Radians` types and the
+//!   [angle-sc](https://crates.io/crates/angle-sc) `Degrees`, `Radians` and `Angle` types.
+//! - `arbitrary`: implements the [arbitrary](https://crates.io/crates/arbitrary) `Arbitrary`
+//!   trait for the unit types, for fuzzing and property testing.
+//! - `bytemuck`: derives the [bytemuck](https://crates.io/crates/bytemuck) `Pod`
+//!   and `Zeroable` traits for the unit types, so that slices of unit values
+//!   can be cast to and from byte slices, and enables the `cast` module.
+//! - `chrono`: conversion from the [chrono](https://crates.io/crates/chrono) `TimeDelta`
+//!   type to `Seconds` and multiplication of speeds by a `TimeDelta`.
+//! - `defmt`: implements the [defmt](https://crates.io/crates/defmt) `Format`
+//!   trait for the unit types, for logging on embedded devices.
+//! - `diesel`: implements the [diesel](https://crates.io/crates/diesel) `ToSql`
+//!   and `FromSql` traits for the unit types, stored as `Double` columns.
+//! - `dimensions`: the `dimensions` module of quantities with compile-time
+//!   dimensional analysis, using [typenum](https://crates.io/crates/typenum).
+//! - `fixed`: saturating conversions between the unit types and
+//!   [fixed](https://crates.io/crates/fixed) point numbers, e.g. Q16.16.
+//! - `libm`: uses the [libm](https://crates.io/crates/libm) crate for floating
+//!   point functions instead of `std`, for `no_std` targets.
+//! - `measurements`: conversions to and from the [measurements](https://crates.io/crates/measurements)
+//!   `Length`, `Speed`, `Acceleration`, `Temperature`, `Pressure`, `Mass` and `Density` types.
+//! - `num-traits`: implements the [num-traits](https://crates.io/crates/num-traits) `Zero`
+//!   and `Bounded` traits for the additive unit types.
+//! - `rayon`: parallel versions of the `batch` slice conversion functions and
+//!   the `stats` functions, using [rayon](https://crates.io/crates/rayon).
+//! - `rust_decimal`: the `decimal` module of mass and volume types with exact
+//!   conversion factors, using [rust_decimal](https://crates.io/crates/rust_decimal).
+//! - `serde` (default): implements the [serde](https://crates.io/crates/serde) `Serialize`
+//!   and `Deserialize` traits for the unit types and enables the `tagged` module.
+//! - `std` (default): uses the `std` library floating point functions.
+//! - `strict-annex5`: uses the knot conversion factor published in `ICAO Annex 5`
+//!   Table 3-3, 0.514 444, instead of 1852/3600, to reproduce ICAO document values.
+//! - `sqlx`: implements the [sqlx](https://crates.io/crates/sqlx) `Type`,
+//!   `Encode` and `Decode` traits for the unit types, stored as `DOUBLE PRECISION` columns.
+//! - `wasm`: exports the distance, speed and angle types and their conversions
+//!   to JavaScript using [wasm-bindgen](https://crates.io/crates/wasm-bindgen).
+
+#![cfg_attr(not(any(test, feature = "std")), no_std)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+/// Invokes the macro `$m` with a comma separated list of every unit type.
+#[allow(unused_macros)]
+macro_rules! for_each_unit {
+    ($m:ident) => {
+        $m!(
+            si::Metres,
+            si::MetresPerSecond,
+            si::MetresPerSecondSquared,
+            si::Kelvin,
+            si::Pascals,
+            si::Kilograms,
+            si::KilogramsPerCubicMetre,
+            si::Radians,
+            si::RadiansPerSecond,
+            si::Seconds,
+            si::KilogramsPerSecond,
+            si::MetresPerKilogram,
+            si::CubicMetres,
+            si::CubicMetresPerSecond,
+            si::KilogramMetres,
+            non_si::NauticalMiles,
+            non_si::Feet,
+            non_si::Knots,
+            non_si::FeetPerMinute,
+            non_si::Degrees,
+            non_si::DegreesPerSecond,
+            non_si::ArcMinutes,
+            non_si::ArcSeconds,
+            non_si::Hours,
+            non_si::KilogramsPerHour,
+            non_si::NauticalMilesPerKilogram,
+            non_si::KilogramsPerLitre,
+            non_si::Litres,
+            non_si::USGallons,
+            non_si::LitresPerHour,
+            non_si::USGallonsPerHour,
+            non_si::Psi,
+            non_si::Hectopascals,
+            non_si::Millibars,
+            non_si::InchesOfMercury,
+            si::PascalSeconds,
+            si::SquareMetresPerSecond,
+            si::JoulesPerKilogramKelvin,
+            si::PascalsPerSecond,
+            non_si::HectopascalsPerHour,
+            si::MetresPerSecondPerMetre,
+            non_si::KnotsPerHundredFeet,
+            si::Amperes,
+            si::Volts,
+            si::Ohms,
+            si::Watts,
+            si::Candela,
+            si::Lumens,
+            si::Lux,
+            si::Becquerels,
+            si::Sieverts,
+            si::SievertsPerSecond,
+            non_si::SievertsPerHour
+        );
+    };
+}
+
+/// A unit of measurement.
+pub trait Unit: Copy {
+    /// The unit symbol, e.g. "ft".
+    const SYMBOL: &'static str;
+
+    /// Construct a unit value from an `f64`.
+    fn new(value: f64) -> Self;
+
+    /// The `f64` value of the unit.
+    fn value(self) -> f64;
+
+    /// The factor to multiply a value of the unit by to convert it to the
+    /// coherent SI unit of the same quantity, e.g. 0.3048 for `Feet`.
+    /// It is 1.0 for the coherent SI units.
+    const SI_FACTOR: f64;
+
+    /// The value of the unit in the coherent SI unit of the same quantity.
+    #[must_use]
+    fn si_value(self) -> f64 {
+        self.value() * Self::SI_FACTOR
+    }
+
+    /// Construct a unit value from a value in the coherent SI unit of the
+    /// same quantity.
+    ///
+    /// The value is multiplied by the reciprocal of `SI_FACTOR`, like the
+    /// `From` conversions between the unit types.
+    #[must_use]
+    fn from_si_value(value: f64) -> Self {
+        Self::new(value * (1.0 / Self::SI_FACTOR))
+    }
+
+    /// The absolute value of the unit.
+    #[must_use]
+    fn abs(self) -> Self {
+        Self::new(math::abs(self.value()))
+    }
+
+    /// Half of the unit value.
+    #[must_use]
+    fn half(self) -> Self {
+        Self::new(0.5 * self.value())
+    }
+
+    /// Double the unit value.
+    #[must_use]
+    fn double(self) -> Self {
+        Self::new(2.0 * self.value())
+    }
+
+    /// The sign of the unit value: 1.0 if it is positive or +0.0,
+    /// -1.0 if it is negative or -0.0 and NaN if it is NaN.
+    #[must_use]
+    fn signum(self) -> f64 {
+        math::signum(self.value())
+    }
+
+    /// `self * factor + addend` with a single rounding error, using the
+    /// fused multiply-add instruction where the target supports it.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[must_use]
+    fn mul_add(self, factor: f64, addend: Self) -> Self {
+        Self::new(math::mul_add(self.value(), factor, addend.value()))
+    }
+
+    /// Write the unit value followed by its symbol, e.g. "1000 ft", in the
+    /// same format as `Display`, without allocating, e.g. to a fixed size
+    /// buffer that implements `core::fmt::Write`.
+    ///
+    /// # Errors
+    ///
+    /// If the writer fails, e.g. because its buffer is full.
+    fn write_to<W: core::fmt::Write>(self, w: &mut W) -> core::fmt::Result
+    where
+        Self: core::fmt::Display,
+    {
+        write!(w, "{self}")
+    }
+
+    /// Whether the unit value is +0.0 or -0.0.
+    #[must_use]
+    fn is_zero(self) -> bool {
+        self.value() == 0.0
+    }
+}
+
+/// A coherent SI unit, i.e. a unit defined in the `si` module.
+pub trait SiUnit: Unit {}
+
+/// A non-SI unit with a conversion to and from its coherent SI unit, so
+/// generic code can accept any unit of a quantity and normalise it to SI.
+pub trait NonSiUnit: Unit {
+    /// The coherent SI unit of the same quantity, e.g. `Metres` for `Feet`.
+    type Si: SiUnit + From<Self> + Into<Self>;
+
+    /// Convert to the SI unit.
+    #[must_use]
+    fn to_si(self) -> Self::Si {
+        Self::Si::from(self)
+    }
 
-#![cfg_attr(not(test), no_std)]
+    /// Convert from the SI unit.
+    #[must_use]
+    fn from_si(value: Self::Si) -> Self {
+        value.into()
+    }
+}
 
+/// Explicit conversion to the `Target` unit type.
+///
+/// It is implemented for every pair of types with a `From` conversion,
+/// including the identity conversion, so generic functions can accept any
+/// unit of a quantity, e.g.:
+///
+/// ```
+/// use icao_units::{non_si::Feet, si::Metres, ConvertTo};
+///
+/// fn normalise<D: ConvertTo<Metres>>(d: D) -> Metres {
+///     d.convert()
+/// }
+///
+/// assert_eq!(Metres(304.8), normalise(Feet(1000.0)));
+/// assert_eq!(Metres(1.0), normalise(Metres(1.0)));
+/// ```
+pub trait ConvertTo<Target> {
+    /// Convert to the `Target` unit type.
+    fn convert(self) -> Target;
+}
+
+impl<T, Target: From<T>> ConvertTo<Target> for T {
+    fn convert(self) -> Target {
+        Target::from(self)
+    }
+}
+
+/// Declares a unit type as an alias of `Quantity` for a new unit marker type
+/// with the given attributes, symbol and SI conversion factor, together with
+/// a constructor function with the same name as the unit type.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! declare_quantity {
+    (
+        $(#[$attr:meta])* $vis:vis $t:ident,
+        $(#[$unit_attr:meta])* $unit:ident,
+        $symbol:literal,
+        $si_factor:expr $(,)?
+    ) => {
+        $(#[$attr])*
+        $vis type $t = $crate::quantity::Quantity<$unit>;
+
+        #[doc = ::core::concat!("Construct a [`", ::core::stringify!($t), "`] value.")]
+        #[allow(non_snake_case)]
+        #[must_use]
+        $vis const fn $t(value: f64) -> $t {
+            $crate::quantity::Quantity::new(value)
+        }
+
+        #[doc = ::core::concat!("The unit of [`", ::core::stringify!($t), "`]: ", $symbol, ".")]
+        #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+        $(#[$unit_attr])*
+        $vis struct $unit;
+
+        impl $crate::quantity::UnitMarker for $unit {
+            const NAME: &'static str = ::core::stringify!($t);
+            const SYMBOL: &'static str = $symbol;
+            const SI_FACTOR: f64 = $si_factor;
+        }
+    };
+}
+
+/// Declares a unit type of this library with `declare_quantity!`, whose unit
+/// marker type derives the `bytemuck` traits when the `bytemuck` feature is
+/// enabled, so that the unit type does too.
+macro_rules! quantity {
+    ($(#[$attr:meta])* $vis:vis $t:ident, $unit:ident, $symbol:literal, $si_factor:expr $(,)?) => {
+        $crate::declare_quantity!(
+            $(#[$attr])* $vis $t,
+            #[cfg_attr(
+                feature = "bytemuck",
+                derive(bytemuck::Pod, bytemuck::Zeroable),
+                repr(C)
+            )]
+            $unit,
+            $symbol,
+            $si_factor
+        );
+    };
+}
+
+/// Declares an additive unit type of this library with `quantity!`.
+macro_rules! additive_quantity {
+    ($(#[$attr:meta])* $vis:vis $t:ident, $unit:ident, $symbol:literal, $si_factor:expr $(,)?) => {
+        quantity!($(#[$attr])* $vis $t, $unit, $symbol, $si_factor);
+
+        impl $crate::quantity::AdditiveUnit for $unit {}
+    };
+}
+
+/// Implements the `Add`, `AddAssign`, `Sub`, `SubAssign` and `Neg` operators
+/// for a unit type.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! impl_additive {
+    ($t:ty) => {
+        impl ::core::ops::Add for $t {
+            type Output = Self;
+
+            fn add(self, rhs: Self) -> Self {
+                Self(self.0 + rhs.0)
+            }
+        }
+
+        impl ::core::ops::AddAssign for $t {
+            fn add_assign(&mut self, rhs: Self) {
+                self.0 += rhs.0;
+            }
+        }
+
+        impl ::core::ops::Sub for $t {
+            type Output = Self;
+
+            fn sub(self, rhs: Self) -> Self {
+                Self(self.0 - rhs.0)
+            }
+        }
+
+        impl ::core::ops::SubAssign for $t {
+            fn sub_assign(&mut self, rhs: Self) {
+                self.0 -= rhs.0;
+            }
+        }
+
+        impl ::core::ops::Neg for $t {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                Self(-self.0)
+            }
+        }
+    };
+}
+
+/// Declares a new additive unit type with the given unit marker type, symbol
+/// and SI conversion factor.
+///
+/// The SI conversion factor is the factor to multiply a value by to convert
+/// it to the coherent SI unit of the same quantity, 1.0 for a coherent SI
+/// unit.
+///
+/// The unit type is an alias of [`quantity::Quantity`] for the unit marker
+/// type, declared in the same way as the unit types defined by this library,
+/// so it has the same traits, including the `Add`, `AddAssign`, `Sub`,
+/// `SubAssign` and `Neg` operators and, when the `serde` feature is enabled,
+/// the same `serde` representation.
+/// A constructor function with the same name as the unit type is also
+/// declared.
+///
+/// # Examples
+///
+/// ```
+/// use icao_units::{declare_unit, Unit};
+///
+/// declare_unit!(
+///     /// A furlong, one eighth of a statute mile.
+///     pub Furlongs, FurlongsUnit, "fur", 201.168
+/// );
+///
+/// let distance = Furlongs(6.0) + Furlongs(2.0);
+/// assert_eq!(Furlongs(8.0), distance);
+/// assert_eq!("fur", Furlongs::SYMBOL);
+/// assert_eq!(1_609.344, distance.si_value());
+/// ```
+#[macro_export]
+macro_rules! declare_unit {
+    ($(#[$attr:meta])* $vis:vis $t:ident, $unit:ident, $symbol:literal, $si_factor:expr $(,)?) => {
+        $crate::declare_quantity!($(#[$attr])* $vis $t, $unit, $symbol, $si_factor);
+
+        impl $crate::quantity::AdditiveUnit for $unit {}
+    };
+}
+
+#[doc(hidden)]
+#[path = "private.rs"]
+pub mod __private;
+pub mod adsb;
+pub mod airspeed;
+pub mod altimeter;
+pub mod altitude;
+#[cfg(feature = "angle-sc")]
+mod angle_sc;
+#[cfg(feature = "arbitrary")]
+pub mod arbitrary;
+#[cfg(target_has_atomic = "64")]
+pub mod atomic;
+pub mod balance;
+pub mod batch;
+#[cfg(feature = "bytemuck")]
+pub mod cast;
+#[cfg(feature = "chrono")]
+mod chrono;
+pub mod csv;
+#[cfg(feature = "rust_decimal")]
+pub mod decimal;
+#[cfg(feature = "defmt")]
+mod defmt;
+pub mod descent;
+#[cfg(feature = "diesel")]
+mod diesel;
+#[cfg(feature = "dimensions")]
+pub mod dimensions;
+pub mod exact;
+pub mod filter;
+#[cfg(feature = "fixed")]
+pub mod fixed;
+pub mod fuel;
+pub mod gradient;
+pub mod heading;
+pub mod humidity;
+pub mod interval;
+pub mod isa;
+pub mod isobaric;
+pub mod iter;
+pub mod kind;
+mod math;
+pub mod measurement;
+#[cfg(feature = "measurements")]
+mod measurements;
 pub mod non_si;
+#[cfg(feature = "num-traits")]
+mod num_traits;
+pub mod pbn;
+pub mod prelude;
+#[cfg(feature = "alloc")]
+pub mod profile;
+pub mod quantity;
+pub mod quantized;
+pub mod radio;
+pub mod ratio;
+pub mod rational;
+pub mod reynolds;
 pub mod si;
+pub mod single;
+#[cfg(feature = "sqlx")]
+mod sqlx;
+pub mod stats;
+#[cfg(feature = "serde")]
+pub mod tagged;
+pub mod trajectory;
+pub mod turn;
+pub mod uncertain;
+pub mod vector;
+#[cfg(feature = "wasm")]
+mod wasm;
+pub mod wind;
+
+#[cfg(test)]
+#[allow(clippy::float_cmp)]
+mod tests {
+    use super::*;
+    use core::mem::{align_of, size_of};
+
+    macro_rules! assert_transparent {
+        ($float:ty, $($t:ty),*) => {
+            $(
+                assert_eq!(size_of::<$float>(), size_of::<$t>());
+                assert_eq!(align_of::<$float>(), align_of::<$t>());
+            )*
+        };
+    }
+
+    macro_rules! assert_f64_transparent {
+        ($($t:ty),*) => {
+            assert_transparent!(f64, $($t),*);
+        };
+    }
+
+    /// A fixed size buffer that implements `core::fmt::Write`.
+    struct Buffer {
+        bytes: [u8; 16],
+        len: usize,
+    }
+
+    impl core::fmt::Write for Buffer {
+        fn write_str(&mut self, s: &str) -> core::fmt::Result {
+            let end = self.len + s.len();
+            self.bytes
+                .get_mut(self.len..end)
+                .ok_or(core::fmt::Error)?
+                .copy_from_slice(s.as_bytes());
+            self.len = end;
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!("1000 ft", non_si::Feet(1000.0).to_string());
+        assert_eq!("304.80 m", format!("{:.2}", si::Metres(304.8)));
+        assert_eq!(
+            "9.80665 m/s²",
+            si::MetresPerSecondSquared(9.806_65).to_string()
+        );
+        assert_eq!("45.5°", non_si::Degrees(45.5).to_string());
+        assert_eq!("0.1 ft", single::Feet32(0.1).to_string());
+
+        let mut buffer = Buffer {
+            bytes: [0; 16],
+            len: 0,
+        };
+        assert!(non_si::Knots(250.0).write_to(&mut buffer).is_ok());
+        assert_eq!(b"250 kt", &buffer.bytes[..buffer.len]);
+        assert!(si::Metres(1e100).write_to(&mut buffer).is_err());
+    }
+
+    #[test]
+    fn test_repr_transparent() {
+        for_each_unit!(assert_f64_transparent);
+        assert_f64_transparent!(heading::DegreesTrue, heading::DegreesMagnetic);
+        assert_f64_transparent!(
+            airspeed::IndicatedAirspeed,
+            airspeed::CalibratedAirspeed,
+            airspeed::TrueAirspeed,
+            airspeed::GroundSpeed
+        );
+        assert_f64_transparent!(
+            altitude::PressureAltitude,
+            altitude::DensityAltitude,
+            altitude::IndicatedAltitude,
+            altitude::GeometricHeight
+        );
+        assert_transparent!(
+            f32,
+            single::Metres32,
+            single::MetresPerSecond32,
+            single::MetresPerSecondSquared32,
+            single::Kelvin32,
+            single::Pascals32,
+            single::Kilograms32,
+            single::KilogramsPerCubicMetre32,
+            single::Radians32,
+            single::Seconds32,
+            single::NauticalMiles32,
+            single::Feet32,
+            single::Knots32,
+            single::Degrees32
+        );
+
+        #[cfg(feature = "bytemuck")]
+        {
+            let values = [si::Metres(1.0), si::Metres(2.0)];
+            let floats: &[f64] = bytemuck::cast_slice(&values);
+            assert_eq!(&[1.0, 2.0], floats);
+        }
+    }
+}
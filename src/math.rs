@@ -0,0 +1,408 @@
+// Copyright (c) 2024 Ken Barker
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"),
+// to deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+// THE SOFTWARE.
+
+//! Floating point functions.
+//!
+//! The library uses the [libm](https://crates.io/crates/libm) crate
+//! when the `libm` feature is enabled, otherwise it uses the `std` functions.
+//!
+//! All floating point functions that are not in `core` should be called via
+//! this module so that the library can be used on `no_std` targets.
+//! Apart from `abs`, `signum` and `round`, they are only available when either the
+//! `std` or the `libm` feature is enabled, so items that call them must be
+//! gated in the same way.
+
+/// The absolute value of `x`.
+#[allow(clippy::missing_const_for_fn)] // libm::fabs is not const
+#[must_use]
+pub fn abs(x: f64) -> f64 {
+    #[cfg(feature = "libm")]
+    {
+        libm::fabs(x)
+    }
+    #[cfg(not(feature = "libm"))]
+    {
+        x.abs()
+    }
+}
+
+/// The sign of `x`: 1.0 if `x` is positive, +0.0 or +∞,
+/// -1.0 if `x` is negative, -0.0 or -∞ and NaN if `x` is NaN.
+#[allow(clippy::missing_const_for_fn)] // libm::copysign is not const
+#[must_use]
+pub fn signum(x: f64) -> f64 {
+    #[cfg(feature = "libm")]
+    {
+        if x.is_nan() {
+            f64::NAN
+        } else {
+            libm::copysign(1.0, x)
+        }
+    }
+    #[cfg(not(feature = "libm"))]
+    {
+        x.signum()
+    }
+}
+
+/// The square root of `x`.
+#[cfg(any(feature = "std", feature = "libm"))]
+#[must_use]
+pub fn sqrt(x: f64) -> f64 {
+    #[cfg(feature = "libm")]
+    {
+        libm::sqrt(x)
+    }
+    #[cfg(not(feature = "libm"))]
+    {
+        x.sqrt()
+    }
+}
+
+/// `x * y + z` with a single rounding error, using the fused multiply-add
+/// instruction where the target supports it.
+///
+/// Without the `std` or `libm` features, `x * y + z` is calculated using
+/// `core` arithmetic, with two rounding errors.
+// Only `profile` calls `mul_add` without the `std` or `libm` feature.
+#[cfg(any(feature = "std", feature = "libm", feature = "alloc"))]
+#[allow(clippy::missing_const_for_fn)] // libm::fma is not const
+#[must_use]
+pub fn mul_add(x: f64, y: f64, z: f64) -> f64 {
+    #[cfg(feature = "libm")]
+    {
+        libm::fma(x, y, z)
+    }
+    #[cfg(all(feature = "std", not(feature = "libm")))]
+    {
+        x.mul_add(y, z)
+    }
+    #[cfg(not(any(feature = "std", feature = "libm")))]
+    #[allow(clippy::suboptimal_flops)] // f64::mul_add is not in core
+    {
+        x * y + z
+    }
+}
+
+/// `x` rounded to the nearest integer, with halves rounded away from zero.
+///
+/// Without the `std` or `libm` features, `x` is rounded using `core`
+/// arithmetic, which gives the same result.
+#[allow(clippy::missing_const_for_fn)] // libm::round is not const
+#[must_use]
+pub fn round(x: f64) -> f64 {
+    #[cfg(feature = "libm")]
+    {
+        libm::round(x)
+    }
+    #[cfg(all(feature = "std", not(feature = "libm")))]
+    {
+        x.round()
+    }
+    #[cfg(not(any(feature = "std", feature = "libm")))]
+    {
+        // 2^52: larger values, infinities and NaNs have no fractional part
+        const MIN_INTEGRAL: f64 = 4_503_599_627_370_496.0;
+        if abs(x) < MIN_INTEGRAL {
+            #[allow(clippy::cast_possible_truncation, clippy::cast_precision_loss)]
+            let truncated = x as i64 as f64;
+            let rounded = if abs(x - truncated) >= 0.5 {
+                truncated + signum(x)
+            } else {
+                truncated
+            };
+            rounded.copysign(x)
+        } else {
+            x
+        }
+    }
+}
+
+/// `e` raised to the power `x`.
+#[cfg(any(feature = "std", feature = "libm"))]
+#[must_use]
+pub fn exp(x: f64) -> f64 {
+    #[cfg(feature = "libm")]
+    {
+        libm::exp(x)
+    }
+    #[cfg(not(feature = "libm"))]
+    {
+        x.exp()
+    }
+}
+
+/// The natural logarithm of `x`.
+#[cfg(any(feature = "std", feature = "libm"))]
+#[must_use]
+pub fn ln(x: f64) -> f64 {
+    #[cfg(feature = "libm")]
+    {
+        libm::log(x)
+    }
+    #[cfg(not(feature = "libm"))]
+    {
+        x.ln()
+    }
+}
+
+/// The base 10 logarithm of `x`.
+#[cfg(any(feature = "std", feature = "libm"))]
+#[must_use]
+pub fn log10(x: f64) -> f64 {
+    #[cfg(feature = "libm")]
+    {
+        libm::log10(x)
+    }
+    #[cfg(not(feature = "libm"))]
+    {
+        x.log10()
+    }
+}
+
+/// `x` raised to the power `y`.
+#[cfg(any(feature = "std", feature = "libm"))]
+#[must_use]
+pub fn powf(x: f64, y: f64) -> f64 {
+    #[cfg(feature = "libm")]
+    {
+        libm::pow(x, y)
+    }
+    #[cfg(not(feature = "libm"))]
+    {
+        x.powf(y)
+    }
+}
+
+/// The sine of `x` radians.
+#[cfg(any(feature = "std", feature = "libm"))]
+#[must_use]
+pub fn sin(x: f64) -> f64 {
+    #[cfg(feature = "libm")]
+    {
+        libm::sin(x)
+    }
+    #[cfg(not(feature = "libm"))]
+    {
+        x.sin()
+    }
+}
+
+/// The cosine of `x` radians.
+#[cfg(any(feature = "std", feature = "libm"))]
+#[must_use]
+pub fn cos(x: f64) -> f64 {
+    #[cfg(feature = "libm")]
+    {
+        libm::cos(x)
+    }
+    #[cfg(not(feature = "libm"))]
+    {
+        x.cos()
+    }
+}
+
+/// The arcsine of `x`, in radians in the range [-π/2, π/2].
+#[cfg(any(feature = "std", feature = "libm"))]
+#[must_use]
+pub fn asin(x: f64) -> f64 {
+    #[cfg(feature = "libm")]
+    {
+        libm::asin(x)
+    }
+    #[cfg(not(feature = "libm"))]
+    {
+        x.asin()
+    }
+}
+
+/// The tangent of `x` radians.
+#[cfg(any(feature = "std", feature = "libm"))]
+#[must_use]
+pub fn tan(x: f64) -> f64 {
+    #[cfg(feature = "libm")]
+    {
+        libm::tan(x)
+    }
+    #[cfg(not(feature = "libm"))]
+    {
+        x.tan()
+    }
+}
+
+/// The arctangent of `x`, in radians in the range [-π/2, π/2].
+#[cfg(any(feature = "std", feature = "libm"))]
+#[must_use]
+pub fn atan(x: f64) -> f64 {
+    #[cfg(feature = "libm")]
+    {
+        libm::atan(x)
+    }
+    #[cfg(not(feature = "libm"))]
+    {
+        x.atan()
+    }
+}
+
+/// The four quadrant arctangent of `y` and `x`, in radians in the range
+/// [-π, π].
+#[cfg(any(feature = "std", feature = "libm"))]
+#[must_use]
+pub fn atan2(y: f64, x: f64) -> f64 {
+    #[cfg(feature = "libm")]
+    {
+        libm::atan2(y, x)
+    }
+    #[cfg(not(feature = "libm"))]
+    {
+        y.atan2(x)
+    }
+}
+
+/// The length of the hypotenuse of a right-angle triangle with sides `x`
+/// and `y`, without intermediate overflow.
+#[cfg(any(feature = "std", feature = "libm"))]
+#[must_use]
+pub fn hypot(x: f64, y: f64) -> f64 {
+    #[cfg(feature = "libm")]
+    {
+        libm::hypot(x, y)
+    }
+    #[cfg(not(feature = "libm"))]
+    {
+        x.hypot(y)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::float_cmp)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_abs() {
+        assert_eq!(1.5, abs(-1.5));
+        assert_eq!(1.5, abs(1.5));
+        assert!(abs(-0.0).is_sign_positive());
+        assert!(abs(f64::NAN).is_nan());
+    }
+
+    #[test]
+    fn test_signum() {
+        assert_eq!(-1.0, signum(-1.5));
+        assert_eq!(1.0, signum(1.5));
+        assert_eq!(1.0, signum(0.0));
+        assert_eq!(-1.0, signum(-0.0));
+        assert_eq!(-1.0, signum(f64::NEG_INFINITY));
+        assert!(signum(f64::NAN).is_nan());
+    }
+
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[test]
+    fn test_sqrt() {
+        assert_eq!(1.5, sqrt(2.25));
+        assert_eq!(0.0, sqrt(0.0));
+        assert!(sqrt(-1.0).is_nan());
+    }
+
+    #[cfg(any(feature = "std", feature = "libm", feature = "alloc"))]
+    #[test]
+    fn test_mul_add() {
+        assert_eq!(7.0, mul_add(2.0, 3.0, 1.0));
+        // 0.1 * 10.0 - 1.0 is exactly 0.0 when rounded twice
+        #[cfg(any(feature = "std", feature = "libm"))]
+        assert_eq!(5.551_115_123_125_783e-17, mul_add(0.1, 10.0, -1.0));
+        #[cfg(not(any(feature = "std", feature = "libm")))]
+        assert_eq!(0.0, mul_add(0.1, 10.0, -1.0));
+    }
+
+    #[test]
+    fn test_round() {
+        assert_eq!(2.0, round(1.5));
+        assert_eq!(-2.0, round(-1.5));
+        assert_eq!(1.0, round(1.4));
+        assert_eq!(3.0, round(2.5));
+        assert_eq!(0.0, round(0.499_999_999_999_999_94));
+        assert!(round(-0.4).is_sign_negative());
+        assert_eq!(4_503_599_627_370_497.0, round(4_503_599_627_370_497.0));
+        assert_eq!(f64::INFINITY, round(f64::INFINITY));
+        assert!(round(f64::NAN).is_nan());
+    }
+
+    #[cfg(not(any(feature = "std", feature = "libm")))]
+    #[test]
+    fn test_round_core() {
+        // the tests link std, so compare the core arithmetic with f64::round
+        let values = [
+            0.0,
+            -0.0,
+            0.5,
+            -0.5,
+            0.499_999_999_999_999_94,
+            -0.499_999_999_999_999_94,
+            2.5,
+            -2.5,
+            1_234.567,
+            -1_234.567,
+            4_503_599_627_370_495.5,
+            -4_503_599_627_370_495.5,
+            4_503_599_627_370_496.0,
+            9_007_199_254_740_993.0,
+            f64::MAX,
+            f64::MIN,
+            f64::MIN_POSITIVE,
+            f64::NEG_INFINITY,
+        ];
+        for x in values {
+            let expected = x.round();
+            assert_eq!(expected, round(x));
+            assert_eq!(expected.is_sign_negative(), round(x).is_sign_negative());
+        }
+        for i in -1_000..=1_000 {
+            let x = f64::from(i) * 0.25;
+            assert_eq!(x.round(), round(x));
+        }
+    }
+
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[test]
+    fn test_exp_ln_powf() {
+        assert_eq!(1.0, exp(0.0));
+        assert_eq!(0.0, ln(1.0));
+        assert!((ln(exp(2.5)) - 2.5).abs() < 1e-15);
+        assert_eq!(3.0, log10(1_000.0));
+        assert_eq!(8.0, powf(2.0, 3.0));
+        assert_eq!(1.5, powf(2.25, 0.5));
+    }
+
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[test]
+    fn test_trigonometry() {
+        assert_eq!(0.0, sin(0.0));
+        assert_eq!(1.0, cos(0.0));
+        assert_eq!(core::f64::consts::FRAC_PI_2, asin(1.0));
+        assert_eq!(0.0, tan(0.0));
+        assert_eq!(core::f64::consts::FRAC_PI_4, atan(1.0));
+        assert_eq!(core::f64::consts::FRAC_PI_4, atan2(1.0, 1.0));
+        assert_eq!(-core::f64::consts::FRAC_PI_2, atan2(-1.0, 0.0));
+        assert_eq!(5.0, hypot(3.0, 4.0));
+        assert!(asin(1.5).is_nan());
+    }
+}
@@ -0,0 +1,158 @@
+// Copyright (c) 2024 Ken Barker
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"),
+// to deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+// THE SOFTWARE.
+
+//! Measurements that remember the unit that they were received in.
+//!
+//! A [`Measurement`] stores its value in SI units for computation together
+//! with the [`UnitKind`] of the original value, so that it can be displayed
+//! in the unit that it was reported in, e.g.:
+//!
+//! ```
+//! use icao_units::kind::UnitKind;
+//! use icao_units::measurement::Measurement;
+//! use icao_units::non_si::Feet;
+//! use icao_units::si::Metres;
+//!
+//! let altitude = Measurement::from(Feet(1000.0));
+//! assert_eq!(UnitKind::Feet, altitude.unit());
+//! assert_eq!(304.8, altitude.si_value());
+//! assert_eq!(Metres(304.8), Metres::try_from(altitude).unwrap());
+//! ```
+
+use crate::kind::{IncompatibleUnits, UnitKind};
+use crate::{non_si, si, Unit};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// A value in SI units together with the unit that it was received in.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Measurement {
+    si_value: f64,
+    unit: UnitKind,
+}
+
+impl Measurement {
+    /// Construct a measurement from a value in the given unit.
+    #[must_use]
+    pub fn new(value: f64, unit: UnitKind) -> Self {
+        Self {
+            si_value: value * unit.si_factor(),
+            unit,
+        }
+    }
+
+    /// The value of the measurement in SI units.
+    #[must_use]
+    pub const fn si_value(self) -> f64 {
+        self.si_value
+    }
+
+    /// The unit that the measurement was received in.
+    #[must_use]
+    pub const fn unit(self) -> UnitKind {
+        self.unit
+    }
+
+    /// The value of the measurement in the unit that it was received in.
+    ///
+    /// The SI value is multiplied by the reciprocal of the SI factor of the
+    /// unit, so the value may differ from the value received by 1 ulp.
+    #[must_use]
+    pub fn value(self) -> f64 {
+        self.si_value * (1.0 / self.unit.si_factor())
+    }
+
+    /// The value of the measurement in the given unit.
+    ///
+    /// # Errors
+    ///
+    /// `IncompatibleUnits` if the unit measures a different quantity.
+    pub fn value_in(self, unit: UnitKind) -> Result<f64, IncompatibleUnits> {
+        UnitKind::convert(self.si_value, self.unit.si_unit(), unit)
+    }
+}
+
+/// Implements conversions between the unit types and `Measurement`.
+macro_rules! impl_measurement {
+    ($($module:ident::$t:ident),*) => {
+        $(
+            impl From<$module::$t> for Measurement {
+                fn from(a: $module::$t) -> Self {
+                    Self {
+                        si_value: a.si_value(),
+                        unit: UnitKind::$t,
+                    }
+                }
+            }
+
+            impl TryFrom<Measurement> for $module::$t {
+                type Error = IncompatibleUnits;
+
+                /// Convert a `Measurement` to the unit type.
+                /// Fails if the unit type measures a different quantity.
+                fn try_from(a: Measurement) -> Result<Self, Self::Error> {
+                    a.value_in(UnitKind::$t).map(Self::new)
+                }
+            }
+        )*
+    };
+}
+
+for_each_unit!(impl_measurement);
+
+#[cfg(test)]
+#[allow(clippy::float_cmp)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_measurement() {
+        let altitude = Measurement::from(non_si::Feet(1000.0));
+        assert_eq!(UnitKind::Feet, altitude.unit());
+        assert_eq!(304.8, altitude.si_value());
+        // multiplying by the reciprocal of 0.304 8 is 1 ulp below 1000.0
+        assert_eq!(999.999_999_999_999_9, altitude.value());
+        assert_eq!(Ok(si::Metres(304.8)), si::Metres::try_from(altitude));
+        assert_eq!(
+            Ok(non_si::Feet(999.999_999_999_999_9)),
+            non_si::Feet::try_from(altitude)
+        );
+        assert_eq!(
+            Err(IncompatibleUnits {
+                from: UnitKind::Metres,
+                to: UnitKind::Knots
+            }),
+            non_si::Knots::try_from(altitude)
+        );
+
+        let speed = Measurement::new(300.0, UnitKind::Knots);
+        assert_eq!(Measurement::from(non_si::Knots(300.0)), speed);
+        assert_eq!(Ok(300.0), speed.value_in(UnitKind::Knots));
+
+        #[cfg(feature = "serde")]
+        {
+            let serialized = serde_json::to_string(&altitude).unwrap();
+            assert_eq!(r#"{"si_value":304.8,"unit":"Feet"}"#, serialized);
+            let deserialized: Measurement = serde_json::from_str(&serialized).unwrap();
+            assert_eq!(altitude, deserialized);
+        }
+    }
+}
@@ -0,0 +1,136 @@
+// Copyright (c) 2024 Ken Barker
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"),
+// to deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+// THE SOFTWARE.
+
+//! Conversions between the unit types and the
+//! [measurements](https://crates.io/crates/measurements) crate types.
+//!
+//! Non-SI units are converted via their SI equivalents.
+
+use crate::{non_si, si};
+use ::measurements::{Acceleration, Density, Length, Mass, Pressure, Speed, Temperature};
+
+macro_rules! impl_si_measurement {
+    ($($t:ty => $m:ty, $from:ident, $as:ident);*) => {
+        $(
+            impl From<$t> for $m {
+                fn from(a: $t) -> Self {
+                    Self::$from(a.0)
+                }
+            }
+
+            impl From<$m> for $t {
+                fn from(a: $m) -> Self {
+                    Self::new(a.$as())
+                }
+            }
+        )*
+    };
+}
+
+impl_si_measurement!(
+    si::Metres => Length, from_meters, as_meters;
+    si::MetresPerSecond => Speed, from_meters_per_second, as_meters_per_second;
+    si::MetresPerSecondSquared => Acceleration, from_meters_per_second_per_second, as_meters_per_second_per_second;
+    si::Kelvin => Temperature, from_kelvin, as_kelvin;
+    si::Pascals => Pressure, from_pascals, as_pascals;
+    si::Kilograms => Mass, from_kilograms, as_kilograms;
+    si::KilogramsPerCubicMetre => Density, from_kilograms_per_cubic_meter, as_kilograms_per_cubic_meter
+);
+
+macro_rules! impl_non_si_measurement {
+    ($($t:ty => $si:ty, $m:ty);*) => {
+        $(
+            impl From<$t> for $m {
+                fn from(a: $t) -> Self {
+                    Self::from(<$si>::from(a))
+                }
+            }
+
+            impl From<$m> for $t {
+                fn from(a: $m) -> Self {
+                    Self::from(<$si>::from(a))
+                }
+            }
+        )*
+    };
+}
+
+impl_non_si_measurement!(
+    non_si::NauticalMiles => si::Metres, Length;
+    non_si::Feet => si::Metres, Length;
+    non_si::Knots => si::MetresPerSecond, Speed
+);
+
+#[cfg(test)]
+#[allow(clippy::float_cmp)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_si_measurements() {
+        let length = Length::from(si::Metres(1852.0));
+        assert_eq!(1852.0, length.as_meters());
+        assert_eq!(si::Metres(1852.0), si::Metres::from(length));
+
+        let speed = Speed::from(si::MetresPerSecond(2.0));
+        assert_eq!(si::MetresPerSecond(2.0), si::MetresPerSecond::from(speed));
+
+        let acceleration = Acceleration::from(si::MetresPerSecondSquared(9.81));
+        assert_eq!(
+            si::MetresPerSecondSquared(9.81),
+            si::MetresPerSecondSquared::from(acceleration)
+        );
+
+        let temperature = Temperature::from(si::Kelvin(288.15));
+        assert_eq!(si::Kelvin(288.15), si::Kelvin::from(temperature));
+
+        let pressure = Pressure::from(si::Pascals(101_325.0));
+        assert_eq!(si::Pascals(101_325.0), si::Pascals::from(pressure));
+
+        let mass = Mass::from(si::Kilograms(1000.0));
+        assert_eq!(si::Kilograms(1000.0), si::Kilograms::from(mass));
+
+        let density = Density::from(si::KilogramsPerCubicMetre(1.225));
+        assert_eq!(
+            si::KilogramsPerCubicMetre(1.225),
+            si::KilogramsPerCubicMetre::from(density)
+        );
+    }
+
+    #[test]
+    fn test_non_si_measurements() {
+        let length = Length::from(non_si::NauticalMiles(1.0));
+        assert_eq!(1852.0, length.as_meters());
+        assert_eq!(
+            non_si::NauticalMiles(1.0),
+            non_si::NauticalMiles::from(length)
+        );
+
+        let length = Length::from(non_si::Feet(1.0));
+        assert_eq!(0.304_8, length.as_meters());
+        assert_eq!(
+            non_si::Feet(0.999_999_999_999_999_9),
+            non_si::Feet::from(length)
+        );
+
+        let speed = Speed::from(non_si::Knots(1.0));
+        assert_eq!(non_si::Knots(1.0), non_si::Knots::from(speed));
+    }
+}
@@ -18,86 +18,1135 @@
 // OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
 // THE SOFTWARE.
 
-//! Non-SI units used in air navigation and conversions to their SI equivalents.  
+//! Non-SI units used in air navigation and conversions to their SI equivalents.
 //! See ICAO Annex 5 Chapter 3, Table 3-3 and Chapter 4, Table 4-1.
+//!
+//! Conversions from SI units multiply by the reciprocal of the conversion
+//! factor instead of dividing by it, since multiplication is much faster.
+//! The results are within 1 ulp of the divided values, so a conversion
+//! to a non-SI unit and back may differ from the original value by 1 ulp,
+//! e.g. `Feet::from(Metres(0.3048))` is 0.999 999 999 999 999 9 ft.
 
 use crate::si;
 use core::convert::From;
+use core::ops::{Div, Mul};
+use core::time::Duration;
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-/// A Nautical Mile `newtype` for representing distance.
-///
-/// Used in navigation, generally for distances in excess of `4 000` m.
-#[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Serialize, Deserialize)]
-pub struct NauticalMiles(pub f64);
+additive_quantity!(
+    /// A Nautical Mile unit type for representing distance.
+    ///
+    /// Used in navigation, generally for distances in excess of `4 000` m.
+    pub NauticalMiles, NauticalMilesUnit, "NM", METRES_PER_NAUTICAL_MILE
+);
 
 /// The length of a Nautical Mile (NM) in metres (m).
 ///
 /// Definition from ICAO Annex 5 Table 3-3.
 pub const METRES_PER_NAUTICAL_MILE: f64 = 1_852.0;
 
+/// The number of Nautical Miles (NM) in a metre (m), the reciprocal of
+/// `METRES_PER_NAUTICAL_MILE`, so that conversions multiply instead of divide.
+pub const NAUTICAL_MILES_PER_METRE: f64 = 1.0 / METRES_PER_NAUTICAL_MILE;
+
+impl NauticalMiles {
+    /// Convert to `Metres`.
+    #[must_use]
+    pub const fn to_metres(self) -> si::Metres {
+        si::Metres(self.0 * METRES_PER_NAUTICAL_MILE)
+    }
+
+    /// Convert from `Metres`.
+    #[must_use]
+    pub const fn from_metres(a: si::Metres) -> Self {
+        Self::new(a.0 * NAUTICAL_MILES_PER_METRE)
+    }
+}
+
+impl si::Metres {
+    /// Convert to `NauticalMiles`.
+    #[must_use]
+    pub const fn to_nautical_miles(self) -> NauticalMiles {
+        NauticalMiles::from_metres(self)
+    }
+
+    /// Convert from `NauticalMiles`.
+    #[must_use]
+    pub const fn from_nautical_miles(a: NauticalMiles) -> Self {
+        a.to_metres()
+    }
+}
+
 impl From<si::Metres> for NauticalMiles {
     fn from(a: si::Metres) -> Self {
-        Self(a.0 / METRES_PER_NAUTICAL_MILE)
+        Self::from_metres(a)
     }
 }
 
 impl From<NauticalMiles> for si::Metres {
     fn from(a: NauticalMiles) -> Self {
-        Self(a.0 * METRES_PER_NAUTICAL_MILE)
+        a.to_metres()
     }
 }
 
-/// A Feet `newtype` for representing altitude.
-///
-/// Used to report aircraft altitude below the
-/// [transition altitude](https://en.wikipedia.org/wiki/Flight_level#Transition_altitude).
-#[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Serialize, Deserialize)]
-pub struct Feet(pub f64);
+impl crate::NonSiUnit for NauticalMiles {
+    type Si = si::Metres;
+}
+
+additive_quantity!(
+    /// A Feet unit type for representing altitude.
+    ///
+    /// Used to report aircraft altitude below the
+    /// [transition altitude](https://en.wikipedia.org/wiki/Flight_level#Transition_altitude).
+    pub Feet, FeetUnit, "ft", METRES_PER_FOOT
+);
 
 /// The length of a foot (ft) in metres (m).
 ///
 /// Definition from ICAO Annex 5 Table 3-3.
 pub const METRES_PER_FOOT: f64 = 0.304_8;
 
+/// The number of feet (ft) in a metre (m), the reciprocal of
+/// `METRES_PER_FOOT`, so that conversions multiply instead of divide.
+///
+/// It is not exact, so converting round numbers of metres to feet may
+/// give results 1 ulp below the exact values.
+pub const FEET_PER_METRE: f64 = 1.0 / METRES_PER_FOOT;
+
+impl Feet {
+    /// The height of a flight level: 100 feet.
+    pub const PER_FLIGHT_LEVEL: Self = Self::new(100.0);
+
+    /// Convert to `Metres`.
+    #[must_use]
+    pub const fn to_metres(self) -> si::Metres {
+        si::Metres(self.0 * METRES_PER_FOOT)
+    }
+
+    /// Convert from `Metres`.
+    #[must_use]
+    pub const fn from_metres(a: si::Metres) -> Self {
+        Self::new(a.0 * FEET_PER_METRE)
+    }
+}
+
+impl si::Metres {
+    /// Convert to `Feet`.
+    #[must_use]
+    pub const fn to_feet(self) -> Feet {
+        Feet::from_metres(self)
+    }
+
+    /// Convert from `Feet`.
+    #[must_use]
+    pub const fn from_feet(a: Feet) -> Self {
+        a.to_metres()
+    }
+}
+
 impl From<si::Metres> for Feet {
     fn from(a: si::Metres) -> Self {
-        Self(a.0 / METRES_PER_FOOT)
+        Self::from_metres(a)
     }
 }
 
 impl From<Feet> for si::Metres {
     fn from(a: Feet) -> Self {
-        Self(a.0 * METRES_PER_FOOT)
+        a.to_metres()
     }
 }
 
-/// A Knots `newtype` for representing speed.
-///
-/// A conversion of 1 kt = 0.5 m/s is used in ICAO Annexes for the representation
-/// of wind speed.
-#[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Serialize, Deserialize)]
-pub struct Knots(pub f64);
+impl crate::NonSiUnit for Feet {
+    type Si = si::Metres;
+}
+
+additive_quantity!(
+    /// A Knots unit type for representing speed.
+    ///
+    /// A conversion of 1 kt = 0.5 m/s is used in ICAO Annexes for the representation
+    /// of wind speed.
+    pub Knots, KnotsUnit, "kt", METRES_PER_SECOND_TO_KNOTS
+);
 
 /// The conversion factor to Knots (kt) from metres per second (m/s).
 ///
 /// Calculated from `METRES_PER_NAUTICAL_MILE` / seconds in an hour,
 /// because it is more precise than the ICAO definition: 0.514 444.
-pub const METRES_PER_SECOND_TO_KNOTS: f64 = METRES_PER_NAUTICAL_MILE / 3600.0;
+#[cfg(not(feature = "strict-annex5"))]
+pub const METRES_PER_SECOND_TO_KNOTS: f64 = METRES_PER_NAUTICAL_MILE / SECONDS_PER_HOUR;
+
+/// The conversion factor to Knots (kt) from metres per second (m/s).
+///
+/// The factor published in ICAO Annex 5 Table 3-3: 0.514 444, enabled by
+/// the `strict-annex5` feature to reproduce ICAO document values.
+#[cfg(feature = "strict-annex5")]
+pub const METRES_PER_SECOND_TO_KNOTS: f64 = 0.514_444;
+
+/// The number of Knots (kt) in a metre per second (m/s), the reciprocal of
+/// `METRES_PER_SECOND_TO_KNOTS`, so that conversions multiply instead of divide.
+pub const KNOTS_PER_METRE_PER_SECOND: f64 = 1.0 / METRES_PER_SECOND_TO_KNOTS;
+
+impl Knots {
+    /// Convert to `MetresPerSecond`.
+    #[must_use]
+    pub const fn to_metres_per_second(self) -> si::MetresPerSecond {
+        si::MetresPerSecond(self.0 * METRES_PER_SECOND_TO_KNOTS)
+    }
+
+    /// Convert from `MetresPerSecond`.
+    #[must_use]
+    pub const fn from_metres_per_second(a: si::MetresPerSecond) -> Self {
+        Self::new(a.0 * KNOTS_PER_METRE_PER_SECOND)
+    }
+}
+
+impl si::MetresPerSecond {
+    /// Convert to `Knots`.
+    #[must_use]
+    pub const fn to_knots(self) -> Knots {
+        Knots::from_metres_per_second(self)
+    }
+
+    /// Convert from `Knots`.
+    #[must_use]
+    pub const fn from_knots(a: Knots) -> Self {
+        a.to_metres_per_second()
+    }
+}
 
 impl From<si::MetresPerSecond> for Knots {
     fn from(a: si::MetresPerSecond) -> Self {
-        Self(a.0 / METRES_PER_SECOND_TO_KNOTS)
+        Self::from_metres_per_second(a)
     }
 }
 
 impl From<Knots> for si::MetresPerSecond {
     fn from(a: Knots) -> Self {
-        Self(a.0 * METRES_PER_SECOND_TO_KNOTS)
+        a.to_metres_per_second()
+    }
+}
+
+impl crate::NonSiUnit for Knots {
+    type Si = si::MetresPerSecond;
+}
+
+/// The number of seconds in an hour.
+pub const SECONDS_PER_HOUR: f64 = 3_600.0;
+
+impl Mul<si::Seconds> for Knots {
+    type Output = NauticalMiles;
+
+    fn mul(self, rhs: si::Seconds) -> NauticalMiles {
+        NauticalMiles(self.0 * rhs.0 / SECONDS_PER_HOUR)
+    }
+}
+
+impl Mul<Duration> for Knots {
+    type Output = NauticalMiles;
+
+    fn mul(self, rhs: Duration) -> NauticalMiles {
+        self * si::Seconds::from(rhs)
+    }
+}
+
+additive_quantity!(
+    /// A `FeetPerMinute` unit type for representing vertical speed.
+    pub FeetPerMinute, FeetPerMinuteUnit, "ft/min", METRES_PER_SECOND_TO_FEET_PER_MINUTE
+);
+
+/// The conversion factor to feet per minute (ft/min) from metres per second
+/// (m/s): 0.3048 / 60.
+pub const METRES_PER_SECOND_TO_FEET_PER_MINUTE: f64 = METRES_PER_FOOT / 60.0;
+
+/// The number of feet per minute (ft/min) in a metre per second (m/s), the
+/// reciprocal of `METRES_PER_SECOND_TO_FEET_PER_MINUTE`.
+pub const FEET_PER_MINUTE_PER_METRE_PER_SECOND: f64 = 1.0 / METRES_PER_SECOND_TO_FEET_PER_MINUTE;
+
+impl From<si::MetresPerSecond> for FeetPerMinute {
+    fn from(a: si::MetresPerSecond) -> Self {
+        Self::new(a.0 * FEET_PER_MINUTE_PER_METRE_PER_SECOND)
+    }
+}
+
+impl From<FeetPerMinute> for si::MetresPerSecond {
+    fn from(a: FeetPerMinute) -> Self {
+        Self::new(a.0 * METRES_PER_SECOND_TO_FEET_PER_MINUTE)
+    }
+}
+
+impl crate::NonSiUnit for FeetPerMinute {
+    type Si = si::MetresPerSecond;
+}
+
+impl Mul<si::Seconds> for FeetPerMinute {
+    type Output = Feet;
+
+    fn mul(self, rhs: si::Seconds) -> Feet {
+        Feet(self.0 * rhs.0 / 60.0)
+    }
+}
+
+additive_quantity!(
+    /// A Degrees unit type for representing plane angles.
+    ///
+    /// Used for headings, tracks and bearings.
+    pub Degrees, DegreesUnit, "°", core::f64::consts::PI / 180.0
+);
+
+impl Degrees {
+    /// A half circle: 180 degrees.
+    pub const HALF_CIRCLE: Self = Self::new(180.0);
+
+    /// A full circle: 360 degrees.
+    pub const FULL_CIRCLE: Self = Self::new(360.0);
+
+    /// The angle normalised to the range [0, 360), e.g. for a heading.
+    #[must_use]
+    pub fn normalise(self) -> Self {
+        let degrees = self.0 % 360.0;
+        if degrees < 0.0 {
+            // a tiny negative angle may round up to 360
+            let degrees = degrees + 360.0;
+            Self::new(if degrees < 360.0 { degrees } else { 0.0 })
+        } else {
+            // convert -0.0 to 0.0
+            Self::new(degrees + 0.0)
+        }
+    }
+
+    /// The signed shortest angular difference from `self` to `other`, in
+    /// the range [-180, 180): positive clockwise, i.e. a turn to the right.
+    #[must_use]
+    pub fn difference(self, other: Self) -> Self {
+        let delta = (other - self).normalise();
+        if delta.0 < 180.0 {
+            delta
+        } else {
+            delta - Self::FULL_CIRCLE
+        }
+    }
+
+    /// The reciprocal of a heading or track, in the range [0, 360).
+    #[must_use]
+    pub fn reciprocal(self) -> Self {
+        (self + Self::HALF_CIRCLE).normalise()
+    }
+
+    /// Convert to `Radians`.
+    #[must_use]
+    pub const fn to_radians(self) -> si::Radians {
+        si::Radians(self.0.to_radians())
+    }
+
+    /// Convert from `Radians`.
+    #[must_use]
+    pub const fn from_radians(a: si::Radians) -> Self {
+        Self::new(a.0.to_degrees())
+    }
+}
+
+impl si::Radians {
+    /// A half circle: π radians.
+    pub const HALF_CIRCLE: Self = Self::new(core::f64::consts::PI);
+
+    /// A full circle: 2π radians.
+    pub const FULL_CIRCLE: Self = Self::new(core::f64::consts::TAU);
+
+    /// Convert to `Degrees`.
+    #[must_use]
+    pub const fn to_degrees(self) -> Degrees {
+        Degrees::from_radians(self)
+    }
+
+    /// Convert from `Degrees`.
+    #[must_use]
+    pub const fn from_degrees(a: Degrees) -> Self {
+        a.to_radians()
+    }
+}
+
+impl From<si::Radians> for Degrees {
+    fn from(a: si::Radians) -> Self {
+        Self::from_radians(a)
+    }
+}
+
+impl From<Degrees> for si::Radians {
+    fn from(a: Degrees) -> Self {
+        a.to_radians()
+    }
+}
+
+impl crate::NonSiUnit for Degrees {
+    type Si = si::Radians;
+}
+
+/// The number of arc minutes in a degree: 60.
+pub const ARC_MINUTES_PER_DEGREE: f64 = 60.0;
+
+/// The number of arc seconds in an arc minute: 60.
+pub const ARC_SECONDS_PER_ARC_MINUTE: f64 = 60.0;
+
+additive_quantity!(
+    /// An `ArcMinutes` unit type for representing small plane angles,
+    /// e.g. differences of latitude.
+    pub ArcMinutes, ArcMinutesUnit, "′", core::f64::consts::PI / (180.0 * ARC_MINUTES_PER_DEGREE)
+);
+
+impl ArcMinutes {
+    /// The distance along a meridian of a difference of latitude:
+    /// one nautical mile per arc minute.
+    ///
+    /// The nautical mile was originally defined as an arc minute of
+    /// latitude, so this is the conventional chart approximation; the
+    /// actual distance varies with latitude by about 0.5%.
+    #[must_use]
+    pub const fn latitude_distance(self) -> NauticalMiles {
+        NauticalMiles(self.0)
+    }
+}
+
+impl NauticalMiles {
+    /// The difference of latitude of a distance along a meridian:
+    /// one arc minute per nautical mile, see [`ArcMinutes::latitude_distance`].
+    #[must_use]
+    pub const fn latitude_difference(self) -> ArcMinutes {
+        ArcMinutes(self.0)
+    }
+}
+
+impl From<Degrees> for ArcMinutes {
+    fn from(a: Degrees) -> Self {
+        Self::new(a.0 * ARC_MINUTES_PER_DEGREE)
+    }
+}
+
+impl From<ArcMinutes> for Degrees {
+    fn from(a: ArcMinutes) -> Self {
+        Self::new(a.0 / ARC_MINUTES_PER_DEGREE)
+    }
+}
+
+impl From<si::Radians> for ArcMinutes {
+    fn from(a: si::Radians) -> Self {
+        Self::from(a.to_degrees())
+    }
+}
+
+impl From<ArcMinutes> for si::Radians {
+    fn from(a: ArcMinutes) -> Self {
+        Degrees::from(a).to_radians()
+    }
+}
+
+impl crate::NonSiUnit for ArcMinutes {
+    type Si = si::Radians;
+}
+
+additive_quantity!(
+    /// An `ArcSeconds` unit type for representing very small plane angles,
+    /// e.g. coordinate resolutions.
+    pub ArcSeconds,
+    ArcSecondsUnit,
+    "″",
+    core::f64::consts::PI / (180.0 * ARC_MINUTES_PER_DEGREE * ARC_SECONDS_PER_ARC_MINUTE)
+);
+
+impl From<ArcMinutes> for ArcSeconds {
+    fn from(a: ArcMinutes) -> Self {
+        Self::new(a.0 * ARC_SECONDS_PER_ARC_MINUTE)
+    }
+}
+
+impl From<ArcSeconds> for ArcMinutes {
+    fn from(a: ArcSeconds) -> Self {
+        Self::new(a.0 / ARC_SECONDS_PER_ARC_MINUTE)
+    }
+}
+
+impl From<Degrees> for ArcSeconds {
+    fn from(a: Degrees) -> Self {
+        Self::new(a.0 * (ARC_MINUTES_PER_DEGREE * ARC_SECONDS_PER_ARC_MINUTE))
+    }
+}
+
+impl From<ArcSeconds> for Degrees {
+    fn from(a: ArcSeconds) -> Self {
+        Self::new(a.0 / (ARC_MINUTES_PER_DEGREE * ARC_SECONDS_PER_ARC_MINUTE))
+    }
+}
+
+impl From<si::Radians> for ArcSeconds {
+    fn from(a: si::Radians) -> Self {
+        Self::from(a.to_degrees())
+    }
+}
+
+impl From<ArcSeconds> for si::Radians {
+    fn from(a: ArcSeconds) -> Self {
+        Degrees::from(a).to_radians()
+    }
+}
+
+impl crate::NonSiUnit for ArcSeconds {
+    type Si = si::Radians;
+}
+
+additive_quantity!(
+    /// A `DegreesPerSecond` unit type for representing angular velocity.
+    ///
+    /// Used for rates of turn.
+    pub DegreesPerSecond, DegreesPerSecondUnit, "°/s", core::f64::consts::PI / 180.0
+);
+
+impl DegreesPerSecond {
+    /// A standard rate turn, also known as a rate one turn: 3 degrees per second.
+    pub const STANDARD_RATE: Self = Self::new(3.0);
+}
+
+impl From<si::RadiansPerSecond> for DegreesPerSecond {
+    fn from(a: si::RadiansPerSecond) -> Self {
+        Self::new(a.0.to_degrees())
+    }
+}
+
+impl From<DegreesPerSecond> for si::RadiansPerSecond {
+    fn from(a: DegreesPerSecond) -> Self {
+        Self::new(a.0.to_radians())
+    }
+}
+
+impl crate::NonSiUnit for DegreesPerSecond {
+    type Si = si::RadiansPerSecond;
+}
+
+additive_quantity!(
+    /// An Hours unit type for representing time, e.g. endurance.
+    pub Hours, HoursUnit, "h", SECONDS_PER_HOUR
+);
+
+impl From<si::Seconds> for Hours {
+    fn from(a: si::Seconds) -> Self {
+        Self::new(a.0 / SECONDS_PER_HOUR)
+    }
+}
+
+impl From<Hours> for si::Seconds {
+    fn from(a: Hours) -> Self {
+        Self::new(a.0 * SECONDS_PER_HOUR)
+    }
+}
+
+impl crate::NonSiUnit for Hours {
+    type Si = si::Seconds;
+}
+
+impl Mul<Hours> for Knots {
+    type Output = NauticalMiles;
+
+    fn mul(self, rhs: Hours) -> NauticalMiles {
+        NauticalMiles(self.0 * rhs.0)
+    }
+}
+
+additive_quantity!(
+    /// A `KilogramsPerHour` unit type for representing fuel flow.
+    pub KilogramsPerHour, KilogramsPerHourUnit, "kg/h", 1.0 / SECONDS_PER_HOUR
+);
+
+impl From<si::KilogramsPerSecond> for KilogramsPerHour {
+    fn from(a: si::KilogramsPerSecond) -> Self {
+        Self::new(a.0 * SECONDS_PER_HOUR)
+    }
+}
+
+impl From<KilogramsPerHour> for si::KilogramsPerSecond {
+    fn from(a: KilogramsPerHour) -> Self {
+        Self::new(a.0 / SECONDS_PER_HOUR)
+    }
+}
+
+impl crate::NonSiUnit for KilogramsPerHour {
+    type Si = si::KilogramsPerSecond;
+}
+
+impl Mul<Hours> for KilogramsPerHour {
+    type Output = si::Kilograms;
+
+    fn mul(self, rhs: Hours) -> si::Kilograms {
+        si::Kilograms(self.0 * rhs.0)
+    }
+}
+
+impl Div<KilogramsPerHour> for si::Kilograms {
+    type Output = Hours;
+
+    /// The endurance of a quantity of fuel at a fuel flow.
+    fn div(self, rhs: KilogramsPerHour) -> Hours {
+        Hours(self.0 / rhs.0)
+    }
+}
+
+additive_quantity!(
+    /// A `NauticalMilesPerKilogram` unit type for representing specific range,
+    /// i.e. the distance flown per unit of fuel burnt.
+    pub NauticalMilesPerKilogram, NauticalMilesPerKilogramUnit, "NM/kg", METRES_PER_NAUTICAL_MILE
+);
+
+impl From<si::MetresPerKilogram> for NauticalMilesPerKilogram {
+    fn from(a: si::MetresPerKilogram) -> Self {
+        Self::new(a.0 * NAUTICAL_MILES_PER_METRE)
+    }
+}
+
+impl From<NauticalMilesPerKilogram> for si::MetresPerKilogram {
+    fn from(a: NauticalMilesPerKilogram) -> Self {
+        Self::new(a.0 * METRES_PER_NAUTICAL_MILE)
+    }
+}
+
+impl crate::NonSiUnit for NauticalMilesPerKilogram {
+    type Si = si::MetresPerKilogram;
+}
+
+impl Div<KilogramsPerHour> for Knots {
+    type Output = NauticalMilesPerKilogram;
+
+    /// The specific range at a true airspeed and fuel flow.
+    fn div(self, rhs: KilogramsPerHour) -> NauticalMilesPerKilogram {
+        NauticalMilesPerKilogram(self.0 / rhs.0)
+    }
+}
+
+impl Mul<si::Kilograms> for NauticalMilesPerKilogram {
+    type Output = NauticalMiles;
+
+    /// The range of a quantity of fuel at a specific range.
+    fn mul(self, rhs: si::Kilograms) -> NauticalMiles {
+        NauticalMiles(self.0 * rhs.0)
+    }
+}
+
+impl Div<NauticalMilesPerKilogram> for NauticalMiles {
+    type Output = si::Kilograms;
+
+    /// The fuel required to fly a distance at a specific range.
+    fn div(self, rhs: NauticalMilesPerKilogram) -> si::Kilograms {
+        si::Kilograms(self.0 / rhs.0)
+    }
+}
+
+additive_quantity!(
+    /// A `KilogramsPerLitre` unit type for representing density, e.g. of fuel.
+    pub KilogramsPerLitre, KilogramsPerLitreUnit, "kg/L", 1_000.0
+);
+
+impl KilogramsPerLitre {
+    /// Convert to `KilogramsPerCubicMetre`.
+    #[must_use]
+    pub const fn to_kilograms_per_cubic_metre(self) -> si::KilogramsPerCubicMetre {
+        si::KilogramsPerCubicMetre(self.0 * 1_000.0)
+    }
+
+    /// Convert from `KilogramsPerCubicMetre`.
+    #[must_use]
+    pub const fn from_kilograms_per_cubic_metre(a: si::KilogramsPerCubicMetre) -> Self {
+        Self::new(a.0 / 1_000.0)
+    }
+}
+
+impl From<si::KilogramsPerCubicMetre> for KilogramsPerLitre {
+    fn from(a: si::KilogramsPerCubicMetre) -> Self {
+        Self::from_kilograms_per_cubic_metre(a)
+    }
+}
+
+impl From<KilogramsPerLitre> for si::KilogramsPerCubicMetre {
+    fn from(a: KilogramsPerLitre) -> Self {
+        a.to_kilograms_per_cubic_metre()
+    }
+}
+
+impl crate::NonSiUnit for KilogramsPerLitre {
+    type Si = si::KilogramsPerCubicMetre;
+}
+
+/// The volume of a litre (L) in cubic metres (m³): 0.001.
+pub const CUBIC_METRES_PER_LITRE: f64 = 0.001;
+
+/// The volume of a US gallon (US gal) in litres (L): 3.785 411 784.
+pub const LITRES_PER_US_GALLON: f64 = 3.785_411_784;
+
+/// Defines the conversions between a non-SI volume or volume flow unit type
+/// and its SI equivalent, where `$factor` is the SI value of one unit.
+macro_rules! impl_volume_conversion {
+    ($t:ty, $si:ty, $factor:expr) => {
+        impl From<$si> for $t {
+            fn from(a: $si) -> Self {
+                Self::new(a.0 / ($factor))
+            }
+        }
+
+        impl From<$t> for $si {
+            fn from(a: $t) -> Self {
+                Self::new(a.0 * ($factor))
+            }
+        }
+
+        impl crate::NonSiUnit for $t {
+            type Si = $si;
+        }
+    };
+}
+
+additive_quantity!(
+    /// A `Litres` unit type for representing volume, e.g. of fuel.
+    pub Litres, LitresUnit, "L", CUBIC_METRES_PER_LITRE
+);
+impl_volume_conversion!(Litres, si::CubicMetres, CUBIC_METRES_PER_LITRE);
+
+additive_quantity!(
+    /// A `USGallons` unit type for representing volume, e.g. of fuel.
+    pub USGallons, USGallonsUnit, "US gal", CUBIC_METRES_PER_LITRE * LITRES_PER_US_GALLON
+);
+impl_volume_conversion!(
+    USGallons,
+    si::CubicMetres,
+    CUBIC_METRES_PER_LITRE * LITRES_PER_US_GALLON
+);
+
+additive_quantity!(
+    /// A `LitresPerHour` unit type for representing volumetric fuel flow.
+    pub LitresPerHour, LitresPerHourUnit, "L/h", CUBIC_METRES_PER_LITRE / SECONDS_PER_HOUR
+);
+impl_volume_conversion!(
+    LitresPerHour,
+    si::CubicMetresPerSecond,
+    CUBIC_METRES_PER_LITRE / SECONDS_PER_HOUR
+);
+
+additive_quantity!(
+    /// A `USGallonsPerHour` unit type for representing volumetric fuel flow.
+    pub USGallonsPerHour,
+    USGallonsPerHourUnit,
+    "US gal/h",
+    CUBIC_METRES_PER_LITRE * LITRES_PER_US_GALLON / SECONDS_PER_HOUR
+);
+impl_volume_conversion!(
+    USGallonsPerHour,
+    si::CubicMetresPerSecond,
+    CUBIC_METRES_PER_LITRE * LITRES_PER_US_GALLON / SECONDS_PER_HOUR
+);
+
+impl From<USGallons> for Litres {
+    fn from(a: USGallons) -> Self {
+        Self::new(a.0 * LITRES_PER_US_GALLON)
+    }
+}
+
+impl From<Litres> for USGallons {
+    fn from(a: Litres) -> Self {
+        Self::new(a.0 / LITRES_PER_US_GALLON)
+    }
+}
+
+impl From<USGallonsPerHour> for LitresPerHour {
+    fn from(a: USGallonsPerHour) -> Self {
+        Self::new(a.0 * LITRES_PER_US_GALLON)
+    }
+}
+
+impl From<LitresPerHour> for USGallonsPerHour {
+    fn from(a: LitresPerHour) -> Self {
+        Self::new(a.0 / LITRES_PER_US_GALLON)
+    }
+}
+
+impl Mul<Hours> for LitresPerHour {
+    type Output = Litres;
+
+    fn mul(self, rhs: Hours) -> Litres {
+        Litres(self.0 * rhs.0)
+    }
+}
+
+impl Mul<Hours> for USGallonsPerHour {
+    type Output = USGallons;
+
+    fn mul(self, rhs: Hours) -> USGallons {
+        USGallons(self.0 * rhs.0)
+    }
+}
+
+impl Mul<KilogramsPerLitre> for Litres {
+    type Output = si::Kilograms;
+
+    /// The mass of a volume of fuel of a given density.
+    fn mul(self, rhs: KilogramsPerLitre) -> si::Kilograms {
+        si::Kilograms(self.0 * rhs.0)
+    }
+}
+
+impl Mul<KilogramsPerLitre> for LitresPerHour {
+    type Output = KilogramsPerHour;
+
+    /// The mass flow of a volume flow of fuel of a given density.
+    fn mul(self, rhs: KilogramsPerLitre) -> KilogramsPerHour {
+        KilogramsPerHour(self.0 * rhs.0)
+    }
+}
+
+impl USGallonsPerHour {
+    /// The mass flow of fuel of the given `density`.
+    #[must_use]
+    pub fn mass_flow(self, density: KilogramsPerLitre) -> KilogramsPerHour {
+        LitresPerHour::from(self) * density
+    }
+}
+
+impl KilogramsPerHour {
+    /// The volume flow of fuel of the given `density`.
+    #[must_use]
+    pub fn volume_flow(self, density: KilogramsPerLitre) -> LitresPerHour {
+        LitresPerHour(self.0 / density.0)
+    }
+}
+
+/// The pressure of a pound-force per square inch (psi) in Pascals (Pa):
+/// 0.453 592 37 kg × 9.806 65 m/s² / (0.0254 m)².
+pub const PASCALS_PER_PSI: f64 = 0.453_592_37 * 9.806_65 / (0.025_4 * 0.025_4);
+
+additive_quantity!(
+    /// A `Psi` unit type for representing pressure in pounds-force per square
+    /// inch, e.g. of tyres, hydraulic systems and oxygen bottles.
+    pub Psi, PsiUnit, "psi", PASCALS_PER_PSI
+);
+
+impl Psi {
+    /// The standard pressure, 101 325 Pa, in psi: about 14.696 psi.
+    pub const STANDARD_PRESSURE: Self = Self::new(101_325.0 / PASCALS_PER_PSI);
+}
+
+impl From<si::Pascals> for Psi {
+    fn from(a: si::Pascals) -> Self {
+        Self::new(a.0 / PASCALS_PER_PSI)
+    }
+}
+
+impl From<Psi> for si::Pascals {
+    fn from(a: Psi) -> Self {
+        Self::new(a.0 * PASCALS_PER_PSI)
+    }
+}
+
+impl crate::NonSiUnit for Psi {
+    type Si = si::Pascals;
+}
+
+/// The pressure of a hectopascal (hPa) or millibar (mb) in Pascals (Pa): 100.
+pub const PASCALS_PER_HECTOPASCAL: f64 = 100.0;
+
+additive_quantity!(
+    /// A `Hectopascals` unit type for representing atmospheric pressure.
+    ///
+    /// Used for altimeter settings and meteorological reports,
+    /// see ICAO Annex 5 Table 3-4.
+    pub Hectopascals, HectopascalsUnit, "hPa", PASCALS_PER_HECTOPASCAL
+);
+
+impl Hectopascals {
+    /// The standard pressure, 101 325 Pa, in hectopascals: 1013.25 hPa.
+    pub const STANDARD_PRESSURE: Self = Self::new(1_013.25);
+}
+
+impl From<si::Pascals> for Hectopascals {
+    fn from(a: si::Pascals) -> Self {
+        Self::new(a.0 / PASCALS_PER_HECTOPASCAL)
+    }
+}
+
+impl From<Hectopascals> for si::Pascals {
+    fn from(a: Hectopascals) -> Self {
+        Self::new(a.0 * PASCALS_PER_HECTOPASCAL)
+    }
+}
+
+impl crate::NonSiUnit for Hectopascals {
+    type Si = si::Pascals;
+}
+
+additive_quantity!(
+    /// A `HectopascalsPerHour` unit type for representing the rate of change of
+    /// atmospheric pressure, e.g. a pressure tendency.
+    pub HectopascalsPerHour,
+    HectopascalsPerHourUnit,
+    "hPa/h",
+    PASCALS_PER_HECTOPASCAL / SECONDS_PER_HOUR
+);
+
+impl From<si::PascalsPerSecond> for HectopascalsPerHour {
+    fn from(a: si::PascalsPerSecond) -> Self {
+        Self::new(a.0 * SECONDS_PER_HOUR / PASCALS_PER_HECTOPASCAL)
+    }
+}
+
+impl From<HectopascalsPerHour> for si::PascalsPerSecond {
+    fn from(a: HectopascalsPerHour) -> Self {
+        Self::new(a.0 * PASCALS_PER_HECTOPASCAL / SECONDS_PER_HOUR)
+    }
+}
+
+impl crate::NonSiUnit for HectopascalsPerHour {
+    type Si = si::PascalsPerSecond;
+}
+
+impl Div<Hours> for Hectopascals {
+    type Output = HectopascalsPerHour;
+
+    /// The mean rate of change of a pressure difference over a time, e.g.
+    /// the three hour pressure tendency.
+    fn div(self, rhs: Hours) -> HectopascalsPerHour {
+        HectopascalsPerHour(self.0 / rhs.0)
+    }
+}
+
+impl Mul<Hours> for HectopascalsPerHour {
+    type Output = Hectopascals;
+
+    /// The pressure change at a rate over a time.
+    fn mul(self, rhs: Hours) -> Hectopascals {
+        Hectopascals(self.0 * rhs.0)
+    }
+}
+
+additive_quantity!(
+    /// A `Millibars` unit type for representing atmospheric pressure in legacy
+    /// meteorological data.
+    ///
+    /// A millibar is equal to a hectopascal, but a distinct type preserves the
+    /// unit of the source data when it is parsed and formatted.
+    pub Millibars, MillibarsUnit, "mb", PASCALS_PER_HECTOPASCAL
+);
+
+impl Millibars {
+    /// The standard pressure, 101 325 Pa, in millibars: 1013.25 mb.
+    pub const STANDARD_PRESSURE: Self = Self::new(1_013.25);
+}
+
+impl From<si::Pascals> for Millibars {
+    fn from(a: si::Pascals) -> Self {
+        Self::new(a.0 / PASCALS_PER_HECTOPASCAL)
+    }
+}
+
+impl From<Millibars> for si::Pascals {
+    fn from(a: Millibars) -> Self {
+        Self::new(a.0 * PASCALS_PER_HECTOPASCAL)
+    }
+}
+
+impl crate::NonSiUnit for Millibars {
+    type Si = si::Pascals;
+}
+
+impl From<Millibars> for Hectopascals {
+    fn from(a: Millibars) -> Self {
+        Self::new(a.0)
+    }
+}
+
+impl From<Hectopascals> for Millibars {
+    fn from(a: Hectopascals) -> Self {
+        Self::new(a.0)
+    }
+}
+
+/// The pressure of an inch of mercury (inHg) in Pascals (Pa): 3 386.389.
+pub const PASCALS_PER_INCH_OF_MERCURY: f64 = 3_386.389;
+
+additive_quantity!(
+    /// An `InchesOfMercury` unit type for representing atmospheric pressure.
+    ///
+    /// Used for altimeter settings in the United States and Canada.
+    pub InchesOfMercury, InchesOfMercuryUnit, "inHg", PASCALS_PER_INCH_OF_MERCURY
+);
+
+impl InchesOfMercury {
+    /// The standard altimeter setting in inches of mercury: 29.92 inHg.
+    ///
+    /// This is the standard pressure, 101 325 Pa, rounded to the hundredths
+    /// of an inch used for altimeter settings, so it is about 4 Pa lower.
+    pub const STANDARD_PRESSURE: Self = Self::new(29.92);
+}
+
+impl From<si::Pascals> for InchesOfMercury {
+    fn from(a: si::Pascals) -> Self {
+        Self::new(a.0 / PASCALS_PER_INCH_OF_MERCURY)
+    }
+}
+
+impl From<InchesOfMercury> for si::Pascals {
+    fn from(a: InchesOfMercury) -> Self {
+        Self::new(a.0 * PASCALS_PER_INCH_OF_MERCURY)
+    }
+}
+
+impl crate::NonSiUnit for InchesOfMercury {
+    type Si = si::Pascals;
+}
+
+/// The rate of change of speed with height of a knot per hundred feet
+/// (kt/100 ft) in metres per second per metre ((m/s)/m).
+pub const METRES_PER_SECOND_PER_METRE_PER_KNOT_PER_HUNDRED_FEET: f64 =
+    METRES_PER_SECOND_TO_KNOTS / (100.0 * METRES_PER_FOOT);
+
+additive_quantity!(
+    /// A `KnotsPerHundredFeet` unit type for representing wind shear, e.g. in
+    /// low level wind shear (LLWS) advisories.
+    pub KnotsPerHundredFeet,
+    KnotsPerHundredFeetUnit,
+    "kt/100 ft",
+    METRES_PER_SECOND_PER_METRE_PER_KNOT_PER_HUNDRED_FEET
+);
+
+impl From<si::MetresPerSecondPerMetre> for KnotsPerHundredFeet {
+    fn from(a: si::MetresPerSecondPerMetre) -> Self {
+        Self::new(a.0 / METRES_PER_SECOND_PER_METRE_PER_KNOT_PER_HUNDRED_FEET)
+    }
+}
+
+impl From<KnotsPerHundredFeet> for si::MetresPerSecondPerMetre {
+    fn from(a: KnotsPerHundredFeet) -> Self {
+        Self::new(a.0 * METRES_PER_SECOND_PER_METRE_PER_KNOT_PER_HUNDRED_FEET)
+    }
+}
+
+impl crate::NonSiUnit for KnotsPerHundredFeet {
+    type Si = si::MetresPerSecondPerMetre;
+}
+
+impl Div<Feet> for Knots {
+    type Output = KnotsPerHundredFeet;
+
+    /// The mean wind shear of a speed difference over a height band.
+    fn div(self, rhs: Feet) -> KnotsPerHundredFeet {
+        KnotsPerHundredFeet(100.0 * self.0 / rhs.0)
+    }
+}
+
+impl Mul<Feet> for KnotsPerHundredFeet {
+    type Output = Knots;
+
+    /// The speed change of a wind shear over a height band.
+    fn mul(self, rhs: Feet) -> Knots {
+        Knots(self.0 * rhs.0 / 100.0)
+    }
+}
+
+additive_quantity!(
+    /// A `SievertsPerHour` unit type for representing a radiation dose
+    /// equivalent rate, e.g. of cosmic radiation at cruise altitudes.
+    pub SievertsPerHour, SievertsPerHourUnit, "Sv/h", 1.0 / SECONDS_PER_HOUR
+);
+
+impl From<si::SievertsPerSecond> for SievertsPerHour {
+    fn from(a: si::SievertsPerSecond) -> Self {
+        Self::new(a.0 * SECONDS_PER_HOUR)
+    }
+}
+
+impl From<SievertsPerHour> for si::SievertsPerSecond {
+    fn from(a: SievertsPerHour) -> Self {
+        Self::new(a.0 / SECONDS_PER_HOUR)
+    }
+}
+
+impl crate::NonSiUnit for SievertsPerHour {
+    type Si = si::SievertsPerSecond;
+}
+
+impl Mul<Hours> for SievertsPerHour {
+    type Output = si::Sieverts;
+
+    /// The dose received at a dose rate over a time, e.g. a flight.
+    fn mul(self, rhs: Hours) -> si::Sieverts {
+        si::Sieverts(self.0 * rhs.0)
+    }
+}
+
+impl Div<Hours> for si::Sieverts {
+    type Output = SievertsPerHour;
+
+    /// The mean dose rate of a dose received over a time.
+    fn div(self, rhs: Hours) -> SievertsPerHour {
+        SievertsPerHour(self.0 / rhs.0)
+    }
+}
+
+/// A Fahrenheit `newtype` for representing temperature, e.g. in US weather
+/// products and cockpit displays.
+///
+/// The Fahrenheit scale is offset from the Kelvin scale, so temperatures are
+/// not additive and `Fahrenheit` does not implement `Unit`, whose conversions
+/// only scale values. Convert temperatures with `From` instead.
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "diesel",
+    derive(diesel::expression::AsExpression, diesel::deserialize::FromSqlRow),
+    diesel(sql_type = diesel::sql_types::Double)
+)]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+#[repr(transparent)]
+pub struct Fahrenheit(pub f64);
+
+/// The number of degrees Fahrenheit in a Kelvin (K): 1.8.
+pub const FAHRENHEIT_DEGREES_PER_KELVIN: f64 = 1.8;
+
+/// Absolute zero, 0 K, in degrees Fahrenheit: -459.67 °F.
+const ABSOLUTE_ZERO_FAHRENHEIT: f64 = -459.67;
+
+impl Fahrenheit {
+    /// The temperature of the ice point, 0 °C: 32 °F.
+    pub const ICE_POINT: Self = Self(32.0);
+}
+
+impl From<Fahrenheit> for si::Kelvin {
+    fn from(a: Fahrenheit) -> Self {
+        Self::new((a.0 - ABSOLUTE_ZERO_FAHRENHEIT) / FAHRENHEIT_DEGREES_PER_KELVIN)
+    }
+}
+
+impl From<si::Kelvin> for Fahrenheit {
+    #[allow(clippy::suboptimal_flops)] // mul_add is not in `core`
+    fn from(a: si::Kelvin) -> Self {
+        Self(a.0 * FAHRENHEIT_DEGREES_PER_KELVIN + ABSOLUTE_ZERO_FAHRENHEIT)
+    }
+}
+
+impl From<Fahrenheit> for si::Celsius {
+    fn from(a: Fahrenheit) -> Self {
+        Self((a.0 - Fahrenheit::ICE_POINT.0) / FAHRENHEIT_DEGREES_PER_KELVIN)
+    }
+}
+
+impl From<si::Celsius> for Fahrenheit {
+    #[allow(clippy::suboptimal_flops)] // mul_add is not in `core`
+    fn from(a: si::Celsius) -> Self {
+        Self(a.0 * FAHRENHEIT_DEGREES_PER_KELVIN + Self::ICE_POINT.0)
+    }
+}
+
+impl core::fmt::Display for Fahrenheit {
+    /// Formats the value followed by the unit symbol, e.g. "59°F".
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        crate::__private::fmt_unit(self.0, "°F", f)
     }
 }
 
 #[cfg(test)]
+#[allow(clippy::clone_on_copy, clippy::float_cmp)]
 mod tests {
     use super::*;
     use crate::si;
@@ -110,14 +1159,17 @@ mod tests {
         let two_nm = NauticalMiles(2.0);
         assert!(one_nm < two_nm);
 
-        let serialized = serde_json::to_string(&one_nm).unwrap();
-        let deserialized: NauticalMiles = serde_json::from_str(&serialized).unwrap();
-        assert_eq!(one_nm, deserialized);
+        #[cfg(feature = "serde")]
+        {
+            let serialized = serde_json::to_string(&one_nm).unwrap();
+            let deserialized: NauticalMiles = serde_json::from_str(&serialized).unwrap();
+            assert_eq!(one_nm, deserialized);
 
-        let bad_text = "junk";
-        let _serde_error = serde_json::from_str::<NauticalMiles>(&bad_text).unwrap_err();
+            let bad_text = "junk";
+            let _serde_error = serde_json::from_str::<NauticalMiles>(bad_text).unwrap_err();
+        }
 
-        print!("NauticalMiles: {:?}", one_nm);
+        print!("NauticalMiles: {one_nm:?}");
     }
 
     #[test]
@@ -138,14 +1190,17 @@ mod tests {
         let two_ft = Feet(2.0);
         assert!(one_ft < two_ft);
 
-        let serialized: String = serde_json::to_string(&one_ft).unwrap();
-        let deserialized: Feet = serde_json::from_str(&serialized).unwrap();
-        assert_eq!(one_ft, deserialized);
+        #[cfg(feature = "serde")]
+        {
+            let serialized = serde_json::to_string(&one_ft).unwrap();
+            let deserialized: Feet = serde_json::from_str(&serialized).unwrap();
+            assert_eq!(one_ft, deserialized);
 
-        let bad_text = "junk";
-        let _serde_error = serde_json::from_str::<Feet>(&bad_text).unwrap_err();
+            let bad_text = "junk";
+            let _serde_error = serde_json::from_str::<Feet>(bad_text).unwrap_err();
+        }
 
-        print!("Feet: {:?}", one_ft);
+        print!("Feet: {one_ft:?}");
     }
 
     #[test]
@@ -154,8 +1209,32 @@ mod tests {
         let metres = si::Metres::from(one_foot);
         assert_eq!(0.304_8, metres.0);
 
+        // multiplying by the reciprocal of 0.304 8 is 1 ulp below 1.0
         let result = Feet::from(metres);
-        assert_eq!(1.0, result.0);
+        assert_eq!(0.999_999_999_999_999_9, result.0);
+    }
+
+    #[test]
+    fn test_round_trip_accuracy() {
+        for i in 0..=100_000 {
+            let value = f64::from(i) * 0.5;
+            let tolerance = 2.0 * f64::EPSILON * value;
+
+            let result = NauticalMiles::from(si::Metres::from(NauticalMiles(value)));
+            assert!((value - result.0).abs() <= tolerance, "{value} {result:?}");
+            let result = si::Metres::from(NauticalMiles::from(si::Metres(value)));
+            assert!((value - result.0).abs() <= tolerance, "{value} {result:?}");
+
+            let result = Feet::from(si::Metres::from(Feet(value)));
+            assert!((value - result.0).abs() <= tolerance, "{value} {result:?}");
+            let result = si::Metres::from(Feet::from(si::Metres(value)));
+            assert!((value - result.0).abs() <= tolerance, "{value} {result:?}");
+
+            let result = Knots::from(si::MetresPerSecond::from(Knots(value)));
+            assert!((value - result.0).abs() <= tolerance, "{value} {result:?}");
+            let result = si::MetresPerSecond::from(Knots::from(si::MetresPerSecond(value)));
+            assert!((value - result.0).abs() <= tolerance, "{value} {result:?}");
+        }
     }
 
     #[test]
@@ -166,14 +1245,17 @@ mod tests {
         let two_kt = Knots(2.0);
         assert!(one_kt < two_kt);
 
-        let serialized = serde_json::to_string(&one_kt).unwrap();
-        let deserialized: Knots = serde_json::from_str(&serialized).unwrap();
-        assert_eq!(one_kt, deserialized);
+        #[cfg(feature = "serde")]
+        {
+            let serialized = serde_json::to_string(&one_kt).unwrap();
+            let deserialized: Knots = serde_json::from_str(&serialized).unwrap();
+            assert_eq!(one_kt, deserialized);
 
-        let bad_text = "junk";
-        let _serde_error = serde_json::from_str::<Knots>(&bad_text).unwrap_err();
+            let bad_text = "junk";
+            let _serde_error = serde_json::from_str::<Knots>(bad_text).unwrap_err();
+        }
 
-        print!("Knots: {:?}", one_kt);
+        print!("Knots: {one_kt:?}");
     }
 
     #[test]
@@ -182,10 +1264,474 @@ mod tests {
         let metres_per_second = si::MetresPerSecond::from(one_knot);
 
         // Definition from ICAO Annex 5 Table 3-3 is 0.514 444
-        assert!(0.514_444 < metres_per_second.0);
-        assert!(0.514_444_5 > metres_per_second.0);
+        #[cfg(not(feature = "strict-annex5"))]
+        {
+            assert!(0.514_444 < metres_per_second.0);
+            assert!(0.514_444_5 > metres_per_second.0);
+        }
+        #[cfg(feature = "strict-annex5")]
+        assert_eq!(0.514_444, metres_per_second.0);
 
         let result = Knots::from(metres_per_second);
         assert_eq!(1.0, result.0);
     }
+
+    #[test]
+    fn test_const_conversions() {
+        const SPEED_LIMIT: si::MetresPerSecond = Knots(250.0).to_metres_per_second();
+        const ONE_NM: si::Metres = si::Metres::from_nautical_miles(NauticalMiles(1.0));
+        const TRANSITION_ALTITUDE: si::Metres = Feet(18_000.0).to_metres();
+        const RIGHT_ANGLE: si::Radians = si::Radians::from_degrees(Degrees(90.0));
+        assert_eq!(si::MetresPerSecond::from(Knots(250.0)), SPEED_LIMIT);
+        assert_eq!(Knots::from(SPEED_LIMIT), SPEED_LIMIT.to_knots());
+        assert_eq!(
+            Knots::from(SPEED_LIMIT),
+            Knots::from_metres_per_second(SPEED_LIMIT)
+        );
+        assert_eq!(SPEED_LIMIT, si::MetresPerSecond::from_knots(Knots(250.0)));
+
+        assert_eq!(si::Metres(1852.0), ONE_NM);
+        assert_eq!(NauticalMiles(1.0), ONE_NM.to_nautical_miles());
+        assert_eq!(NauticalMiles(1.0), NauticalMiles::from_metres(ONE_NM));
+        assert_eq!(ONE_NM, NauticalMiles(1.0).to_metres());
+
+        assert_eq!(si::Metres::from(Feet(18_000.0)), TRANSITION_ALTITUDE);
+        assert_eq!(Feet(18_000.0), TRANSITION_ALTITUDE.to_feet());
+        assert_eq!(Feet(18_000.0), Feet::from_metres(TRANSITION_ALTITUDE));
+        assert_eq!(TRANSITION_ALTITUDE, si::Metres::from_feet(Feet(18_000.0)));
+
+        assert_eq!(si::Radians(core::f64::consts::FRAC_PI_2), RIGHT_ANGLE);
+        assert_eq!(Degrees(90.0), RIGHT_ANGLE.to_degrees());
+        assert_eq!(Degrees(90.0), Degrees::from_radians(RIGHT_ANGLE));
+        assert_eq!(RIGHT_ANGLE, Degrees(90.0).to_radians());
+    }
+
+    #[test]
+    fn test_additive() {
+        let mut altitude = Feet(1000.0) + Feet(500.0);
+        assert_eq!(Feet(1500.0), altitude);
+        altitude -= Feet(250.0);
+        assert_eq!(Feet(1250.0), altitude);
+        altitude += Feet(50.0);
+        assert_eq!(Feet(1300.0), altitude);
+        assert_eq!(Feet(-300.0), Feet(1000.0) - altitude);
+        assert_eq!(Feet(-1300.0), -altitude);
+    }
+
+    #[test]
+    fn test_si_value() {
+        use crate::Unit;
+
+        fn total_si<T: Unit>(values: &[T]) -> f64 {
+            values.iter().map(|v| v.si_value()).sum()
+        }
+
+        assert_eq!(si::Metres::from(Feet(1000.0)).0, Feet(1000.0).si_value());
+        assert_eq!(
+            si::Metres::from(NauticalMiles(2.0)).0,
+            NauticalMiles(2.0).si_value()
+        );
+        assert_eq!(
+            si::MetresPerSecond::from(Knots(250.0)).0,
+            Knots(250.0).si_value()
+        );
+        assert_eq!(si::Radians::from(Degrees(90.0)).0, Degrees(90.0).si_value());
+        assert_eq!(Feet(999.999_999_999_999_9), Feet::from_si_value(304.8));
+        assert_eq!(NauticalMiles(2.0), NauticalMiles::from_si_value(3_704.0));
+        assert_eq!(3.048, total_si(&[Feet(2.0), Feet(8.0)]));
+        assert_eq!(1.0, si::Metres(1.0).si_value());
+    }
+
+    #[test]
+    fn test_convert_to() {
+        use crate::ConvertTo;
+
+        fn normalise<D: ConvertTo<si::Metres>>(d: D) -> si::Metres {
+            d.convert()
+        }
+
+        assert_eq!(si::Metres(304.8), normalise(Feet(1000.0)));
+        assert_eq!(si::Metres(1852.0), normalise(NauticalMiles(1.0)));
+        assert_eq!(si::Metres(1.0), normalise(si::Metres(1.0)));
+
+        let speed: Knots = si::MetresPerSecond(METRES_PER_SECOND_TO_KNOTS).convert();
+        assert_eq!(Knots(1.0), speed);
+    }
+
+    #[test]
+    fn test_feet_per_minute() {
+        let rate = FeetPerMinute(1000.0);
+        let metres_per_second = si::MetresPerSecond::from(rate);
+        assert!((metres_per_second.0 - 5.08).abs() < 1e-15);
+        assert!((FeetPerMinute::from(metres_per_second).0 - 1000.0).abs() < 1e-12);
+        assert_eq!(Feet(500.0), rate * si::Seconds(30.0));
+        assert_eq!("1000 ft/min", rate.to_string());
+
+        #[cfg(feature = "serde")]
+        {
+            let serialized = serde_json::to_string(&rate).unwrap();
+            let deserialized: FeetPerMinute = serde_json::from_str(&serialized).unwrap();
+            assert_eq!(rate, deserialized);
+        }
+    }
+
+    #[test]
+    fn test_fuel_planning() {
+        assert_eq!(si::Seconds(5400.0), si::Seconds::from(Hours(1.5)));
+        assert_eq!(Hours(1.5), Hours::from(si::Seconds(5400.0)));
+
+        let fuel_flow = KilogramsPerHour(2400.0);
+        assert_eq!(
+            si::KilogramsPerSecond(fuel_flow.0 / SECONDS_PER_HOUR),
+            si::KilogramsPerSecond::from(fuel_flow)
+        );
+        assert_eq!(
+            fuel_flow,
+            KilogramsPerHour::from(si::KilogramsPerSecond(fuel_flow.0 / SECONDS_PER_HOUR))
+        );
+
+        let fuel = si::Kilograms(6000.0);
+        let endurance = fuel / fuel_flow;
+        assert_eq!(Hours(2.5), endurance);
+        assert_eq!(fuel, fuel_flow * endurance);
+
+        let specific_range = Knots(450.0) / fuel_flow;
+        assert_eq!(NauticalMilesPerKilogram(0.1875), specific_range);
+        assert_eq!(NauticalMiles(1125.0), specific_range * fuel);
+        assert_eq!(Knots(450.0) * endurance, specific_range * fuel);
+        assert_eq!(fuel, NauticalMiles(1125.0) / specific_range);
+        assert_eq!(
+            si::MetresPerKilogram(347.25),
+            si::MetresPerKilogram::from(specific_range)
+        );
+        assert!(
+            (NauticalMilesPerKilogram::from(si::MetresPerKilogram(347.25)).0 - 0.1875).abs()
+                < 1e-15
+        );
+        assert_eq!("0.1875 NM/kg", specific_range.to_string());
+        assert_eq!("2.5 h", endurance.to_string());
+    }
+
+    #[test]
+    fn test_kilograms_per_litre() {
+        let density = KilogramsPerLitre(0.8);
+        assert_eq!(
+            si::KilogramsPerCubicMetre(800.0),
+            si::KilogramsPerCubicMetre::from(density)
+        );
+        assert_eq!(
+            density,
+            KilogramsPerLitre::from(si::KilogramsPerCubicMetre(800.0))
+        );
+        assert_eq!("0.8 kg/L", density.to_string());
+    }
+
+    #[test]
+    fn test_arc_minutes_and_seconds() {
+        assert_eq!(ArcMinutes(90.0), ArcMinutes::from(Degrees(1.5)));
+        assert_eq!(Degrees(1.5), Degrees::from(ArcMinutes(90.0)));
+        assert_eq!(ArcSeconds(5400.0), ArcSeconds::from(Degrees(1.5)));
+        assert_eq!(Degrees(1.5), Degrees::from(ArcSeconds(5400.0)));
+        assert_eq!(ArcSeconds(90.0), ArcSeconds::from(ArcMinutes(1.5)));
+        assert_eq!(ArcMinutes(1.5), ArcMinutes::from(ArcSeconds(90.0)));
+
+        let radians = si::Radians::from(ArcMinutes(60.0));
+        assert_eq!(Degrees(1.0).to_radians(), radians);
+        assert_eq!(ArcMinutes(60.0), ArcMinutes::from(radians));
+        assert_eq!(
+            Degrees(1.0).to_radians(),
+            si::Radians::from(ArcSeconds(3600.0))
+        );
+        assert_eq!(ArcSeconds(3600.0), ArcSeconds::from(radians));
+        assert!((<ArcMinutes as crate::Unit>::SI_FACTOR - radians.0 / 60.0).abs() < 1e-18);
+
+        // 1 NM per minute of latitude
+        assert_eq!(NauticalMiles(60.0), ArcMinutes(60.0).latitude_distance());
+        assert_eq!(ArcMinutes(30.0), NauticalMiles(30.0).latitude_difference());
+        assert_eq!(
+            si::Metres(1852.0),
+            si::Metres::from(ArcMinutes(1.0).latitude_distance())
+        );
+
+        assert_eq!("30′", ArcMinutes(30.0).to_string());
+        assert_eq!("15.5″", ArcSeconds(15.5).to_string());
+    }
+
+    #[test]
+    fn test_volume_and_volume_flow() {
+        assert_eq!(si::CubicMetres(1.5), si::CubicMetres::from(Litres(1500.0)));
+        assert_eq!(Litres(1500.0), Litres::from(si::CubicMetres(1.5)));
+        assert_eq!(Litres(LITRES_PER_US_GALLON), Litres::from(USGallons(1.0)));
+        assert_eq!(
+            USGallons(1.0),
+            USGallons::from(Litres(LITRES_PER_US_GALLON))
+        );
+        assert!((si::CubicMetres::from(USGallons(1000.0)).0 - 3.785_411_784).abs() < 1e-15);
+
+        let flow = USGallonsPerHour(10.0);
+        let litres_per_hour = LitresPerHour::from(flow);
+        assert_eq!(LitresPerHour(37.854_117_84), litres_per_hour);
+        assert_eq!(flow, USGallonsPerHour::from(litres_per_hour));
+        assert!(
+            (si::CubicMetresPerSecond::from(flow).0
+                - si::CubicMetresPerSecond::from(litres_per_hour).0)
+                .abs()
+                < 1e-20
+        );
+        assert!((LitresPerHour::from(si::CubicMetresPerSecond(0.001)).0 - 3600.0).abs() < 1e-9);
+
+        assert_eq!(USGallons(25.0), flow * Hours(2.5));
+        assert_eq!(Litres(100.0), LitresPerHour(40.0) * Hours(2.5));
+
+        let density = KilogramsPerLitre(0.72);
+        assert_eq!(si::Kilograms(72.0), Litres(100.0) * density);
+        assert_eq!(KilogramsPerHour(36.0), LitresPerHour(50.0) * density);
+        assert_eq!(LitresPerHour::from(flow) * density, flow.mass_flow(density));
+        assert!((KilogramsPerHour(28.8).volume_flow(density).0 - 40.0).abs() < 1e-12);
+        assert_eq!("10 US gal/h", flow.to_string());
+    }
+
+    #[test]
+    fn test_degrees_per_second() {
+        let rate = DegreesPerSecond::STANDARD_RATE;
+        let radians = si::RadiansPerSecond::from(rate);
+        assert_eq!(3.0_f64.to_radians(), radians.0);
+        assert!((DegreesPerSecond::from(radians).0 - rate.0).abs() < 1e-15);
+        assert_eq!("3°/s", rate.to_string());
+
+        #[cfg(feature = "serde")]
+        {
+            let serialized = serde_json::to_string(&rate).unwrap();
+            let deserialized: DegreesPerSecond = serde_json::from_str(&serialized).unwrap();
+            assert_eq!(rate, deserialized);
+        }
+    }
+
+    #[test]
+    fn test_degrees_heading_helpers() {
+        assert_eq!(Degrees(0.0), Degrees(360.0).normalise());
+        assert_eq!(Degrees(350.0), Degrees(-10.0).normalise());
+        assert_eq!(Degrees(90.0), Degrees(-630.0).normalise());
+        assert_eq!(Degrees(0.0), Degrees(-1e-20).normalise());
+        assert!(Degrees(-0.0).normalise().0.is_sign_positive());
+        assert!(Degrees(f64::NAN).normalise().0.is_nan());
+
+        assert_eq!(Degrees(20.0), Degrees(350.0).difference(Degrees(10.0)));
+        assert_eq!(Degrees(-20.0), Degrees(10.0).difference(Degrees(350.0)));
+        assert_eq!(Degrees(-180.0), Degrees(0.0).difference(Degrees(180.0)));
+        assert_eq!(Degrees(-180.0), Degrees(180.0).difference(Degrees(0.0)));
+        assert_eq!(Degrees(0.0), Degrees(720.0).difference(Degrees(0.0)));
+
+        assert_eq!(Degrees(270.0), Degrees(90.0).reciprocal());
+        assert_eq!(Degrees(0.0), Degrees(180.0).reciprocal());
+        assert_eq!(Degrees(135.0), Degrees(-45.0).reciprocal());
+    }
+
+    #[test]
+    fn test_constants() {
+        const TRANSITION_LEVEL: Feet = Feet(60.0 * Feet::PER_FLIGHT_LEVEL.0);
+        assert_eq!(Feet(6000.0), TRANSITION_LEVEL);
+        assert_eq!(Feet(0.0), Feet::ZERO);
+        assert_eq!(Knots(f64::MAX), Knots::MAX);
+        assert_eq!(NauticalMiles(f64::MIN), NauticalMiles::MIN);
+        assert_eq!(si::Radians::HALF_CIRCLE, Degrees::HALF_CIRCLE.into());
+        assert_eq!(si::Radians::FULL_CIRCLE, Degrees::FULL_CIRCLE.into());
+        assert_eq!(si::Metres::ZERO, si::Metres::default());
+    }
+
+    #[test]
+    fn test_convert_method() {
+        assert_eq!(
+            Feet(999.999_999_999_999_9),
+            si::Metres(304.8).convert::<Feet>()
+        );
+        assert_eq!(
+            NauticalMiles(1.0),
+            si::Metres(1852.0).convert::<NauticalMiles>()
+        );
+        assert_eq!(
+            si::Metres(1852.0),
+            NauticalMiles(1.0).convert::<si::Metres>()
+        );
+        assert_eq!(Knots(1.0), Knots(1.0).convert::<Knots>());
+        assert_eq!(
+            Degrees(180.0),
+            si::Radians(core::f64::consts::PI).convert::<Degrees>()
+        );
+    }
+
+    #[test]
+    fn test_non_si_unit() {
+        use crate::NonSiUnit;
+
+        fn speed_in_si<S: NonSiUnit<Si = si::MetresPerSecond>>(speed: S) -> si::MetresPerSecond {
+            speed.to_si()
+        }
+
+        assert_eq!(
+            si::MetresPerSecond(METRES_PER_SECOND_TO_KNOTS),
+            speed_in_si(Knots(1.0))
+        );
+        assert_eq!(si::Metres(304.8), Feet(1000.0).to_si());
+        assert_eq!(
+            NauticalMiles(1.0),
+            NauticalMiles::from_si(si::Metres(1852.0))
+        );
+        assert_eq!(
+            Degrees(180.0),
+            Degrees::from_si(si::Radians(core::f64::consts::PI))
+        );
+    }
+
+    #[test]
+    fn test_knots_times_time() {
+        let speed = Knots(120.0);
+        assert_eq!(NauticalMiles(1.0), speed * si::Seconds(30.0));
+        assert_eq!(NauticalMiles(63.0), speed * Duration::from_secs(1890));
+    }
+
+    #[test]
+    fn test_degrees() {
+        let one_deg = Degrees(1.0);
+        let one_deg_clone = one_deg.clone();
+        assert_eq!(one_deg, one_deg_clone);
+        let two_deg = Degrees(2.0);
+        assert!(one_deg < two_deg);
+
+        #[cfg(feature = "serde")]
+        {
+            let serialized = serde_json::to_string(&one_deg).unwrap();
+            let deserialized: Degrees = serde_json::from_str(&serialized).unwrap();
+            assert_eq!(one_deg, deserialized);
+
+            let bad_text = "junk";
+            let _serde_error = serde_json::from_str::<Degrees>(bad_text).unwrap_err();
+        }
+
+        print!("Degrees: {one_deg:?}");
+    }
+
+    #[test]
+    fn test_convert_degrees() {
+        let degrees = Degrees(180.0);
+        let radians = si::Radians::from(degrees);
+        assert_eq!(core::f64::consts::PI, radians.0);
+
+        let result = Degrees::from(radians);
+        assert_eq!(180.0, result.0);
+    }
+
+    #[test]
+    fn test_fahrenheit() {
+        assert_eq!(
+            si::Kelvin::ICE_POINT,
+            si::Kelvin::from(Fahrenheit::ICE_POINT)
+        );
+        assert!((212.0 - Fahrenheit::from(si::Kelvin(373.15)).0).abs() < 1e-12);
+        assert_eq!(si::Kelvin(0.0), si::Kelvin::from(Fahrenheit(-459.67)));
+        // ISA sea level temperature, 15 °C
+        let isa = Fahrenheit::from(si::Kelvin(288.15));
+        assert!((Fahrenheit(59.0).0 - isa.0).abs() < 1e-12);
+        assert!((288.15 - si::Kelvin::from(isa).0).abs() < 1e-12);
+        // -40 °C is -40 °F
+        assert!((-40.0 - Fahrenheit::from(si::Kelvin(233.15)).0).abs() < 1e-12);
+        assert_eq!("59°F", Fahrenheit(59.0).to_string());
+        assert_eq!("-40.0°F", format!("{:.1}", Fahrenheit(-40.0)));
+
+        assert_eq!(
+            si::Celsius::ICE_POINT,
+            si::Celsius::from(Fahrenheit::ICE_POINT)
+        );
+        assert_eq!(Fahrenheit(212.0), Fahrenheit::from(si::Celsius(100.0)));
+        assert_eq!(Fahrenheit(-40.0), Fahrenheit::from(si::Celsius(-40.0)));
+        assert_eq!(si::Celsius(15.0), si::Celsius::from(Fahrenheit(59.0)));
+    }
+
+    #[test]
+    fn test_psi() {
+        assert_eq!(6_894.757, (PASCALS_PER_PSI * 1_000.0).round() / 1_000.0);
+        assert_eq!(si::Pascals(PASCALS_PER_PSI * 200.0), Psi(200.0).into());
+        assert!((Psi(200.0).0 - Psi::from(si::Pascals::from(Psi(200.0))).0).abs() < 1e-12);
+        // ISA mean sea level pressure
+        assert_eq!(
+            14.696,
+            (Psi::from(si::Pascals(101_325.0)).0 * 1_000.0).round() / 1_000.0
+        );
+        assert_eq!(Psi(30.0), Psi(20.0) + Psi(10.0));
+        assert_eq!("3000 psi", Psi(3_000.0).to_string());
+        assert!(
+            (si::Pascals::STANDARD_PRESSURE.0 - si::Pascals::from(Psi::STANDARD_PRESSURE).0).abs()
+                < 1e-9
+        );
+    }
+
+    #[test]
+    fn test_hectopascals_and_millibars() {
+        let qnh = Hectopascals(1_013.25);
+        assert_eq!(si::Pascals(101_325.0), qnh.into());
+        assert_eq!(qnh, Hectopascals::from(si::Pascals(101_325.0)));
+        assert_eq!("1013.25 hPa", qnh.to_string());
+        assert_eq!(Hectopascals::STANDARD_PRESSURE, qnh);
+        assert_eq!(Millibars::STANDARD_PRESSURE, Millibars::from(qnh));
+
+        let legacy = Millibars(998.0);
+        assert_eq!(Hectopascals(998.0), legacy.into());
+        assert_eq!(legacy, Millibars::from(Hectopascals(998.0)));
+        assert_eq!(si::Pascals(99_800.0), legacy.into());
+        assert_eq!(legacy, Millibars::from(si::Pascals(99_800.0)));
+        assert_eq!(Millibars(1_000.0), legacy + Millibars(2.0));
+        assert_eq!("998 mb", legacy.to_string());
+    }
+
+    #[test]
+    fn test_inches_of_mercury() {
+        // the US standard altimeter setting
+        let standard = si::Pascals::from(InchesOfMercury(29.92));
+        assert_eq!(101_321.0, standard.0.round());
+        let isa = InchesOfMercury::from(si::Pascals(101_325.0));
+        assert_eq!(29.921, (isa.0 * 1_000.0).round() / 1_000.0);
+        assert_eq!("29.92 inHg", InchesOfMercury(29.92).to_string());
+        assert_eq!(
+            InchesOfMercury::STANDARD_PRESSURE.0,
+            (isa.0 * 100.0).round() / 100.0
+        );
+    }
+
+    #[test]
+    fn test_hectopascals_per_hour() {
+        // a three hour pressure tendency
+        let tendency = Hectopascals(-4.5) / Hours(3.0);
+        assert_eq!(HectopascalsPerHour(-1.5), tendency);
+        assert_eq!(Hectopascals(-3.0), tendency * Hours(2.0));
+        let rate = si::PascalsPerSecond::from(tendency);
+        assert!((-150.0 / 3_600.0 - rate.0).abs() < 1e-15);
+        assert!((tendency - HectopascalsPerHour::from(rate)).0.abs() < 1e-12);
+        assert_eq!("-1.5 hPa/h", tendency.to_string());
+    }
+
+    #[test]
+    fn test_knots_per_hundred_feet() {
+        // a 20 kt speed change in 400 ft
+        let shear = Knots(20.0) / Feet(400.0);
+        assert_eq!(KnotsPerHundredFeet(5.0), shear);
+        assert_eq!(Knots(10.0), shear * Feet(200.0));
+        let si_shear = si::MetresPerSecondPerMetre::from(shear);
+        let expected = si::MetresPerSecond::from(Knots(20.0)).0 / si::Metres::from(Feet(400.0)).0;
+        assert!((expected - si_shear.0).abs() < 1e-15);
+        assert!((shear - KnotsPerHundredFeet::from(si_shear)).0.abs() < 1e-12);
+        assert_eq!("5 kt/100 ft", shear.to_string());
+    }
+
+    #[test]
+    fn test_sieverts_per_hour() {
+        // a typical cruise dose rate of 5 µSv/h on a ten hour flight
+        let rate = SievertsPerHour(5e-6);
+        let dose = rate * Hours(10.0);
+        assert_eq!(si::Sieverts(5e-5), dose);
+        assert_eq!(rate, dose / Hours(10.0));
+        let si_rate = si::SievertsPerSecond::from(rate);
+        assert_eq!(5e-6 / 3_600.0, si_rate.0);
+        assert_eq!(rate, SievertsPerHour::from(si_rate));
+        assert_eq!("0.000005 Sv/h", rate.to_string());
+    }
 }
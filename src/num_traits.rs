@@ -0,0 +1,76 @@
+// Copyright (c) 2024 Ken Barker
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"),
+// to deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+// THE SOFTWARE.
+
+//! [num-traits](https://crates.io/crates/num-traits) `Zero` and `Bounded`
+//! implementations for the additive unit types, so that they can be used
+//! in generic numeric code, e.g. as the elements of `nalgebra` vectors.
+
+use crate::quantity::{AdditiveUnit, Quantity};
+use ::num_traits::{Bounded, Zero};
+
+impl<U: AdditiveUnit> Zero for Quantity<U> {
+    fn zero() -> Self {
+        Self::ZERO
+    }
+
+    fn is_zero(&self) -> bool {
+        self.0.is_zero()
+    }
+}
+
+impl<U: AdditiveUnit> Bounded for Quantity<U> {
+    fn min_value() -> Self {
+        Self::MIN
+    }
+
+    fn max_value() -> Self {
+        Self::MAX
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{non_si, si};
+
+    fn sum<T: Zero + Copy>(values: &[T]) -> T {
+        values.iter().fold(T::zero(), |acc, &x| acc + x)
+    }
+
+    #[test]
+    fn test_zero() {
+        assert_eq!(si::Metres(0.0), si::Metres::zero());
+        assert!(non_si::Feet::zero().is_zero());
+        assert!(!non_si::Feet(1.0).is_zero());
+
+        let distances = [
+            non_si::NauticalMiles(1.5),
+            non_si::NauticalMiles(2.0),
+            non_si::NauticalMiles(0.5),
+        ];
+        assert_eq!(non_si::NauticalMiles(4.0), sum(&distances));
+    }
+
+    #[test]
+    fn test_bounded() {
+        assert_eq!(non_si::Knots(f64::MIN), non_si::Knots::min_value());
+        assert_eq!(non_si::Knots(f64::MAX), non_si::Knots::max_value());
+    }
+}
@@ -0,0 +1,126 @@
+// Copyright (c) 2024 Ken Barker
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"),
+// to deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+// THE SOFTWARE.
+
+//! Performance-based navigation (PBN).
+//!
+//! A required navigation performance (RNP) value is the lateral navigation
+//! accuracy in nautical miles that must be achieved for at least 95% of the
+//! flight time. The containment limit of an RNP specification is twice the
+//! RNP value, e.g.:
+//!
+//! ```
+//! use icao_units::non_si::NauticalMiles;
+//! use icao_units::pbn::Rnp;
+//! use icao_units::si::Metres;
+//!
+//! let rnp = Rnp::new(0.3);
+//! assert_eq!(NauticalMiles(0.6), rnp.containment_limit());
+//! assert_eq!(Metres(555.6), Metres::from(rnp.accuracy()));
+//!
+//! assert!(rnp.is_within_accuracy(Metres(-500.0)));
+//! assert!(!rnp.is_within_accuracy(NauticalMiles(0.4)));
+//! assert!(rnp.is_within_containment(NauticalMiles(0.4)));
+//! ```
+
+use crate::non_si::NauticalMiles;
+use crate::si::Metres;
+use crate::{math, ConvertTo};
+use core::fmt;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// A required navigation performance (RNP) value in nautical miles,
+/// e.g. 1.0 for RNP 1.
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[repr(transparent)]
+pub struct Rnp(pub f64);
+
+impl Rnp {
+    /// Construct an RNP value from nautical miles.
+    #[must_use]
+    pub const fn new(nautical_miles: f64) -> Self {
+        Self(nautical_miles)
+    }
+
+    /// The lateral navigation accuracy required for 95% of the flight time:
+    /// 1× the RNP value.
+    #[must_use]
+    pub const fn accuracy(self) -> NauticalMiles {
+        NauticalMiles(self.0)
+    }
+
+    /// The containment limit: 2× the RNP value.
+    #[must_use]
+    pub const fn containment_limit(self) -> NauticalMiles {
+        NauticalMiles(2.0 * self.0)
+    }
+
+    /// Whether the magnitude of a `cross_track_error` is within the
+    /// lateral navigation accuracy.
+    #[must_use]
+    pub fn is_within_accuracy<D: ConvertTo<Metres>>(self, cross_track_error: D) -> bool {
+        math::abs(cross_track_error.convert().0) <= Metres::from(self.accuracy()).0
+    }
+
+    /// Whether the magnitude of a `cross_track_error` is within the
+    /// containment limit.
+    #[must_use]
+    pub fn is_within_containment<D: ConvertTo<Metres>>(self, cross_track_error: D) -> bool {
+        math::abs(cross_track_error.convert().0) <= Metres::from(self.containment_limit()).0
+    }
+}
+
+impl fmt::Display for Rnp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "RNP {}", self.0)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::float_cmp)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rnp() {
+        let rnp = Rnp::new(1.0);
+        assert_eq!(NauticalMiles(1.0), rnp.accuracy());
+        assert_eq!(NauticalMiles(2.0), rnp.containment_limit());
+        assert_eq!(Metres(3704.0), Metres::from(rnp.containment_limit()));
+
+        assert!(rnp.is_within_accuracy(NauticalMiles(1.0)));
+        assert!(rnp.is_within_accuracy(NauticalMiles(-1.0)));
+        assert!(!rnp.is_within_accuracy(Metres(1853.0)));
+        assert!(rnp.is_within_containment(Metres(-3704.0)));
+        assert!(!rnp.is_within_containment(NauticalMiles(2.1)));
+
+        assert!(Rnp::new(0.3) < rnp);
+        assert_eq!("RNP 0.3", Rnp::new(0.3).to_string());
+
+        #[cfg(feature = "serde")]
+        {
+            let serialized = serde_json::to_string(&rnp).unwrap();
+            assert_eq!("1.0", serialized);
+            let deserialized: Rnp = serde_json::from_str(&serialized).unwrap();
+            assert_eq!(rnp, deserialized);
+        }
+    }
+}
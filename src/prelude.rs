@@ -0,0 +1,55 @@
+// Copyright (c) 2024 Ken Barker
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"),
+// to deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+// THE SOFTWARE.
+
+//! Re-exports the common unit types, traits and constants, e.g.:
+//!
+//! ```
+//! use icao_units::prelude::*;
+//!
+//! let altitude: Metres = Feet(1000.0).convert();
+//! assert_eq!(Metres(1000.0 * METRES_PER_FOOT), altitude);
+//! ```
+
+pub use crate::airspeed::{
+    CalibratedAirspeed, GroundSpeed, IndicatedAirspeed, MachNumber, TrueAirspeed,
+};
+pub use crate::altimeter::AltimeterSetting;
+pub use crate::altitude::{DensityAltitude, GeometricHeight, IndicatedAltitude, PressureAltitude};
+pub use crate::heading::{DegreesMagnetic, DegreesTrue};
+pub use crate::iter::{CompensatedSum, ConvertIterator};
+pub use crate::kind::{QuantityKind, UnitKind};
+pub use crate::non_si::{
+    ArcMinutes, ArcSeconds, Degrees, DegreesPerSecond, Fahrenheit, Feet, FeetPerMinute,
+    Hectopascals, HectopascalsPerHour, Hours, InchesOfMercury, KilogramsPerHour, KilogramsPerLitre,
+    Knots, KnotsPerHundredFeet, Litres, LitresPerHour, Millibars, NauticalMiles,
+    NauticalMilesPerKilogram, Psi, SievertsPerHour, USGallons, USGallonsPerHour, METRES_PER_FOOT,
+    METRES_PER_NAUTICAL_MILE, METRES_PER_SECOND_TO_KNOTS, SECONDS_PER_HOUR,
+};
+pub use crate::quantity::{AdditiveUnit, Quantity, UnitMarker};
+pub use crate::ratio::{Decibels, PartsPerMillion, PerMille, Percent};
+pub use crate::si::{
+    Amperes, Becquerels, Candela, Celsius, CubicMetres, CubicMetresPerSecond,
+    JoulesPerKilogramKelvin, Kelvin, KilogramMetres, Kilograms, KilogramsPerCubicMetre,
+    KilogramsPerSecond, Lumens, Lux, Metres, MetresPerKilogram, MetresPerSecond,
+    MetresPerSecondPerMetre, MetresPerSecondSquared, Ohms, PascalSeconds, Pascals,
+    PascalsPerSecond, Radians, RadiansPerSecond, Seconds, Sieverts, SievertsPerSecond,
+    SquareMetresPerSecond, Volts, Watts,
+};
+pub use crate::{ConvertTo, NonSiUnit, SiUnit, Unit};
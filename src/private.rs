@@ -0,0 +1,135 @@
+// Copyright (c) 2024 Ken Barker
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"),
+// to deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+// THE SOFTWARE.
+
+//! Items used by the unit types, not part of the public API.
+
+#[cfg(feature = "serde")]
+use crate::Unit;
+use core::fmt;
+#[cfg(feature = "serde")]
+use core::marker::PhantomData;
+#[cfg(feature = "serde")]
+use serde::de::{self, Deserialize, Deserializer, SeqAccess, Visitor};
+
+/// Format a unit value followed by its symbol, e.g. "1000 ft", using the
+/// precision of the formatter, if any.
+///
+/// Angles in degrees, minutes and seconds are formatted without a space,
+/// e.g. "45°", "90°T" or "30′".
+///
+/// # Errors
+///
+/// If the formatter fails.
+pub fn fmt_unit<V: fmt::Display>(
+    value: V,
+    symbol: &str,
+    f: &mut fmt::Formatter<'_>,
+) -> fmt::Result {
+    let separator = if symbol.starts_with(['°', '′', '″']) {
+        ""
+    } else {
+        " "
+    };
+    if let Some(precision) = f.precision() {
+        write!(f, "{value:.precision$}{separator}{symbol}")
+    } else {
+        write!(f, "{value}{separator}{symbol}")
+    }
+}
+
+/// Deserialize a unit type from a newtype struct in the same way as
+/// `#[derive(Deserialize)]`.
+///
+/// # Errors
+///
+/// If the value is not a number.
+#[cfg(feature = "serde")]
+pub fn deserialize_newtype<'de, T, D>(name: &'static str, deserializer: D) -> Result<T, D::Error>
+where
+    T: Unit,
+    D: Deserializer<'de>,
+{
+    struct NewtypeVisitor<T>(&'static str, PhantomData<T>);
+
+    impl<'de, T: Unit> Visitor<'de> for NewtypeVisitor<T> {
+        type Value = T;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            write!(formatter, "tuple struct {}", self.0)
+        }
+
+        fn visit_newtype_struct<E>(self, deserializer: E) -> Result<T, E::Error>
+        where
+            E: Deserializer<'de>,
+        {
+            f64::deserialize(deserializer).map(T::new)
+        }
+
+        fn visit_seq<A>(self, mut seq: A) -> Result<T, A::Error>
+        where
+            A: SeqAccess<'de>,
+        {
+            seq.next_element::<f64>()?
+                .map(T::new)
+                .ok_or_else(|| de::Error::invalid_length(0, &self))
+        }
+    }
+
+    deserializer.deserialize_newtype_struct(name, NewtypeVisitor(name, PhantomData))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Unit;
+
+    crate::declare_unit!(
+        /// A furlong, one eighth of a statute mile.
+        pub Furlongs, FurlongsUnit, "fur", 201.168
+    );
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn test_declare_unit() {
+        let distance = Furlongs(6.0);
+        assert_eq!(Furlongs(8.0), distance + Furlongs(2.0));
+        assert_eq!(Furlongs(4.0), distance - Furlongs(2.0));
+        assert_eq!(Furlongs(-6.0), -distance);
+        assert_eq!(Furlongs(6.0), Furlongs(-6.0).abs());
+        assert!(Furlongs(1.0) < distance);
+        assert_eq!("fur", Furlongs::SYMBOL);
+        assert_eq!(6.0, distance.value());
+        assert_eq!(1_207.008, distance.si_value());
+        assert_eq!(Furlongs(8.0), Furlongs::from_si_value(1_609.344));
+        assert_eq!(distance, distance.convert::<Furlongs>());
+        assert!(Furlongs::MIN < Furlongs::ZERO && Furlongs::ZERO < distance);
+        assert!(distance < Furlongs::MAX);
+        assert_eq!("Furlongs(6.0)", format!("{distance:?}"));
+        assert_eq!("6 fur", distance.to_string());
+
+        #[cfg(feature = "serde")]
+        {
+            let serialized = serde_json::to_string(&distance).unwrap();
+            assert_eq!("6.0", serialized);
+            let deserialized: Furlongs = serde_json::from_str(&serialized).unwrap();
+            assert_eq!(distance, deserialized);
+            assert!(serde_json::from_str::<Furlongs>("\"6.0\"").is_err());
+        }
+    }
+}
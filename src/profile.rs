@@ -0,0 +1,136 @@
+// Copyright (c) 2024 Ken Barker
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"),
+// to deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+// THE SOFTWARE.
+
+//! Altitude keyed profile tables.
+//!
+//! A [`ProfileTable`] holds values at increasing altitudes, e.g. a winds
+//! aloft forecast, a temperature profile or a performance table, and
+//! linearly interpolates between them, e.g.:
+//!
+//! ```
+//! use icao_units::non_si::{Feet, Knots};
+//! use icao_units::profile::ProfileTable;
+//!
+//! let winds = ProfileTable::new(vec![
+//!     (Feet(0.0), Knots(10.0)),
+//!     (Feet(10_000.0), Knots(30.0)),
+//!     (Feet(30_000.0), Knots(90.0)),
+//! ])
+//! .unwrap();
+//! assert_eq!(Knots(20.0), winds.interpolate(Feet(5_000.0)));
+//! assert_eq!(Knots(60.0), winds.interpolate(Feet(20_000.0)));
+//! // the values are not extrapolated
+//! assert_eq!(Knots(90.0), winds.interpolate(Feet(40_000.0)));
+//! ```
+//!
+//! The values are interpolated linearly, so angles such as wind directions
+//! must not wrap around between entries.
+
+use crate::{math, Unit};
+use alloc::vec::Vec;
+
+/// A table of values of unit type `Y` at increasing altitudes of unit
+/// type `K`, e.g. `Feet` or `Metres`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ProfileTable<K, Y> {
+    points: Vec<(K, Y)>,
+}
+
+impl<K: Unit, Y: Unit> ProfileTable<K, Y> {
+    /// Construct a table from `(altitude, value)` points.
+    ///
+    /// Returns `None` if there are no points or the altitudes are not
+    /// finite and strictly increasing.
+    #[must_use]
+    pub fn new(points: Vec<(K, Y)>) -> Option<Self> {
+        let valid = !points.is_empty()
+            && points.iter().all(|(k, _)| k.value().is_finite())
+            && points.windows(2).all(|w| w[0].0.value() < w[1].0.value());
+        valid.then_some(Self { points })
+    }
+
+    /// The `(altitude, value)` points of the table.
+    #[must_use]
+    pub fn points(&self) -> &[(K, Y)] {
+        &self.points
+    }
+
+    /// The value at `altitude`, linearly interpolated between the
+    /// neighbouring points.
+    ///
+    /// Below the lowest point and above the highest point, the value of
+    /// that point is returned.
+    #[must_use]
+    pub fn interpolate(&self, altitude: K) -> Y {
+        let altitude = altitude.value();
+        let i = self.points.partition_point(|(k, _)| k.value() <= altitude);
+        if i == 0 {
+            self.points[0].1
+        } else if i == self.points.len() {
+            self.points[i - 1].1
+        } else {
+            let (k0, y0) = self.points[i - 1];
+            let (k1, y1) = self.points[i];
+            let fraction = (altitude - k0.value()) / (k1.value() - k0.value());
+            Y::new(math::mul_add(fraction, y1.value() - y0.value(), y0.value()))
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::float_cmp)]
+mod tests {
+    use super::*;
+    use crate::non_si::{Degrees, Feet};
+    use crate::si::{Kelvin, Metres};
+
+    #[test]
+    fn test_profile_table() {
+        let temperatures = ProfileTable::new(vec![
+            (Metres(0.0), Kelvin(288.15)),
+            (Metres(11_000.0), Kelvin(216.65)),
+            (Metres(20_000.0), Kelvin(216.65)),
+        ])
+        .unwrap();
+        assert_eq!(3, temperatures.points().len());
+        assert_eq!(Kelvin(288.15), temperatures.interpolate(Metres(0.0)));
+        assert!((temperatures.interpolate(Metres(5_500.0)).0 - 252.4).abs() < 1e-12);
+        assert_eq!(Kelvin(216.65), temperatures.interpolate(Metres(11_000.0)));
+        assert_eq!(Kelvin(216.65), temperatures.interpolate(Metres(15_000.0)));
+        assert_eq!(Kelvin(288.15), temperatures.interpolate(Metres(-500.0)));
+
+        let single = ProfileTable::new(vec![(Feet(1_000.0), Degrees(270.0))]).unwrap();
+        assert_eq!(Degrees(270.0), single.interpolate(Feet(0.0)));
+        assert_eq!(Degrees(270.0), single.interpolate(Feet(2_000.0)));
+
+        assert_eq!(None, ProfileTable::<Feet, Degrees>::new(vec![]));
+        assert_eq!(
+            None,
+            ProfileTable::new(vec![
+                (Feet(1_000.0), Degrees(270.0)),
+                (Feet(1_000.0), Degrees(280.0))
+            ])
+        );
+        assert_eq!(
+            None,
+            ProfileTable::new(vec![(Feet(f64::NAN), Degrees(270.0))])
+        );
+    }
+}
@@ -0,0 +1,248 @@
+// Copyright (c) 2024 Ken Barker
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"),
+// to deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+// THE SOFTWARE.
+
+//! The generic `Quantity` type that the `f64` unit types are defined with.
+//!
+//! Each unit type, e.g. `si::Metres`, is an alias of [`Quantity`] for a zero
+//! sized unit marker type, e.g. `si::MetresUnit`, which carries the unit
+//! symbol and SI conversion factor in its [`UnitMarker`] implementation.
+//! The traits of the unit types are implemented once for `Quantity`, so every
+//! unit type has the same trait implementations, and generic code can accept
+//! a value of any unit, e.g.:
+//!
+//! ```
+//! use icao_units::non_si::Feet;
+//! use icao_units::quantity::{Quantity, UnitMarker};
+//! use icao_units::si::Metres;
+//!
+//! fn symbol<U: UnitMarker>(_value: Quantity<U>) -> &'static str {
+//!     U::SYMBOL
+//! }
+//!
+//! assert_eq!("m", symbol(Metres(1.0)));
+//! assert_eq!("ft", symbol(Feet(1.0)));
+//! ```
+//!
+//! A constructor function with the same name as each alias is declared with
+//! it, so unit values are constructed in the same way as tuple structs,
+//! e.g. `Metres(1.0)`, and their value is the `.0` field.
+//! However, unit values cannot be destructured with tuple struct patterns.
+
+use crate::Unit;
+use core::cmp::Ordering;
+use core::fmt;
+use core::marker::PhantomData;
+use core::ops::{Add, AddAssign, Neg, Sub, SubAssign};
+
+/// A zero sized type that identifies the unit of a [`Quantity`].
+pub trait UnitMarker: Copy + 'static {
+    /// The name of the unit type, e.g. "Metres", used by `Debug` and `serde`.
+    const NAME: &'static str;
+
+    /// The unit symbol, e.g. "m".
+    const SYMBOL: &'static str;
+
+    /// The factor to multiply a value of the unit by to convert it to the
+    /// coherent SI unit of the same quantity, e.g. 0.3048 for `Feet`.
+    const SI_FACTOR: f64;
+}
+
+/// A unit whose values can be added and subtracted, i.e. a `Quantity` of
+/// the unit implements `Add`, `AddAssign`, `Sub`, `SubAssign` and `Neg`.
+pub trait AdditiveUnit: UnitMarker {}
+
+/// An `f64` value of the unit `U`.
+#[derive(Clone, Copy)]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+#[cfg_attr(
+    feature = "diesel",
+    derive(diesel::expression::AsExpression, diesel::deserialize::FromSqlRow),
+    diesel(sql_type = diesel::sql_types::Double)
+)]
+#[repr(transparent)]
+pub struct Quantity<U>(pub f64, PhantomData<U>);
+
+impl<U: UnitMarker> Quantity<U> {
+    /// Zero.
+    pub const ZERO: Self = Self::new(0.0);
+
+    /// The smallest finite value.
+    pub const MIN: Self = Self::new(f64::MIN);
+
+    /// The largest finite value.
+    pub const MAX: Self = Self::new(f64::MAX);
+
+    /// Construct a unit value from an `f64`.
+    #[must_use]
+    pub const fn new(value: f64) -> Self {
+        Self(value, PhantomData)
+    }
+
+    /// Convert to the unit type `T`, e.g. `value.convert::<Feet>()`.
+    #[must_use]
+    pub fn convert<T>(self) -> T
+    where
+        Self: crate::ConvertTo<T>,
+    {
+        crate::ConvertTo::convert(self)
+    }
+}
+
+impl<U: UnitMarker> Unit for Quantity<U> {
+    const SYMBOL: &'static str = U::SYMBOL;
+    const SI_FACTOR: f64 = U::SI_FACTOR;
+
+    fn new(value: f64) -> Self {
+        Self::new(value)
+    }
+
+    fn value(self) -> f64 {
+        self.0
+    }
+}
+
+impl<U: UnitMarker> Default for Quantity<U> {
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+impl<U: UnitMarker> PartialEq for Quantity<U> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<U: UnitMarker> PartialOrd for Quantity<U> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.0.partial_cmp(&other.0)
+    }
+}
+
+impl<U: UnitMarker> fmt::Debug for Quantity<U> {
+    /// Formats the unit type name and value, e.g. "Metres(1.0)".
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple(U::NAME).field(&self.0).finish()
+    }
+}
+
+impl<U: UnitMarker> fmt::Display for Quantity<U> {
+    /// Formats the value followed by the unit symbol, e.g. "1000 ft".
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        crate::__private::fmt_unit(self.0, U::SYMBOL, f)
+    }
+}
+
+impl<U: AdditiveUnit> Add for Quantity<U> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self::new(self.0 + rhs.0)
+    }
+}
+
+impl<U: AdditiveUnit> AddAssign for Quantity<U> {
+    fn add_assign(&mut self, rhs: Self) {
+        self.0 += rhs.0;
+    }
+}
+
+impl<U: AdditiveUnit> Sub for Quantity<U> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self::new(self.0 - rhs.0)
+    }
+}
+
+impl<U: AdditiveUnit> SubAssign for Quantity<U> {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.0 -= rhs.0;
+    }
+}
+
+impl<U: AdditiveUnit> Neg for Quantity<U> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.0)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<U: UnitMarker> serde::Serialize for Quantity<U> {
+    /// Serializes the value as a newtype struct named after the unit type,
+    /// in the same way as `#[derive(Serialize)]`.
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_newtype_struct(U::NAME, &self.0)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, U: UnitMarker> serde::Deserialize<'de> for Quantity<U> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        crate::__private::deserialize_newtype(U::NAME, deserializer)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::float_cmp)]
+mod tests {
+    use super::*;
+    use crate::{non_si, si};
+
+    fn si_value<U: UnitMarker>(value: Quantity<U>) -> f64 {
+        value.0 * U::SI_FACTOR
+    }
+
+    #[test]
+    fn test_quantity() {
+        let altitude = non_si::Feet(1000.0);
+        assert_eq!(Quantity::<non_si::FeetUnit>::new(1000.0), altitude);
+        assert_eq!(altitude.si_value(), si_value(altitude));
+        assert_eq!(1.0, si_value(si::Metres(1.0)));
+        assert_eq!("Feet", non_si::FeetUnit::NAME);
+        assert_eq!("ft", non_si::FeetUnit::SYMBOL);
+
+        assert_eq!(si::Metres::ZERO, si::Metres::default());
+        assert!(si::Metres::MIN < si::Metres::ZERO && si::Metres::ZERO < si::Metres::MAX);
+        assert!(si::Metres(f64::NAN).partial_cmp(&si::Metres(1.0)).is_none());
+
+        let mut distance = si::Metres(3.0) + si::Metres(2.0) - si::Metres(1.0);
+        distance += si::Metres(1.0);
+        distance -= si::Metres(2.0);
+        assert_eq!(si::Metres(-3.0), -distance);
+
+        assert_eq!("Feet(1000.0)", format!("{altitude:?}"));
+        assert_eq!("1000 ft", altitude.to_string());
+        assert_eq!("288.15 K", si::Kelvin(288.15).to_string());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_quantity_serde() {
+        let altitude = non_si::Feet(1000.0);
+        let serialized = serde_json::to_string(&altitude).unwrap();
+        assert_eq!("1000.0", serialized);
+        let deserialized: non_si::Feet = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(altitude, deserialized);
+        assert!(serde_json::from_str::<non_si::Feet>("\"1000.0\"").is_err());
+    }
+}
@@ -0,0 +1,477 @@
+// Copyright (c) 2024 Ken Barker
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"),
+// to deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+// THE SOFTWARE.
+
+//! Quantized unit types, to model downlinked data faithfully.
+//!
+//! Aircraft report altitudes, flight levels and headings at a fixed
+//! resolution. The types in this module can only hold values at that
+//! resolution: they are constructed by rounding a `f64` unit value with the
+//! `TryFrom` trait, or from an exact integer value with `new`, and are
+//! guaranteed to be valid thereafter, e.g.:
+//!
+//! ```
+//! use icao_units::non_si::Feet;
+//! use icao_units::quantized::{Altitude25, FlightLevel5};
+//!
+//! let altitude = Altitude25::try_from(Feet(10_012.0)).unwrap();
+//! assert_eq!(10_000, altitude.value());
+//! assert_eq!(None, Altitude25::new(10_010));
+//!
+//! let level = FlightLevel5::try_from(Feet(34_800.0)).unwrap();
+//! assert_eq!(350, level.value());
+//! assert_eq!(Feet(35_000.0), Feet::from(level));
+//! ```
+//!
+//! When the `serde` feature is enabled, the types are serialized as their
+//! integer values and deserialization fails for values that are not valid.
+
+#![allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+
+use crate::exact::OutOfRange;
+use crate::non_si;
+use core::fmt;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The error returned when constructing a quantized unit type from an
+/// integer value that is not a valid quantized value.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct NotQuantized(pub i32);
+
+impl fmt::Display for NotQuantized {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid quantized value: {}", self.0)
+    }
+}
+
+impl core::error::Error for NotQuantized {}
+
+/// Defines a quantized unit type holding an `i32` multiple of `$step`,
+/// where `$factor` is the value of one integer unit in the `f64` unit type.
+macro_rules! quantized_unit {
+    ($(#[$attr:meta])* $t:ident, $f64_t:ty, $step:expr, $factor:expr) => {
+        $(#[$attr])*
+        #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        #[cfg_attr(
+            feature = "serde",
+            derive(Serialize, Deserialize),
+            serde(try_from = "i32", into = "i32")
+        )]
+        pub struct $t(i32);
+
+        impl $t {
+            /// The quantization step.
+            pub const STEP: i32 = $step;
+
+            /// Construct from an integer value, if it is a multiple of `STEP`.
+            #[must_use]
+            pub const fn new(value: i32) -> Option<Self> {
+                if value % Self::STEP == 0 {
+                    Some(Self(value))
+                } else {
+                    None
+                }
+            }
+
+            /// The integer value, a multiple of `STEP`.
+            #[must_use]
+            pub const fn value(self) -> i32 {
+                self.0
+            }
+        }
+
+        impl TryFrom<$f64_t> for $t {
+            type Error = OutOfRange;
+
+            /// Round to the nearest multiple of `STEP`.
+            /// Fails if the value is not finite or is out of range.
+            fn try_from(a: $f64_t) -> Result<Self, Self::Error> {
+                let step = f64::from(Self::STEP);
+                let value = crate::math::round(a.0 / ($factor * step)) * step;
+                if (f64::from(i32::MIN) <= value) && (value <= f64::from(i32::MAX)) {
+                    Ok(Self(value as i32))
+                } else {
+                    Err(OutOfRange(a.0))
+                }
+            }
+        }
+
+        impl From<$t> for $f64_t {
+            fn from(a: $t) -> Self {
+                Self::new(f64::from(a.0) * $factor)
+            }
+        }
+
+        impl TryFrom<i32> for $t {
+            type Error = NotQuantized;
+
+            fn try_from(value: i32) -> Result<Self, Self::Error> {
+                Self::new(value).ok_or(NotQuantized(value))
+            }
+        }
+
+        impl From<$t> for i32 {
+            fn from(a: $t) -> Self {
+                a.0
+            }
+        }
+    };
+}
+
+quantized_unit!(
+    /// An altitude in feet, in steps of 25 ft.
+    Altitude25,
+    non_si::Feet,
+    25,
+    1.0
+);
+
+quantized_unit!(
+    /// A flight level, i.e. hundreds of feet, in steps of 5.
+    FlightLevel5,
+    non_si::Feet,
+    5,
+    non_si::Feet::PER_FLIGHT_LEVEL.0
+);
+
+/// A flight level, i.e. a pressure altitude in hundreds of feet,
+/// e.g. `FlightLevel(350)` for FL350.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct FlightLevel(pub i32);
+
+impl FlightLevel {
+    /// The flight level number, in hundreds of feet.
+    #[must_use]
+    pub const fn value(self) -> i32 {
+        self.0
+    }
+}
+
+impl FlightLevel {
+    /// Iterate over the flight levels from `first` to `last` inclusive,
+    /// upwards or downwards, e.g.
+    /// `FlightLevel::range(FlightLevel(100), FlightLevel(400)).step_by_levels(10)`.
+    pub const fn range(first: Self, last: Self) -> FlightLevelRange {
+        FlightLevelRange {
+            next: Some(first.0),
+            last: last.0,
+            step: 1,
+        }
+    }
+}
+
+/// An iterator over a range of flight levels.
+///
+/// It is created by the [`FlightLevel::range`] function.
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct FlightLevelRange {
+    next: Option<i32>,
+    last: i32,
+    step: i32,
+}
+
+impl FlightLevelRange {
+    /// Step through the range in increments of `levels`, e.g. 10 for
+    /// every 1000 ft.
+    ///
+    /// # Panics
+    ///
+    /// If `levels` is not positive.
+    pub const fn step_by_levels(self, levels: i32) -> Self {
+        assert!(levels > 0, "levels must be positive");
+        Self {
+            step: levels,
+            ..self
+        }
+    }
+}
+
+impl Iterator for FlightLevelRange {
+    type Item = FlightLevel;
+
+    fn next(&mut self) -> Option<FlightLevel> {
+        let current = self.next?;
+        let next = if current <= self.last {
+            current
+                .checked_add(self.step)
+                .filter(|next| *next <= self.last)
+        } else {
+            current
+                .checked_sub(self.step)
+                .filter(|next| *next >= self.last)
+        };
+        self.next = next;
+        Some(FlightLevel(current))
+    }
+}
+
+impl core::iter::FusedIterator for FlightLevelRange {}
+
+impl TryFrom<non_si::Feet> for FlightLevel {
+    type Error = OutOfRange;
+
+    /// Round to the nearest flight level.
+    /// Fails if the value is not finite or is out of range.
+    fn try_from(a: non_si::Feet) -> Result<Self, Self::Error> {
+        let value = crate::math::round(a.0 / non_si::Feet::PER_FLIGHT_LEVEL.0);
+        if (f64::from(i32::MIN) <= value) && (value <= f64::from(i32::MAX)) {
+            Ok(Self(value as i32))
+        } else {
+            Err(OutOfRange(a.0))
+        }
+    }
+}
+
+impl From<FlightLevel> for non_si::Feet {
+    fn from(a: FlightLevel) -> Self {
+        Self::new(f64::from(a.0) * Self::PER_FLIGHT_LEVEL.0)
+    }
+}
+
+impl From<FlightLevel5> for FlightLevel {
+    fn from(a: FlightLevel5) -> Self {
+        Self(a.0)
+    }
+}
+
+impl fmt::Display for FlightLevel {
+    /// Format as "FL" and a three digit number, e.g. "FL050".
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "FL{:03}", self.0)
+    }
+}
+
+/// A heading in whole degrees, in the range 0 to 359.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(try_from = "i32", into = "i32")
+)]
+pub struct WholeDegrees(u16);
+
+impl WholeDegrees {
+    /// Construct from an integer number of degrees, if it is in the range
+    /// 0 to 359.
+    #[must_use]
+    pub const fn new(degrees: u16) -> Option<Self> {
+        if degrees < 360 {
+            Some(Self(degrees))
+        } else {
+            None
+        }
+    }
+
+    /// The number of degrees, in the range 0 to 359.
+    #[must_use]
+    pub const fn value(self) -> u16 {
+        self.0
+    }
+}
+
+impl TryFrom<non_si::Degrees> for WholeDegrees {
+    type Error = OutOfRange;
+
+    /// Round to the nearest whole degree, in the range 0 to 359.
+    /// Fails if the value is not finite.
+    fn try_from(a: non_si::Degrees) -> Result<Self, Self::Error> {
+        if a.0.is_finite() {
+            let degrees = crate::math::round(a.0) % 360.0;
+            let degrees = if degrees < 0.0 {
+                degrees + 360.0
+            } else {
+                degrees
+            };
+            Ok(Self(degrees as u16))
+        } else {
+            Err(OutOfRange(a.0))
+        }
+    }
+}
+
+impl From<WholeDegrees> for non_si::Degrees {
+    fn from(a: WholeDegrees) -> Self {
+        Self::new(f64::from(a.0))
+    }
+}
+
+impl TryFrom<i32> for WholeDegrees {
+    type Error = NotQuantized;
+
+    fn try_from(value: i32) -> Result<Self, Self::Error> {
+        u16::try_from(value)
+            .ok()
+            .and_then(Self::new)
+            .ok_or(NotQuantized(value))
+    }
+}
+
+impl From<WholeDegrees> for i32 {
+    fn from(a: WholeDegrees) -> Self {
+        Self::from(a.0)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::float_cmp)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_altitude_and_flight_level() {
+        assert_eq!(Some(10_025), Altitude25::new(10_025).map(Altitude25::value));
+        assert_eq!(None, Altitude25::new(10_010));
+        assert_eq!(
+            Ok(Altitude25::new(10_025).unwrap()),
+            Altitude25::try_from(non_si::Feet(10_012.5))
+        );
+        assert_eq!(
+            Ok(Altitude25::new(-1_000).unwrap()),
+            Altitude25::try_from(non_si::Feet(-1_010.0))
+        );
+        assert_eq!(
+            Err(OutOfRange(f64::INFINITY)),
+            Altitude25::try_from(non_si::Feet(f64::INFINITY))
+        );
+        assert_eq!(
+            Err(OutOfRange(1e12)),
+            Altitude25::try_from(non_si::Feet(1e12))
+        );
+        assert!(Altitude25::try_from(non_si::Feet(f64::NAN)).is_err());
+
+        let level = FlightLevel5::try_from(non_si::Feet(34_760.0)).unwrap();
+        assert_eq!(350, level.value());
+        assert_eq!(non_si::Feet(35_000.0), non_si::Feet::from(level));
+        assert_eq!(None, FlightLevel5::new(352));
+        assert_eq!(Err(NotQuantized(352)), FlightLevel5::try_from(352));
+        assert_eq!(
+            "invalid quantized value: 352",
+            NotQuantized(352).to_string()
+        );
+
+        #[cfg(feature = "serde")]
+        {
+            let serialized = serde_json::to_string(&level).unwrap();
+            assert_eq!("350", serialized);
+            let deserialized: FlightLevel5 = serde_json::from_str(&serialized).unwrap();
+            assert_eq!(level, deserialized);
+            assert!(serde_json::from_str::<FlightLevel5>("352").is_err());
+            assert!(serde_json::from_str::<Altitude25>("10010").is_err());
+        }
+    }
+
+    #[test]
+    fn test_flight_level() {
+        let level = FlightLevel(195);
+        assert_eq!(195, level.value());
+        assert_eq!(non_si::Feet(19_500.0), non_si::Feet::from(level));
+        assert_eq!(Ok(level), FlightLevel::try_from(non_si::Feet(19_460.0)));
+        assert!(FlightLevel::try_from(non_si::Feet(f64::NAN)).is_err());
+        assert_eq!(
+            FlightLevel(350),
+            FlightLevel::from(FlightLevel5::new(350).unwrap())
+        );
+        assert!(FlightLevel(100) < level);
+        assert_eq!("FL195", level.to_string());
+        assert_eq!("FL050", FlightLevel(50).to_string());
+
+        let levels: Vec<FlightLevel> = FlightLevel::range(FlightLevel(100), FlightLevel(400))
+            .step_by_levels(100)
+            .collect();
+        assert_eq!(
+            vec![
+                FlightLevel(100),
+                FlightLevel(200),
+                FlightLevel(300),
+                FlightLevel(400)
+            ],
+            levels
+        );
+        let levels: Vec<FlightLevel> = FlightLevel::range(FlightLevel(350), FlightLevel(300))
+            .step_by_levels(20)
+            .collect();
+        assert_eq!(
+            vec![FlightLevel(350), FlightLevel(330), FlightLevel(310)],
+            levels
+        );
+        assert_eq!(
+            3,
+            FlightLevel::range(FlightLevel(10), FlightLevel(12)).count()
+        );
+        assert_eq!(
+            vec![FlightLevel(i32::MAX)],
+            FlightLevel::range(FlightLevel(i32::MAX), FlightLevel(i32::MAX)).collect::<Vec<_>>()
+        );
+
+        #[cfg(feature = "serde")]
+        {
+            let serialized = serde_json::to_string(&level).unwrap();
+            assert_eq!("195", serialized);
+            let deserialized: FlightLevel = serde_json::from_str(&serialized).unwrap();
+            assert_eq!(level, deserialized);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "levels must be positive")]
+    fn test_step_by_zero_levels() {
+        let _ = FlightLevel::range(FlightLevel(100), FlightLevel(400)).step_by_levels(0);
+    }
+
+    #[test]
+    fn test_whole_degrees() {
+        assert_eq!(Some(359), WholeDegrees::new(359).map(WholeDegrees::value));
+        assert_eq!(None, WholeDegrees::new(360));
+        assert_eq!(
+            Ok(WholeDegrees::new(0).unwrap()),
+            WholeDegrees::try_from(non_si::Degrees(359.6))
+        );
+        assert_eq!(
+            Ok(WholeDegrees::new(350).unwrap()),
+            WholeDegrees::try_from(non_si::Degrees(-10.2))
+        );
+        assert_eq!(
+            Ok(WholeDegrees::new(0).unwrap()),
+            WholeDegrees::try_from(non_si::Degrees(-0.4))
+        );
+        assert_eq!(
+            Ok(WholeDegrees::new(90).unwrap()),
+            WholeDegrees::try_from(non_si::Degrees(810.0))
+        );
+        assert!(WholeDegrees::try_from(non_si::Degrees(f64::NAN)).is_err());
+        assert_eq!(
+            non_si::Degrees(90.0),
+            non_si::Degrees::from(WholeDegrees::new(90).unwrap())
+        );
+        assert_eq!(Err(NotQuantized(-1)), WholeDegrees::try_from(-1));
+
+        #[cfg(feature = "serde")]
+        {
+            let heading = WholeDegrees::new(270).unwrap();
+            let serialized = serde_json::to_string(&heading).unwrap();
+            assert_eq!("270", serialized);
+            let deserialized: WholeDegrees = serde_json::from_str(&serialized).unwrap();
+            assert_eq!(heading, deserialized);
+            assert!(serde_json::from_str::<WholeDegrees>("360").is_err());
+        }
+    }
+}
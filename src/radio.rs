@@ -0,0 +1,181 @@
+// Copyright (c) 2024 Ken Barker
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"),
+// to deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+// THE SOFTWARE.
+
+//! Radio navigation geometry.
+//!
+//! Distance measuring equipment (DME) measures the slant range from the
+//! station to the aircraft, which is longer than the distance along the
+//! ground, especially close to the station at altitude, e.g.:
+//!
+//! ```
+//! # #[cfg(any(feature = "std", feature = "libm"))]
+//! # {
+//! use icao_units::non_si::{Feet, NauticalMiles};
+//! use icao_units::radio::{ground_distance, slant_range};
+//!
+//! // directly above the station at 6076 ft, the DME reads about 1 NM
+//! let slant = slant_range(NauticalMiles(0.0), Feet(6_076.0), Feet(0.0));
+//! assert_eq!(1.0, slant.0.round());
+//!
+//! let ground = ground_distance(NauticalMiles(10.0), Feet(30_000.0), Feet(1_000.0)).unwrap();
+//! assert_eq!(8.79, (ground.0 * 100.0).round() / 100.0);
+//! # }
+//! ```
+//!
+//! The conversions use a flat earth, which is accurate for the distances
+//! at which the slant range correction is significant.
+//!
+//! The curvature of the earth limits the range of VHF radio and radar to
+//! the radio horizon. Atmospheric refraction bends radio waves towards the
+//! earth, which is modelled by an earth with 4/3 of its actual radius, giving
+//! the standard formula of about 1.23√h NM for an antenna at h ft, e.g.:
+//!
+//! ```
+//! # #[cfg(any(feature = "std", feature = "libm"))]
+//! # {
+//! use icao_units::non_si::{Feet, NauticalMiles};
+//! use icao_units::radio::{radio_horizon, radio_line_of_sight};
+//!
+//! assert_eq!(123.0, radio_horizon(Feet(10_000.0)).0.round());
+//! assert_eq!(135.0, radio_line_of_sight(Feet(10_000.0), Feet(100.0)).0.round());
+//! # }
+//! ```
+
+#[cfg(any(feature = "std", feature = "libm"))]
+use crate::math;
+#[cfg(any(feature = "std", feature = "libm"))]
+use crate::non_si::{Feet, NauticalMiles};
+use crate::si::Metres;
+
+/// The mean radius of the earth, as defined by the IUGG.
+pub const EARTH_MEAN_RADIUS: Metres = Metres(6_371_008.8);
+
+/// The standard effective earth radius factor, 4/3, which models the
+/// refraction of radio waves in the standard atmosphere.
+pub const STANDARD_EFFECTIVE_RADIUS_FACTOR: f64 = 4.0 / 3.0;
+
+/// The height of an aircraft at `aircraft_elevation` above a station at
+/// `station_elevation`, in metres.
+#[cfg(any(feature = "std", feature = "libm"))]
+fn height_difference(aircraft_elevation: Feet, station_elevation: Feet) -> f64 {
+    Metres::from(aircraft_elevation - station_elevation).0
+}
+
+/// The distance along the ground to a DME station from its `slant_range`,
+/// given the elevations of the aircraft and the station.
+///
+/// Returns `None` if the slant range is less than the height difference.
+#[cfg(any(feature = "std", feature = "libm"))]
+#[must_use]
+pub fn ground_distance(
+    slant_range: NauticalMiles,
+    aircraft_elevation: Feet,
+    station_elevation: Feet,
+) -> Option<NauticalMiles> {
+    let slant = Metres::from(slant_range).0;
+    let height = height_difference(aircraft_elevation, station_elevation);
+    (slant >= math::abs(height)).then(|| {
+        let squared = math::mul_add(slant, slant, -height * height);
+        NauticalMiles::from(Metres(math::sqrt(squared)))
+    })
+}
+
+/// The slant range to a DME station from the `ground_distance`,
+/// given the elevations of the aircraft and the station.
+#[cfg(any(feature = "std", feature = "libm"))]
+#[must_use]
+pub fn slant_range(
+    ground_distance: NauticalMiles,
+    aircraft_elevation: Feet,
+    station_elevation: Feet,
+) -> NauticalMiles {
+    let ground = Metres::from(ground_distance).0;
+    let height = height_difference(aircraft_elevation, station_elevation);
+    NauticalMiles::from(Metres(math::hypot(ground, height)))
+}
+
+/// The distance to the horizon from an antenna at `height` above the
+/// ground, for an earth of `factor` times its mean radius.
+///
+/// A `factor` of 1.0 gives the geometric (optical) horizon and
+/// [`STANDARD_EFFECTIVE_RADIUS_FACTOR`] gives the radio horizon.
+#[cfg(any(feature = "std", feature = "libm"))]
+#[must_use]
+pub fn horizon_distance(height: Feet, factor: f64) -> NauticalMiles {
+    let radius = factor * EARTH_MEAN_RADIUS.0;
+    NauticalMiles::from(Metres(math::sqrt(2.0 * radius * Metres::from(height).0)))
+}
+
+/// The radio horizon of an antenna at `height` above the ground,
+/// about 1.23√h NM for a height of h ft.
+#[cfg(any(feature = "std", feature = "libm"))]
+#[must_use]
+pub fn radio_horizon(height: Feet) -> NauticalMiles {
+    horizon_distance(height, STANDARD_EFFECTIVE_RADIUS_FACTOR)
+}
+
+/// The maximum radio line of sight distance between two antennas at
+/// heights `height_1` and `height_2` above the ground: the sum of their
+/// radio horizons.
+#[cfg(any(feature = "std", feature = "libm"))]
+#[must_use]
+pub fn radio_line_of_sight(height_1: Feet, height_2: Feet) -> NauticalMiles {
+    radio_horizon(height_1) + radio_horizon(height_2)
+}
+
+#[cfg(test)]
+#[allow(clippy::float_cmp)]
+mod tests {
+    #[cfg(any(feature = "std", feature = "libm"))]
+    use super::*;
+
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[test]
+    fn test_slant_range() {
+        // a 3-4-5 triangle in nautical miles
+        let height = Feet::from(Metres::from(NauticalMiles(3.0)));
+        let slant = slant_range(NauticalMiles(4.0), height, Feet(0.0));
+        assert!((slant.0 - 5.0).abs() < 1e-12);
+        let ground = ground_distance(slant, height, Feet(0.0)).unwrap();
+        assert!((ground.0 - 4.0).abs() < 1e-12);
+
+        // the station may be above the aircraft
+        let ground = ground_distance(slant, Feet(0.0), height).unwrap();
+        assert!((ground.0 - 4.0).abs() < 1e-12);
+
+        assert_eq!(None, ground_distance(NauticalMiles(2.0), height, Feet(0.0)));
+    }
+
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[test]
+    fn test_radio_horizon() {
+        assert_eq!(NauticalMiles(0.0), radio_horizon(Feet(0.0)));
+        let horizon = radio_horizon(Feet(100.0));
+        assert!((horizon.0 - 12.29).abs() < 0.01);
+        let optical = horizon_distance(Feet(100.0), 1.0);
+        assert!((optical.0 - 10.64).abs() < 0.01);
+        assert!((horizon.0 / optical.0 - math::sqrt(4.0 / 3.0)).abs() < 1e-12);
+
+        assert_eq!(
+            radio_horizon(Feet(35_000.0)) + horizon,
+            radio_line_of_sight(Feet(35_000.0), Feet(100.0))
+        );
+    }
+}
@@ -0,0 +1,367 @@
+// Copyright (c) 2024 Ken Barker
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"),
+// to deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+// THE SOFTWARE.
+
+//! Dimensionless ratios.
+//!
+//! Many aviation quantities are ratios expressed as a percentage or per
+//! mille, e.g. climb gradients, engine N1 and fuel reserves. [`Percent`]
+//! and [`PerMille`] convert to and from plain fractions and scale unit
+//! values, e.g.:
+//!
+//! ```
+//! use icao_units::ratio::{PerMille, Percent};
+//! use icao_units::si::Kilograms;
+//!
+//! // a 5% contingency fuel on a 4000 kg trip fuel
+//! let contingency = Percent(5.0).of(Kilograms(4_000.0));
+//! assert_eq!(Kilograms(200.0), contingency);
+//! assert_eq!(Percent(5.0), Percent::of_whole(contingency, Kilograms(4_000.0)));
+//!
+//! assert_eq!(0.05, Percent(5.0).ratio());
+//! assert_eq!(PerMille(50.0), PerMille::from(Percent(5.0)));
+//! ```
+//!
+//! Trace quantities, e.g. carbon monoxide concentrations and fuel water
+//! contamination, are measured in [`PartsPerMillion`].
+//!
+//! Signal levels and link budgets are measured in [`Decibels`], which are
+//! logarithmic, so adding them multiplies the ratios, e.g.:
+//!
+//! ```
+//! # #[cfg(any(feature = "std", feature = "libm"))]
+//! # {
+//! use icao_units::ratio::Decibels;
+//!
+//! let gain = Decibels(20.0) + Decibels(10.0);
+//! assert_eq!(1_000.0, gain.to_power_ratio());
+//! assert_eq!(Decibels(20.0), Decibels::from_field_ratio(10.0));
+//! # }
+//! ```
+
+use crate::gradient::Gradient;
+use crate::humidity::RelativeHumidity;
+#[cfg(any(feature = "std", feature = "libm"))]
+use crate::math;
+use crate::Unit;
+use core::fmt;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// A ratio as a percentage, e.g. 25.0 for 25%.
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[repr(transparent)]
+pub struct Percent(pub f64);
+
+impl_additive!(Percent);
+
+impl Percent {
+    /// Construct a percentage from a plain fraction, e.g. 0.25 for 25%.
+    #[must_use]
+    pub fn from_ratio(ratio: f64) -> Self {
+        Self(ratio * 100.0)
+    }
+
+    /// The percentage as a plain fraction.
+    #[must_use]
+    pub fn ratio(self) -> f64 {
+        self.0 / 100.0
+    }
+
+    /// The percentage of a `whole` that a `part` is.
+    #[must_use]
+    pub fn of_whole<U: Unit>(part: U, whole: U) -> Self {
+        Self::from_ratio(part.value() / whole.value())
+    }
+
+    /// This percentage of a unit `value`.
+    #[must_use]
+    pub fn of<U: Unit>(self, value: U) -> U {
+        U::new(self.ratio() * value.value())
+    }
+}
+
+impl From<PerMille> for Percent {
+    fn from(a: PerMille) -> Self {
+        Self(a.0 / 10.0)
+    }
+}
+
+impl From<Gradient> for Percent {
+    fn from(a: Gradient) -> Self {
+        Self::from_ratio(a.0)
+    }
+}
+
+impl From<Percent> for Gradient {
+    fn from(a: Percent) -> Self {
+        Self(a.ratio())
+    }
+}
+
+impl From<RelativeHumidity> for Percent {
+    fn from(a: RelativeHumidity) -> Self {
+        Self(a.0)
+    }
+}
+
+impl fmt::Display for Percent {
+    /// Formats the percentage followed by a percent sign, e.g. "25 %".
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        crate::__private::fmt_unit(self.0, "%", f)
+    }
+}
+
+/// A ratio in parts per thousand, e.g. 25.0 for 25‰.
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[repr(transparent)]
+pub struct PerMille(pub f64);
+
+impl_additive!(PerMille);
+
+impl PerMille {
+    /// Construct a per mille value from a plain fraction, e.g. 0.025 for 25‰.
+    #[must_use]
+    pub fn from_ratio(ratio: f64) -> Self {
+        Self(ratio * 1_000.0)
+    }
+
+    /// The per mille value as a plain fraction.
+    #[must_use]
+    pub fn ratio(self) -> f64 {
+        self.0 / 1_000.0
+    }
+
+    /// The parts per thousand of a `whole` that a `part` is.
+    #[must_use]
+    pub fn of_whole<U: Unit>(part: U, whole: U) -> Self {
+        Self::from_ratio(part.value() / whole.value())
+    }
+
+    /// This per mille value of a unit `value`.
+    #[must_use]
+    pub fn of<U: Unit>(self, value: U) -> U {
+        U::new(self.ratio() * value.value())
+    }
+}
+
+impl From<Percent> for PerMille {
+    fn from(a: Percent) -> Self {
+        Self(a.0 * 10.0)
+    }
+}
+
+impl fmt::Display for PerMille {
+    /// Formats the value followed by a per mille sign, e.g. "25 ‰".
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        crate::__private::fmt_unit(self.0, "‰", f)
+    }
+}
+
+/// A ratio in parts per million, e.g. 400.0 for 400 ppm.
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[repr(transparent)]
+pub struct PartsPerMillion(pub f64);
+
+impl_additive!(PartsPerMillion);
+
+impl PartsPerMillion {
+    /// Construct a parts per million value from a plain fraction, e.g.
+    /// 0.0004 for 400 ppm.
+    #[must_use]
+    pub fn from_ratio(ratio: f64) -> Self {
+        Self(ratio * 1e6)
+    }
+
+    /// The parts per million value as a plain fraction.
+    #[must_use]
+    pub fn ratio(self) -> f64 {
+        self.0 / 1e6
+    }
+
+    /// The parts per million of a `whole` that a `part` is.
+    #[must_use]
+    pub fn of_whole<U: Unit>(part: U, whole: U) -> Self {
+        Self::from_ratio(part.value() / whole.value())
+    }
+
+    /// This parts per million value of a unit `value`.
+    #[must_use]
+    pub fn of<U: Unit>(self, value: U) -> U {
+        U::new(self.ratio() * value.value())
+    }
+}
+
+impl From<Percent> for PartsPerMillion {
+    fn from(a: Percent) -> Self {
+        Self(a.0 * 10_000.0)
+    }
+}
+
+impl From<PartsPerMillion> for Percent {
+    fn from(a: PartsPerMillion) -> Self {
+        Self(a.0 / 10_000.0)
+    }
+}
+
+impl fmt::Display for PartsPerMillion {
+    /// Formats the value followed by "ppm", e.g. "400 ppm".
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        crate::__private::fmt_unit(self.0, "ppm", f)
+    }
+}
+
+/// A logarithmic ratio in decibels, e.g. of a signal power to a reference
+/// power.
+///
+/// Adding decibels multiplies the underlying ratios, e.g. the gains and
+/// losses of a link budget. Powers must be summed by converting them to
+/// ratios with [`Decibels::to_power_ratio`] first.
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[repr(transparent)]
+pub struct Decibels(pub f64);
+
+impl_additive!(Decibels);
+
+impl Decibels {
+    /// Construct decibels from a power ratio: 10 log₁₀(ratio).
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[must_use]
+    pub fn from_power_ratio(ratio: f64) -> Self {
+        Self(10.0 * math::log10(ratio))
+    }
+
+    /// The power ratio of the decibels: 10^(dB / 10).
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[must_use]
+    pub fn to_power_ratio(self) -> f64 {
+        math::powf(10.0, self.0 / 10.0)
+    }
+
+    /// Construct decibels from a field ratio, e.g. of voltages or field
+    /// strengths: 20 log₁₀(ratio).
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[must_use]
+    pub fn from_field_ratio(ratio: f64) -> Self {
+        Self(20.0 * math::log10(ratio))
+    }
+
+    /// The field ratio of the decibels: 10^(dB / 20).
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[must_use]
+    pub fn to_field_ratio(self) -> f64 {
+        math::powf(10.0, self.0 / 20.0)
+    }
+}
+
+impl fmt::Display for Decibels {
+    /// Formats the value followed by "dB", e.g. "3 dB".
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        crate::__private::fmt_unit(self.0, "dB", f)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::float_cmp)]
+mod tests {
+    use super::*;
+    use crate::non_si::{Feet, NauticalMiles};
+    use crate::si::Kilograms;
+
+    #[test]
+    fn test_percent() {
+        assert_eq!(Percent(25.0), Percent::from_ratio(0.25));
+        assert_eq!(0.25, Percent(25.0).ratio());
+        assert_eq!(Percent(10.0), Percent(7.5) + Percent(2.5));
+        assert_eq!(Feet(1_000.0), Percent(10.0).of(Feet(10_000.0)));
+        assert_eq!(
+            Percent(50.0),
+            Percent::of_whole(NauticalMiles(5.0), NauticalMiles(10.0))
+        );
+
+        assert_eq!(Percent(5.0), Percent::from(Gradient(0.05)));
+        assert_eq!(Gradient(0.05), Gradient::from(Percent(5.0)));
+        assert_eq!(Percent(72.0), Percent::from(RelativeHumidity(72.0)));
+        assert_eq!("25 %", Percent(25.0).to_string());
+    }
+
+    #[test]
+    fn test_per_mille() {
+        assert_eq!(PerMille(25.0), PerMille::from_ratio(0.025));
+        assert_eq!(0.025, PerMille(25.0).ratio());
+        assert_eq!(PerMille(-5.0), -PerMille(5.0));
+        assert_eq!(Feet(25.0), PerMille(2.5).of(Feet(10_000.0)));
+        assert_eq!(
+            PerMille(500.0),
+            PerMille::of_whole(NauticalMiles(5.0), NauticalMiles(10.0))
+        );
+
+        assert_eq!(PerMille(25.0), PerMille::from(Percent(2.5)));
+        assert_eq!(Percent(2.5), Percent::from(PerMille(25.0)));
+        assert_eq!("25 ‰", PerMille(25.0).to_string());
+    }
+
+    #[test]
+    fn test_parts_per_million() {
+        assert_eq!(PartsPerMillion(400.0), PartsPerMillion::from_ratio(0.000_4));
+        assert_eq!(0.000_4, PartsPerMillion(400.0).ratio());
+        assert_eq!(
+            PartsPerMillion(50.0),
+            PartsPerMillion(35.0) + PartsPerMillion(15.0)
+        );
+        assert_eq!(
+            PartsPerMillion(250.0),
+            PartsPerMillion::of_whole(Kilograms(1.0), Kilograms(4_000.0))
+        );
+        assert_eq!(
+            Kilograms(2.0),
+            PartsPerMillion(500.0).of(Kilograms(4_000.0))
+        );
+
+        assert_eq!(
+            PartsPerMillion(5_000.0),
+            PartsPerMillion::from(Percent(0.5))
+        );
+        assert_eq!(Percent(0.5), Percent::from(PartsPerMillion(5_000.0)));
+        assert_eq!("400 ppm", PartsPerMillion(400.0).to_string());
+    }
+
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[test]
+    fn test_decibels() {
+        assert_eq!(Decibels(30.0), Decibels::from_power_ratio(1_000.0));
+        assert_eq!(Decibels(-20.0), Decibels::from_power_ratio(0.01));
+        assert_eq!(100.0, Decibels(20.0).to_power_ratio());
+        assert!((Decibels::from_power_ratio(2.0).0 - 3.010_299_956_639_812).abs() < 1e-12);
+
+        assert_eq!(Decibels(40.0), Decibels::from_field_ratio(100.0));
+        assert_eq!(10.0, Decibels(20.0).to_field_ratio());
+        assert!((Decibels(6.0).to_field_ratio() - 1.995_262_314_968_879_6).abs() < 1e-12);
+
+        // a 10 dB amplifier after a 3 dB cable loss
+        let gain = Decibels(10.0) - Decibels(3.0);
+        assert_eq!(Decibels(7.0), gain);
+        assert!((gain.to_power_ratio() - 5.011_872_336_272_722).abs() < 1e-12);
+        assert_eq!("3 dB", Decibels(3.0).to_string());
+    }
+}
@@ -0,0 +1,201 @@
+// Copyright (c) 2024 Ken Barker
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"),
+// to deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+// THE SOFTWARE.
+
+//! Exact rational conversion factors for integer arithmetic.
+//!
+//! The conversion factors defined in `ICAO Annex 5` Table 3-3 between the
+//! distance and speed units are rational numbers, e.g. a foot is exactly
+//! 3048/10000 metres. The [`Ratio`] constants in this module allow fixed-point
+//! and exact arithmetic code to convert integer values without floating point
+//! rounding, e.g.:
+//!
+//! ```
+//! use icao_units::rational::METRES_PER_FOOT;
+//!
+//! // 1250 ft is exactly 381 m
+//! assert_eq!(Some(381), METRES_PER_FOOT.convert_exact(1250));
+//! // 1000 ft is 304.8 m, which rounds to 305 m
+//! assert_eq!(None, METRES_PER_FOOT.convert_exact(1000));
+//! assert_eq!(Some(305), METRES_PER_FOOT.convert_rounded(1000));
+//! ```
+
+/// An exact rational number in lowest terms with a positive denominator.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Ratio {
+    numerator: i64,
+    denominator: i64,
+}
+
+/// The greatest common divisor of `a` and `b`.
+const fn gcd(mut a: i64, mut b: i64) -> i64 {
+    while b != 0 {
+        let t = b;
+        b = a % b;
+        a = t;
+    }
+    a.abs()
+}
+
+impl Ratio {
+    /// Construct a ratio in lowest terms.
+    ///
+    /// # Panics
+    ///
+    /// If `denominator` is zero.
+    #[must_use]
+    pub const fn new(numerator: i64, denominator: i64) -> Self {
+        assert!(denominator != 0, "denominator must not be zero");
+        let divisor = gcd(numerator, denominator) * denominator.signum();
+        Self {
+            numerator: numerator / divisor,
+            denominator: denominator / divisor,
+        }
+    }
+
+    /// The numerator.
+    #[must_use]
+    pub const fn numerator(self) -> i64 {
+        self.numerator
+    }
+
+    /// The denominator.
+    #[must_use]
+    pub const fn denominator(self) -> i64 {
+        self.denominator
+    }
+
+    /// The reciprocal of the ratio, for the inverse conversion.
+    #[must_use]
+    pub const fn recip(self) -> Self {
+        Self::new(self.denominator, self.numerator)
+    }
+
+    /// The ratio as the nearest `f64`.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub const fn to_f64(self) -> f64 {
+        self.numerator as f64 / self.denominator as f64
+    }
+
+    /// Multiply `value` by the ratio, if the result is an exact integer
+    /// that fits in an `i64`.
+    #[must_use]
+    pub const fn convert_exact(self, value: i64) -> Option<i64> {
+        let product = value as i128 * self.numerator as i128;
+        let denominator = self.denominator as i128;
+        if product % denominator == 0 {
+            narrow(product / denominator)
+        } else {
+            None
+        }
+    }
+
+    /// Multiply `value` by the ratio and round to the nearest integer, with
+    /// halves rounded away from zero, if the result fits in an `i64`.
+    #[must_use]
+    pub const fn convert_rounded(self, value: i64) -> Option<i64> {
+        let product = value as i128 * self.numerator as i128;
+        let denominator = self.denominator as i128;
+        let half = if product < 0 {
+            -denominator / 2
+        } else {
+            denominator / 2
+        };
+        narrow((product + half) / denominator)
+    }
+}
+
+/// Convert an `i128` to an `i64`, if it fits.
+#[allow(clippy::cast_possible_truncation)]
+const fn narrow(value: i128) -> Option<i64> {
+    if value >= i64::MIN as i128 && value <= i64::MAX as i128 {
+        Some(value as i64)
+    } else {
+        None
+    }
+}
+
+/// The length of a nautical mile (NM) in metres (m): 1852.
+pub const METRES_PER_NAUTICAL_MILE: Ratio = Ratio::new(1_852, 1);
+
+/// The length of a foot (ft) in metres (m): 0.3048.
+pub const METRES_PER_FOOT: Ratio = Ratio::new(3_048, 10_000);
+
+/// The length of a nautical mile (NM) in feet (ft): 1852 / 0.3048.
+pub const FEET_PER_NAUTICAL_MILE: Ratio = Ratio::new(18_520_000, 3_048);
+
+/// The speed of a knot (kt) in metres per second (m/s): 1852 / 3600.
+pub const METRES_PER_SECOND_PER_KNOT: Ratio = Ratio::new(1_852, 3_600);
+
+/// The number of seconds in an hour: 3600.
+pub const SECONDS_PER_HOUR: Ratio = Ratio::new(3_600, 1);
+
+#[cfg(test)]
+#[allow(clippy::float_cmp)]
+mod tests {
+    use super::*;
+    use crate::non_si;
+
+    #[test]
+    fn test_ratio() {
+        assert_eq!(381, METRES_PER_FOOT.numerator());
+        assert_eq!(1_250, METRES_PER_FOOT.denominator());
+        assert_eq!(Ratio::new(1_250, 381), METRES_PER_FOOT.recip());
+        assert_eq!(Ratio::new(1, -2), Ratio::new(-2, 4));
+        assert_eq!(-1, Ratio::new(1, -2).numerator());
+        assert_eq!(2, Ratio::new(1, -2).denominator());
+
+        assert_eq!(non_si::METRES_PER_FOOT, METRES_PER_FOOT.to_f64());
+        assert_eq!(
+            non_si::METRES_PER_NAUTICAL_MILE,
+            METRES_PER_NAUTICAL_MILE.to_f64()
+        );
+        #[cfg(not(feature = "strict-annex5"))]
+        assert_eq!(
+            non_si::METRES_PER_SECOND_TO_KNOTS,
+            METRES_PER_SECOND_PER_KNOT.to_f64()
+        );
+        assert_eq!(non_si::SECONDS_PER_HOUR, SECONDS_PER_HOUR.to_f64());
+    }
+
+    #[test]
+    fn test_convert() {
+        assert_eq!(Some(381), METRES_PER_FOOT.convert_exact(1_250));
+        assert_eq!(None, METRES_PER_FOOT.convert_exact(1_000));
+        assert_eq!(Some(305), METRES_PER_FOOT.convert_rounded(1_000));
+        assert_eq!(Some(-305), METRES_PER_FOOT.convert_rounded(-1_000));
+        assert_eq!(Some(1_250), METRES_PER_FOOT.recip().convert_exact(381));
+        assert_eq!(Some(463), METRES_PER_SECOND_PER_KNOT.convert_exact(900));
+        assert_eq!(
+            Some(18_520_000),
+            FEET_PER_NAUTICAL_MILE.convert_exact(3_048)
+        );
+        assert_eq!(Some(1), Ratio::new(1, 2).convert_rounded(1));
+        assert_eq!(Some(-1), Ratio::new(1, 2).convert_rounded(-1));
+        assert_eq!(None, METRES_PER_NAUTICAL_MILE.convert_exact(i64::MAX));
+        assert_eq!(None, METRES_PER_NAUTICAL_MILE.convert_rounded(i64::MIN));
+    }
+
+    #[test]
+    #[should_panic(expected = "denominator must not be zero")]
+    fn test_zero_denominator() {
+        let _ = Ratio::new(1, 0);
+    }
+}
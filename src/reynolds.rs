@@ -0,0 +1,113 @@
+// Copyright (c) 2024 Ken Barker
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"),
+// to deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+// THE SOFTWARE.
+
+//! Reynolds numbers, the ratio of inertial to viscous forces in a flow.
+//!
+//! A [`ReynoldsNumber`] is calculated from a speed, a characteristic length,
+//! e.g. the mean aerodynamic chord of a wing, and the kinematic viscosity of
+//! the air, which may be taken from the ICAO Standard Atmosphere, e.g.:
+//!
+//! ```
+//! # #[cfg(any(feature = "std", feature = "libm"))]
+//! # {
+//! use icao_units::non_si::Knots;
+//! use icao_units::reynolds::ReynoldsNumber;
+//! use icao_units::si::{Metres, SquareMetresPerSecond};
+//!
+//! let reynolds = ReynoldsNumber::new(Knots(250.0), Metres(3.0), SquareMetresPerSecond(1.5e-5));
+//! assert_eq!(25.7, (reynolds.0 / 1e5).round() / 10.0);
+//!
+//! let reynolds = ReynoldsNumber::isa(Knots(250.0), Metres(3.0), Metres(0.0));
+//! assert_eq!(26.4, (reynolds.0 / 1e5).round() / 10.0);
+//! # }
+//! ```
+
+#[cfg(any(feature = "std", feature = "libm"))]
+use crate::isa;
+use crate::si::{Metres, MetresPerSecond, SquareMetresPerSecond};
+use crate::ConvertTo;
+use core::fmt;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// A dimensionless Reynolds number.
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[repr(transparent)]
+pub struct ReynoldsNumber(pub f64);
+
+impl ReynoldsNumber {
+    /// The Reynolds number of a flow at `speed` over a characteristic
+    /// `length` in a fluid of kinematic `viscosity`: V L / ν.
+    #[must_use]
+    pub fn new<S, L>(speed: S, length: L, viscosity: SquareMetresPerSecond) -> Self
+    where
+        S: ConvertTo<MetresPerSecond>,
+        L: ConvertTo<Metres>,
+    {
+        Self(speed.convert().0 * length.convert().0 / viscosity.0)
+    }
+
+    /// The Reynolds number of a flow at `speed` over a characteristic
+    /// `length` in the ISA at a geopotential `altitude`.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[must_use]
+    pub fn isa<S, L>(speed: S, length: L, altitude: Metres) -> Self
+    where
+        S: ConvertTo<MetresPerSecond>,
+        L: ConvertTo<Metres>,
+    {
+        let viscosity = isa::dynamic_viscosity(isa::temperature(altitude)) / isa::density(altitude);
+        Self::new(speed, length, viscosity)
+    }
+}
+
+impl fmt::Display for ReynoldsNumber {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Re {:e}", self.0)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::float_cmp)]
+mod tests {
+    #[cfg(any(feature = "std", feature = "libm"))]
+    use super::*;
+    #[cfg(any(feature = "std", feature = "libm"))]
+    use crate::non_si::Feet;
+
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[test]
+    fn test_reynolds_number() {
+        let reynolds = ReynoldsNumber::new(
+            MetresPerSecond(100.0),
+            Metres(2.0),
+            SquareMetresPerSecond(2e-5),
+        );
+        assert_eq!(ReynoldsNumber(1e7), reynolds);
+        assert_eq!("Re 1e7", reynolds.to_string());
+
+        let reynolds = ReynoldsNumber::isa(MetresPerSecond(100.0), Metres(1.0), Metres(0.0));
+        assert_eq!(6_845_946.0, reynolds.0.round());
+        // the kinematic viscosity increases with altitude
+        let high = ReynoldsNumber::isa(MetresPerSecond(100.0), Feet(3.28), Metres(11_000.0));
+        assert!(high < reynolds);
+    }
+}
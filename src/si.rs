@@ -18,43 +18,572 @@
 // OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
 // THE SOFTWARE.
 
-//! Si units used in air navigation.  
+//! Si units used in air navigation.
 //! See ICAO Annex 5 Chapter 3.
 
+use core::ops::{Add, Div, Mul, Sub};
+use core::time::{Duration, TryFromFloatSecsError};
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-/// A `Metres` `newtype` for representing distance.
-#[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Serialize, Deserialize)]
-pub struct Metres(pub f64);
+additive_quantity!(
+    /// A `Metres` unit type for representing distance.
+    pub Metres, MetresUnit, "m", 1.0
+);
 
-/// A `MetresPerSecond` `newtype` for representing speed.
-#[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Serialize, Deserialize)]
-pub struct MetresPerSecond(pub f64);
+additive_quantity!(
+    /// A `MetresPerSecond` unit type for representing speed.
+    pub MetresPerSecond, MetresPerSecondUnit, "m/s", 1.0
+);
 
-/// A `MetresPerSecondSquared` `newtype` for representing acceleration.
-#[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Serialize, Deserialize)]
-pub struct MetresPerSecondSquared(pub f64);
+additive_quantity!(
+    /// A `MetresPerSecondSquared` unit type for representing acceleration.
+    pub MetresPerSecondSquared, MetresPerSecondSquaredUnit, "m/s²", 1.0
+);
 
-/// A Kelvin `newtype` for representing temperature.
-#[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Serialize, Deserialize)]
-pub struct Kelvin(pub f64);
+impl MetresPerSecondSquared {
+    /// The standard acceleration of gravity, g₀: 9.806 65 m/s².
+    pub const STANDARD_GRAVITY: Self = Self::new(9.806_65);
+}
+
+quantity!(
+    /// A Kelvin unit type for representing temperature.
+    pub Kelvin, KelvinUnit, "K", 1.0
+);
+
+impl Kelvin {
+    /// The temperature of the ice point, 0 °C: 273.15 K.
+    pub const ICE_POINT: Self = Self::new(273.15);
+}
+
+/// A Celsius `newtype` for representing temperature, e.g. outside air
+/// temperature (OAT) and dew point in meteorological reports.
+///
+/// The Celsius scale is offset from the Kelvin scale, so temperatures are
+/// not additive and `Celsius` does not implement `Unit`, whose conversions
+/// only scale values. Convert temperatures with `From` instead.
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "diesel",
+    derive(diesel::expression::AsExpression, diesel::deserialize::FromSqlRow),
+    diesel(sql_type = diesel::sql_types::Double)
+)]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+#[repr(transparent)]
+pub struct Celsius(pub f64);
+
+impl Celsius {
+    /// The temperature of the ice point: 0 °C.
+    pub const ICE_POINT: Self = Self(0.0);
+}
+
+impl From<Celsius> for Kelvin {
+    fn from(a: Celsius) -> Self {
+        Self::new(a.0 + Self::ICE_POINT.0)
+    }
+}
+
+impl From<Kelvin> for Celsius {
+    fn from(a: Kelvin) -> Self {
+        Self(a.0 - Kelvin::ICE_POINT.0)
+    }
+}
+
+impl core::fmt::Display for Celsius {
+    /// Formats the value followed by the unit symbol, e.g. "15°C".
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        crate::__private::fmt_unit(self.0, "°C", f)
+    }
+}
+
+additive_quantity!(
+    /// A `KelvinDelta` unit type for representing a temperature difference,
+    /// e.g. the deviation of a temperature from the ISA temperature.
+    ///
+    /// Temperature differences are additive and the same in kelvin and
+    /// degrees Celsius, e.g. ISA+10 is `KelvinDelta(10.0)`.
+    pub KelvinDelta, KelvinDeltaUnit, "K", 1.0
+);
+
+impl Add<KelvinDelta> for Kelvin {
+    type Output = Self;
+
+    fn add(self, other: KelvinDelta) -> Self {
+        Self::new(self.0 + other.0)
+    }
+}
+
+impl Sub<KelvinDelta> for Kelvin {
+    type Output = Self;
+
+    fn sub(self, other: KelvinDelta) -> Self {
+        Self::new(self.0 - other.0)
+    }
+}
+
+impl Sub for Kelvin {
+    type Output = KelvinDelta;
+
+    fn sub(self, other: Self) -> KelvinDelta {
+        KelvinDelta(self.0 - other.0)
+    }
+}
+
+impl Add<KelvinDelta> for Celsius {
+    type Output = Self;
+
+    fn add(self, other: KelvinDelta) -> Self {
+        Self(self.0 + other.0)
+    }
+}
+
+impl Sub<KelvinDelta> for Celsius {
+    type Output = Self;
+
+    fn sub(self, other: KelvinDelta) -> Self {
+        Self(self.0 - other.0)
+    }
+}
+
+impl Sub for Celsius {
+    type Output = KelvinDelta;
+
+    fn sub(self, other: Self) -> KelvinDelta {
+        KelvinDelta(self.0 - other.0)
+    }
+}
+
+additive_quantity!(
+    /// A Pascals unit type for representing pressure.
+    pub Pascals, PascalsUnit, "Pa", 1.0
+);
+
+impl Pascals {
+    /// The standard pressure, the ISA mean sea level pressure: 101 325 Pa.
+    pub const STANDARD_PRESSURE: Self = Self::new(101_325.0);
+}
+
+additive_quantity!(
+    /// A Kilograms unit type for representing mass.
+    pub Kilograms, KilogramsUnit, "kg", 1.0
+);
+
+additive_quantity!(
+    /// A Kilograms unit type for representing density.
+    pub KilogramsPerCubicMetre, KilogramsPerCubicMetreUnit, "kg/m³", 1.0
+);
+
+additive_quantity!(
+    /// A Radians unit type for representing plane angles.
+    pub Radians, RadiansUnit, "rad", 1.0
+);
+
+additive_quantity!(
+    /// A `RadiansPerSecond` unit type for representing angular velocity.
+    pub RadiansPerSecond, RadiansPerSecondUnit, "rad/s", 1.0
+);
+
+additive_quantity!(
+    /// A Seconds unit type for representing time.
+    pub Seconds, SecondsUnit, "s", 1.0
+);
+
+impl From<Duration> for Seconds {
+    fn from(a: Duration) -> Self {
+        Self::new(a.as_secs_f64())
+    }
+}
+
+impl TryFrom<Seconds> for Duration {
+    type Error = TryFromFloatSecsError;
+
+    /// Convert `Seconds` to a `Duration`.
+    /// Fails if the value is negative, too large or not finite.
+    fn try_from(a: Seconds) -> Result<Self, Self::Error> {
+        Self::try_from_secs_f64(a.0)
+    }
+}
+
+impl Mul<Seconds> for MetresPerSecond {
+    type Output = Metres;
+
+    fn mul(self, rhs: Seconds) -> Metres {
+        Metres(self.0 * rhs.0)
+    }
+}
+
+impl Div<Seconds> for Metres {
+    type Output = MetresPerSecond;
+
+    fn div(self, rhs: Seconds) -> MetresPerSecond {
+        MetresPerSecond(self.0 / rhs.0)
+    }
+}
+
+impl Div<Seconds> for MetresPerSecond {
+    type Output = MetresPerSecondSquared;
+
+    fn div(self, rhs: Seconds) -> MetresPerSecondSquared {
+        MetresPerSecondSquared(self.0 / rhs.0)
+    }
+}
+
+impl Mul<Seconds> for MetresPerSecondSquared {
+    type Output = MetresPerSecond;
+
+    fn mul(self, rhs: Seconds) -> MetresPerSecond {
+        MetresPerSecond(self.0 * rhs.0)
+    }
+}
+
+impl Mul<Duration> for MetresPerSecond {
+    type Output = Metres;
+
+    fn mul(self, rhs: Duration) -> Metres {
+        self * Seconds::from(rhs)
+    }
+}
+
+additive_quantity!(
+    /// A `KilogramsPerSecond` unit type for representing mass flow, e.g. fuel flow.
+    pub KilogramsPerSecond, KilogramsPerSecondUnit, "kg/s", 1.0
+);
+
+additive_quantity!(
+    /// A `MetresPerKilogram` unit type for representing specific range,
+    /// i.e. the distance flown per unit of fuel burnt.
+    pub MetresPerKilogram, MetresPerKilogramUnit, "m/kg", 1.0
+);
+
+additive_quantity!(
+    /// A `CubicMetres` unit type for representing volume.
+    pub CubicMetres, CubicMetresUnit, "m³", 1.0
+);
+
+additive_quantity!(
+    /// A `CubicMetresPerSecond` unit type for representing volume flow.
+    pub CubicMetresPerSecond, CubicMetresPerSecondUnit, "m³/s", 1.0
+);
+
+additive_quantity!(
+    /// A `KilogramMetres` unit type for representing the moment of a mass about
+    /// a datum, e.g. in weight and balance calculations.
+    pub KilogramMetres, KilogramMetresUnit, "kg·m", 1.0
+);
+
+impl Mul<Metres> for Kilograms {
+    type Output = KilogramMetres;
+
+    /// The moment of a mass at an arm from the datum.
+    fn mul(self, rhs: Metres) -> KilogramMetres {
+        KilogramMetres(self.0 * rhs.0)
+    }
+}
+
+impl Mul<Kilograms> for Metres {
+    type Output = KilogramMetres;
+
+    /// The moment of a mass at an arm from the datum.
+    fn mul(self, rhs: Kilograms) -> KilogramMetres {
+        KilogramMetres(self.0 * rhs.0)
+    }
+}
+
+impl Div<Kilograms> for KilogramMetres {
+    type Output = Metres;
+
+    /// The arm of a moment, e.g. the centre of gravity of a total moment
+    /// divided by the total mass.
+    fn div(self, rhs: Kilograms) -> Metres {
+        Metres(self.0 / rhs.0)
+    }
+}
+
+impl Div<Metres> for KilogramMetres {
+    type Output = Kilograms;
+
+    /// The mass that gives a moment at an arm.
+    fn div(self, rhs: Metres) -> Kilograms {
+        Kilograms(self.0 / rhs.0)
+    }
+}
+
+additive_quantity!(
+    /// A `PascalSeconds` unit type for representing the dynamic viscosity of a
+    /// fluid, e.g. air.
+    pub PascalSeconds, PascalSecondsUnit, "Pa·s", 1.0
+);
+
+additive_quantity!(
+    /// A `SquareMetresPerSecond` unit type for representing the kinematic
+    /// viscosity of a fluid, i.e. its dynamic viscosity divided by its density.
+    pub SquareMetresPerSecond, SquareMetresPerSecondUnit, "m²/s", 1.0
+);
+
+impl Div<KilogramsPerCubicMetre> for PascalSeconds {
+    type Output = SquareMetresPerSecond;
+
+    /// The kinematic viscosity of a fluid of a given density.
+    fn div(self, rhs: KilogramsPerCubicMetre) -> SquareMetresPerSecond {
+        SquareMetresPerSecond(self.0 / rhs.0)
+    }
+}
+
+impl Mul<KilogramsPerCubicMetre> for SquareMetresPerSecond {
+    type Output = PascalSeconds;
+
+    /// The dynamic viscosity of a fluid of a given density.
+    fn mul(self, rhs: KilogramsPerCubicMetre) -> PascalSeconds {
+        PascalSeconds(self.0 * rhs.0)
+    }
+}
+
+additive_quantity!(
+    /// A `JoulesPerKilogramKelvin` unit type for representing a specific gas
+    /// constant or a specific heat capacity.
+    pub JoulesPerKilogramKelvin, JoulesPerKilogramKelvinUnit, "J/(kg·K)", 1.0
+);
+
+additive_quantity!(
+    /// A `PascalsPerSecond` unit type for representing the rate of change of
+    /// pressure.
+    pub PascalsPerSecond, PascalsPerSecondUnit, "Pa/s", 1.0
+);
+
+impl Div<Seconds> for Pascals {
+    type Output = PascalsPerSecond;
+
+    /// The mean rate of change of a pressure difference over a time.
+    fn div(self, rhs: Seconds) -> PascalsPerSecond {
+        PascalsPerSecond(self.0 / rhs.0)
+    }
+}
+
+impl Mul<Seconds> for PascalsPerSecond {
+    type Output = Pascals;
+
+    /// The pressure change at a rate over a time.
+    fn mul(self, rhs: Seconds) -> Pascals {
+        Pascals(self.0 * rhs.0)
+    }
+}
+
+additive_quantity!(
+    /// A `MetresPerSecondPerMetre` unit type for representing the rate of change
+    /// of speed with height, e.g. wind shear.
+    pub MetresPerSecondPerMetre, MetresPerSecondPerMetreUnit, "(m/s)/m", 1.0
+);
+
+impl Div<Metres> for MetresPerSecond {
+    type Output = MetresPerSecondPerMetre;
+
+    /// The mean rate of change of a speed difference over a height band.
+    fn div(self, rhs: Metres) -> MetresPerSecondPerMetre {
+        MetresPerSecondPerMetre(self.0 / rhs.0)
+    }
+}
+
+impl Mul<Metres> for MetresPerSecondPerMetre {
+    type Output = MetresPerSecond;
+
+    /// The speed change over a height band.
+    fn mul(self, rhs: Metres) -> MetresPerSecond {
+        MetresPerSecond(self.0 * rhs.0)
+    }
+}
+
+additive_quantity!(
+    /// An `Amperes` unit type for representing electric current.
+    pub Amperes, AmperesUnit, "A", 1.0
+);
+
+additive_quantity!(
+    /// A `Volts` unit type for representing electric potential difference.
+    pub Volts, VoltsUnit, "V", 1.0
+);
+
+additive_quantity!(
+    /// An `Ohms` unit type for representing electrical resistance.
+    pub Ohms, OhmsUnit, "Ω", 1.0
+);
+
+additive_quantity!(
+    /// A `Watts` unit type for representing power.
+    pub Watts, WattsUnit, "W", 1.0
+);
+
+impl Mul<Amperes> for Volts {
+    type Output = Watts;
+
+    /// The power of a current at a potential difference.
+    fn mul(self, rhs: Amperes) -> Watts {
+        Watts(self.0 * rhs.0)
+    }
+}
+
+impl Mul<Volts> for Amperes {
+    type Output = Watts;
+
+    /// The power of a current at a potential difference.
+    fn mul(self, rhs: Volts) -> Watts {
+        Watts(self.0 * rhs.0)
+    }
+}
+
+impl Div<Volts> for Watts {
+    type Output = Amperes;
+
+    /// The current drawn by a power at a potential difference.
+    fn div(self, rhs: Volts) -> Amperes {
+        Amperes(self.0 / rhs.0)
+    }
+}
+
+impl Div<Amperes> for Watts {
+    type Output = Volts;
+
+    /// The potential difference of a power at a current.
+    fn div(self, rhs: Amperes) -> Volts {
+        Volts(self.0 / rhs.0)
+    }
+}
+
+impl Div<Amperes> for Volts {
+    type Output = Ohms;
 
-/// A Pascals `newtype` for representing pressure.
-#[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Serialize, Deserialize)]
-pub struct Pascals(pub f64);
+    /// The resistance of a potential difference at a current, from Ohm's law.
+    fn div(self, rhs: Amperes) -> Ohms {
+        Ohms(self.0 / rhs.0)
+    }
+}
+
+impl Mul<Ohms> for Amperes {
+    type Output = Volts;
+
+    /// The potential difference of a current through a resistance, from Ohm's law.
+    fn mul(self, rhs: Ohms) -> Volts {
+        Volts(self.0 * rhs.0)
+    }
+}
+
+impl Mul<Amperes> for Ohms {
+    type Output = Volts;
 
-/// A Kilograms `newtype` for representing mass.
-#[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Serialize, Deserialize)]
-pub struct Kilograms(pub f64);
+    /// The potential difference of a current through a resistance, from Ohm's law.
+    fn mul(self, rhs: Amperes) -> Volts {
+        Volts(self.0 * rhs.0)
+    }
+}
 
-/// A Kilograms `newtype` for representing density.
-#[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Serialize, Deserialize)]
-pub struct KilogramsPerCubicMetre(pub f64);
+impl Div<Ohms> for Volts {
+    type Output = Amperes;
+
+    /// The current of a potential difference across a resistance, from Ohm's law.
+    fn div(self, rhs: Ohms) -> Amperes {
+        Amperes(self.0 / rhs.0)
+    }
+}
+
+additive_quantity!(
+    /// A `Candela` unit type for representing luminous intensity, e.g. of
+    /// runway and approach lights.
+    pub Candela, CandelaUnit, "cd", 1.0
+);
+
+impl Candela {
+    /// The illuminance normal to the direction of a point source of light
+    /// at a `distance`, from the inverse square law.
+    #[must_use]
+    pub fn illuminance_at(self, distance: Metres) -> Lux {
+        Lux(self.0 / (distance.0 * distance.0))
+    }
+}
+
+additive_quantity!(
+    /// A `Lumens` unit type for representing luminous flux.
+    pub Lumens, LumensUnit, "lm", 1.0
+);
+
+additive_quantity!(
+    /// A `Lux` unit type for representing illuminance.
+    pub Lux, LuxUnit, "lx", 1.0
+);
+
+additive_quantity!(
+    /// A `Becquerels` unit type for representing the activity of a radioactive
+    /// source.
+    pub Becquerels, BecquerelsUnit, "Bq", 1.0
+);
+
+additive_quantity!(
+    /// A `Sieverts` unit type for representing a radiation dose equivalent,
+    /// e.g. the cosmic radiation dose received by aircrew.
+    pub Sieverts, SievertsUnit, "Sv", 1.0
+);
+
+additive_quantity!(
+    /// A `SievertsPerSecond` unit type for representing a radiation dose
+    /// equivalent rate.
+    pub SievertsPerSecond, SievertsPerSecondUnit, "Sv/s", 1.0
+);
+
+impl Mul<Seconds> for SievertsPerSecond {
+    type Output = Sieverts;
+
+    /// The dose received at a dose rate over a time.
+    fn mul(self, rhs: Seconds) -> Sieverts {
+        Sieverts(self.0 * rhs.0)
+    }
+}
+
+/// Implements the `SiUnit` marker trait for SI unit types.
+macro_rules! impl_si_unit {
+    ($($t:ty),*) => {
+        $(impl crate::SiUnit for $t {})*
+    };
+}
+
+impl_si_unit!(
+    Metres,
+    MetresPerSecond,
+    MetresPerSecondSquared,
+    Kelvin,
+    KelvinDelta,
+    Pascals,
+    Kilograms,
+    KilogramsPerCubicMetre,
+    Radians,
+    RadiansPerSecond,
+    Seconds,
+    KilogramsPerSecond,
+    MetresPerKilogram,
+    CubicMetres,
+    CubicMetresPerSecond,
+    KilogramMetres,
+    PascalSeconds,
+    SquareMetresPerSecond,
+    JoulesPerKilogramKelvin,
+    PascalsPerSecond,
+    MetresPerSecondPerMetre,
+    Amperes,
+    Volts,
+    Ohms,
+    Watts,
+    Candela,
+    Lumens,
+    Lux,
+    Becquerels,
+    Sieverts,
+    SievertsPerSecond
+);
 
 #[cfg(test)]
+#[allow(clippy::clone_on_copy, clippy::float_cmp)]
 mod tests {
     use super::*;
-    use serde_json;
+    #[cfg(any(feature = "std", feature = "libm"))]
+    use crate::Unit;
 
     #[test]
     fn test_metres() {
@@ -64,14 +593,17 @@ mod tests {
         let two_m = Metres(2.0);
         assert!(one_m < two_m);
 
-        let serialized = serde_json::to_string(&one_m).unwrap();
-        let deserialized: Metres = serde_json::from_str(&serialized).unwrap();
-        assert_eq!(one_m, deserialized);
+        #[cfg(feature = "serde")]
+        {
+            let serialized = serde_json::to_string(&one_m).unwrap();
+            let deserialized: Metres = serde_json::from_str(&serialized).unwrap();
+            assert_eq!(one_m, deserialized);
 
-        let bad_text = "junk";
-        let _serde_error = serde_json::from_str::<Metres>(&bad_text).unwrap_err();
+            let bad_text = "junk";
+            let _serde_error = serde_json::from_str::<Metres>(bad_text).unwrap_err();
+        }
 
-        print!("Metres: {:?}", one_m);
+        print!("Metres: {one_m:?}");
     }
 
     #[test]
@@ -82,14 +614,17 @@ mod tests {
         let two_mps = MetresPerSecond(2.0);
         assert!(one_mps < two_mps);
 
-        let serialized = serde_json::to_string(&one_mps).unwrap();
-        let deserialized: MetresPerSecond = serde_json::from_str(&serialized).unwrap();
-        assert_eq!(one_mps, deserialized);
+        #[cfg(feature = "serde")]
+        {
+            let serialized = serde_json::to_string(&one_mps).unwrap();
+            let deserialized: MetresPerSecond = serde_json::from_str(&serialized).unwrap();
+            assert_eq!(one_mps, deserialized);
 
-        let bad_text = "junk";
-        let _serde_error = serde_json::from_str::<MetresPerSecond>(&bad_text).unwrap_err();
+            let bad_text = "junk";
+            let _serde_error = serde_json::from_str::<MetresPerSecond>(bad_text).unwrap_err();
+        }
 
-        print!("MetresPerSecond: {:?}", one_mps);
+        print!("MetresPerSecond: {one_mps:?}");
     }
 
     #[test]
@@ -100,14 +635,18 @@ mod tests {
         let two_mps2 = MetresPerSecondSquared(2.0);
         assert!(one_mps2 < two_mps2);
 
-        let serialized = serde_json::to_string(&one_mps2).unwrap();
-        let deserialized: MetresPerSecondSquared = serde_json::from_str(&serialized).unwrap();
-        assert_eq!(one_mps2, deserialized);
+        #[cfg(feature = "serde")]
+        {
+            let serialized = serde_json::to_string(&one_mps2).unwrap();
+            let deserialized: MetresPerSecondSquared = serde_json::from_str(&serialized).unwrap();
+            assert_eq!(one_mps2, deserialized);
 
-        let bad_text = "junk";
-        let _serde_error = serde_json::from_str::<MetresPerSecondSquared>(&bad_text).unwrap_err();
+            let bad_text = "junk";
+            let _serde_error =
+                serde_json::from_str::<MetresPerSecondSquared>(bad_text).unwrap_err();
+        }
 
-        print!("MetresPerSecondSquared: {:?}", one_mps2);
+        print!("MetresPerSecondSquared: {one_mps2:?}");
     }
 
     #[test]
@@ -118,14 +657,53 @@ mod tests {
         let two_k = Kelvin(2.0);
         assert!(one_k < two_k);
 
-        let serialized = serde_json::to_string(&one_k).unwrap();
-        let deserialized: Kelvin = serde_json::from_str(&serialized).unwrap();
-        assert_eq!(one_k, deserialized);
+        #[cfg(feature = "serde")]
+        {
+            let serialized = serde_json::to_string(&one_k).unwrap();
+            let deserialized: Kelvin = serde_json::from_str(&serialized).unwrap();
+            assert_eq!(one_k, deserialized);
+
+            let bad_text = "junk";
+            let _serde_error = serde_json::from_str::<Kelvin>(bad_text).unwrap_err();
+        }
+
+        print!("Kelvin: {one_k:?}");
+    }
+
+    #[test]
+    fn test_celsius() {
+        assert_eq!(Kelvin::ICE_POINT, Kelvin::from(Celsius::ICE_POINT));
+        assert_eq!(Celsius::ICE_POINT, Celsius::from(Kelvin::ICE_POINT));
+        assert_eq!(Kelvin(288.15), Kelvin::from(Celsius(15.0)));
+        assert!((-273.15 - Celsius::from(Kelvin(0.0)).0).abs() < 1e-12);
+        assert!(Celsius(-56.5) < Celsius(15.0));
+
+        #[cfg(feature = "serde")]
+        {
+            let serialized = serde_json::to_string(&Celsius(15.0)).unwrap();
+            let deserialized: Celsius = serde_json::from_str(&serialized).unwrap();
+            assert_eq!(Celsius(15.0), deserialized);
+        }
+
+        assert_eq!("15°C", Celsius(15.0).to_string());
+        assert_eq!("-56.5°C", format!("{:.1}", Celsius(-56.5)));
+    }
+
+    #[test]
+    fn test_kelvin_delta() {
+        let deviation = KelvinDelta(10.0);
+        assert_eq!(KelvinDelta(15.0), deviation + KelvinDelta(5.0));
+        assert_eq!(KelvinDelta(-10.0), -deviation);
+
+        assert_eq!(Kelvin(298.15), Kelvin(288.15) + deviation);
+        assert_eq!(Kelvin(278.15), Kelvin(288.15) - deviation);
+        assert_eq!(deviation, Kelvin(298.15) - Kelvin(288.15));
 
-        let bad_text = "junk";
-        let _serde_error = serde_json::from_str::<Kelvin>(&bad_text).unwrap_err();
+        assert_eq!(Celsius(25.0), Celsius(15.0) + deviation);
+        assert_eq!(Celsius(5.0), Celsius(15.0) - deviation);
+        assert_eq!(KelvinDelta(3.0), Celsius(15.0) - Celsius(12.0));
 
-        print!("Kelvin: {:?}", one_k);
+        assert_eq!("10 K", deviation.to_string());
     }
 
     #[test]
@@ -135,15 +713,19 @@ mod tests {
         assert_eq!(one_pa, one_pa_clone);
         let two_pa = Pascals(2.0);
         assert!(one_pa < two_pa);
+        assert_eq!(Pascals(101_325.0), Pascals::STANDARD_PRESSURE);
 
-        let serialized = serde_json::to_string(&one_pa).unwrap();
-        let deserialized: Pascals = serde_json::from_str(&serialized).unwrap();
-        assert_eq!(one_pa, deserialized);
+        #[cfg(feature = "serde")]
+        {
+            let serialized = serde_json::to_string(&one_pa).unwrap();
+            let deserialized: Pascals = serde_json::from_str(&serialized).unwrap();
+            assert_eq!(one_pa, deserialized);
 
-        let bad_text = "junk";
-        let _serde_error = serde_json::from_str::<Pascals>(&bad_text).unwrap_err();
+            let bad_text = "junk";
+            let _serde_error = serde_json::from_str::<Pascals>(bad_text).unwrap_err();
+        }
 
-        print!("Pascals: {:?}", one_pa);
+        print!("Pascals: {one_pa:?}");
     }
 
     #[test]
@@ -154,14 +736,17 @@ mod tests {
         let two_kg = Kilograms(2.0);
         assert!(one_kg < two_kg);
 
-        let serialized = serde_json::to_string(&one_kg).unwrap();
-        let deserialized: Kilograms = serde_json::from_str(&serialized).unwrap();
-        assert_eq!(one_kg, deserialized);
+        #[cfg(feature = "serde")]
+        {
+            let serialized = serde_json::to_string(&one_kg).unwrap();
+            let deserialized: Kilograms = serde_json::from_str(&serialized).unwrap();
+            assert_eq!(one_kg, deserialized);
 
-        let bad_text = "junk";
-        let _serde_error = serde_json::from_str::<Kilograms>(&bad_text).unwrap_err();
+            let bad_text = "junk";
+            let _serde_error = serde_json::from_str::<Kilograms>(bad_text).unwrap_err();
+        }
 
-        print!("Kilograms: {:?}", one_kg);
+        print!("Kilograms: {one_kg:?}");
     }
 
     #[test]
@@ -172,13 +757,247 @@ mod tests {
         let two_kgm3 = KilogramsPerCubicMetre(2.0);
         assert!(one_kgm3 < two_kgm3);
 
-        let serialized = serde_json::to_string(&one_kgm3).unwrap();
-        let deserialized: KilogramsPerCubicMetre = serde_json::from_str(&serialized).unwrap();
-        assert_eq!(one_kgm3, deserialized);
+        #[cfg(feature = "serde")]
+        {
+            let serialized = serde_json::to_string(&one_kgm3).unwrap();
+            let deserialized: KilogramsPerCubicMetre = serde_json::from_str(&serialized).unwrap();
+            assert_eq!(one_kgm3, deserialized);
+
+            let bad_text = "junk";
+            let _serde_error =
+                serde_json::from_str::<KilogramsPerCubicMetre>(bad_text).unwrap_err();
+        }
+
+        print!("KilogramsPerCubicMetre: {one_kgm3:?}");
+    }
+
+    #[test]
+    fn test_radians() {
+        let one_rad = Radians(1.0);
+        let one_rad_clone = one_rad.clone();
+        assert_eq!(one_rad, one_rad_clone);
+        let two_rad = Radians(2.0);
+        assert!(one_rad < two_rad);
+
+        #[cfg(feature = "serde")]
+        {
+            let serialized = serde_json::to_string(&one_rad).unwrap();
+            let deserialized: Radians = serde_json::from_str(&serialized).unwrap();
+            assert_eq!(one_rad, deserialized);
+
+            let bad_text = "junk";
+            let _serde_error = serde_json::from_str::<Radians>(bad_text).unwrap_err();
+        }
+
+        print!("Radians: {one_rad:?}");
+    }
+
+    #[test]
+    fn test_radians_per_second() {
+        let one_rps = RadiansPerSecond(1.0);
+        let two_rps = RadiansPerSecond(2.0);
+        assert!(one_rps < two_rps);
+        assert_eq!(one_rps, two_rps - one_rps);
+
+        #[cfg(feature = "serde")]
+        {
+            let serialized = serde_json::to_string(&one_rps).unwrap();
+            let deserialized: RadiansPerSecond = serde_json::from_str(&serialized).unwrap();
+            assert_eq!(one_rps, deserialized);
+        }
+    }
+
+    #[test]
+    fn test_seconds() {
+        let one_s = Seconds(1.0);
+        let one_s_clone = one_s.clone();
+        assert_eq!(one_s, one_s_clone);
+        let two_s = Seconds(2.0);
+        assert!(one_s < two_s);
+
+        #[cfg(feature = "serde")]
+        {
+            let serialized = serde_json::to_string(&one_s).unwrap();
+            let deserialized: Seconds = serde_json::from_str(&serialized).unwrap();
+            assert_eq!(one_s, deserialized);
+
+            let bad_text = "junk";
+            let _serde_error = serde_json::from_str::<Seconds>(bad_text).unwrap_err();
+        }
+
+        print!("Seconds: {one_s:?}");
+    }
+
+    #[test]
+    fn test_seconds_duration() {
+        let duration = Duration::from_millis(1500);
+        let seconds = Seconds::from(duration);
+        assert_eq!(1.5, seconds.0);
+        assert_eq!(Ok(duration), Duration::try_from(seconds));
+        assert!(Duration::try_from(Seconds(-1.0)).is_err());
+        assert!(Duration::try_from(Seconds(f64::NAN)).is_err());
+    }
+
+    #[test]
+    fn test_speed_times_time() {
+        let speed = MetresPerSecond(100.0);
+        assert_eq!(Metres(150.0), speed * Seconds(1.5));
+        assert_eq!(Metres(150.0), speed * Duration::from_millis(1500));
+    }
+
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[test]
+    fn test_additive() {
+        let mut distance = Metres(1.0) + Metres(2.0);
+        assert_eq!(Metres(3.0), distance);
+        distance -= Metres(0.5);
+        assert_eq!(Metres(2.5), distance);
+        distance += Metres(1.0);
+        assert_eq!(Metres(3.5), distance);
+        assert_eq!(Metres(-2.5), Metres(1.0) - distance);
+        assert_eq!(Metres(-3.5), -distance);
+        assert_eq!(distance, (-distance).abs());
+        assert_eq!(Metres(0.0), Metres::default());
+        assert_eq!(Metres(1.75), distance.half());
+        assert_eq!(Metres(7.0), distance.double());
+        assert_eq!(1.0, distance.signum());
+        assert_eq!(-1.0, (-distance).signum());
+        assert!(Metres::default().is_zero());
+        assert!(!distance.is_zero());
+        assert_eq!(Metres(8.0), distance.mul_add(2.0, Metres(1.0)));
+
+        let pressure_delta = Pascals(101_325.0) - Pascals(100_000.0);
+        assert_eq!(Pascals(1_325.0), pressure_delta);
+        assert_eq!(Pascals(-1_325.0), -pressure_delta);
+        assert_eq!(Pascals(0.0), Pascals::default());
+        assert_eq!(
+            KilogramsPerCubicMetre(0.25),
+            KilogramsPerCubicMetre(1.25) - KilogramsPerCubicMetre(1.0)
+        );
+        assert_eq!(
+            MetresPerSecondSquared(19.6),
+            MetresPerSecondSquared(9.8) + MetresPerSecondSquared(9.8)
+        );
+    }
+
+    #[cfg(feature = "bytemuck")]
+    #[test]
+    fn test_bytemuck() {
+        let values = [Metres(1.0), Metres(2.0)];
+        let bytes: &[u8] = bytemuck::cast_slice(&values);
+        assert_eq!(2 * core::mem::size_of::<f64>(), bytes.len());
+        let result: &[Metres] = bytemuck::cast_slice(bytes);
+        assert_eq!(values, result);
+
+        let zero: Pascals = bytemuck::Zeroable::zeroed();
+        assert_eq!(Pascals(0.0), zero);
+    }
+
+    #[test]
+    fn test_kinematics() {
+        assert_eq!(MetresPerSecond(5.0), Metres(50.0) / Seconds(10.0));
+        assert_eq!(
+            MetresPerSecondSquared(0.5),
+            MetresPerSecond(5.0) / Seconds(10.0)
+        );
+        assert_eq!(
+            MetresPerSecond(5.0),
+            MetresPerSecondSquared(0.5) * Seconds(10.0)
+        );
+    }
+
+    #[test]
+    fn test_kilogram_metres() {
+        let moment = Kilograms(80.0) * Metres(2.5);
+        assert_eq!(KilogramMetres(200.0), moment);
+        assert_eq!(moment, Metres(2.5) * Kilograms(80.0));
+        assert_eq!(Metres(2.5), moment / Kilograms(80.0));
+        assert_eq!(Kilograms(80.0), moment / Metres(2.5));
+        assert_eq!("200 kg·m", moment.to_string());
+    }
+
+    #[test]
+    fn test_pascal_seconds() {
+        let viscosity = PascalSeconds(1.789_4e-5);
+        assert!(PascalSeconds::ZERO < viscosity);
+        assert_eq!("0.000017894 Pa·s", viscosity.to_string());
+    }
 
-        let bad_text = "junk";
-        let _serde_error = serde_json::from_str::<KilogramsPerCubicMetre>(&bad_text).unwrap_err();
+    #[test]
+    fn test_square_metres_per_second() {
+        let density = KilogramsPerCubicMetre(1.25);
+        let viscosity = PascalSeconds(2.5e-5) / density;
+        assert_eq!(SquareMetresPerSecond(2e-5), viscosity);
+        assert_eq!(PascalSeconds(2.5e-5), viscosity * density);
+        assert_eq!("0.00002 m²/s", viscosity.to_string());
+    }
+
+    #[test]
+    fn test_joules_per_kilogram_kelvin() {
+        let gas_constant = JoulesPerKilogramKelvin(287.0);
+        assert!(gas_constant < JoulesPerKilogramKelvin(461.5));
+        assert_eq!("287 J/(kg·K)", gas_constant.to_string());
+    }
 
-        print!("KilogramsPerCubicMetre: {:?}", one_kgm3);
+    #[test]
+    fn test_pascals_per_second() {
+        let rate = Pascals(-300.0) / Seconds(600.0);
+        assert_eq!(PascalsPerSecond(-0.5), rate);
+        assert_eq!(Pascals(-900.0), rate * Seconds(1_800.0));
+        assert_eq!("-0.5 Pa/s", rate.to_string());
+    }
+
+    #[test]
+    fn test_metres_per_second_per_metre() {
+        let shear = MetresPerSecond(5.0) / Metres(100.0);
+        assert_eq!(MetresPerSecondPerMetre(0.05), shear);
+        assert_eq!(MetresPerSecond(2.5), shear * Metres(50.0));
+        assert_eq!("0.05 (m/s)/m", shear.to_string());
+    }
+
+    #[test]
+    fn test_electrical_units() {
+        let voltage = Volts(28.0);
+        let current = Amperes(2.5);
+        let power = voltage * current;
+        assert_eq!(Watts(70.0), power);
+        assert_eq!(power, current * voltage);
+        assert_eq!(current, power / voltage);
+        assert_eq!(voltage, power / current);
+
+        let resistance = voltage / current;
+        assert_eq!(Ohms(11.2), resistance);
+        assert_eq!(voltage, current * resistance);
+        assert_eq!(voltage, resistance * current);
+        assert_eq!(current, voltage / resistance);
+
+        assert_eq!("2.5 A", current.to_string());
+        assert_eq!("28 V", voltage.to_string());
+        assert_eq!("11.2 Ω", resistance.to_string());
+        assert_eq!("70 W", power.to_string());
+    }
+
+    #[test]
+    fn test_photometric_units() {
+        let intensity = Candela(10_000.0);
+        assert_eq!(Lux(1.0), intensity.illuminance_at(Metres(100.0)));
+        assert_eq!(Candela(15_000.0), intensity + Candela(5_000.0));
+        assert_eq!(Lumens(800.0), Lumens(1_000.0) - Lumens(200.0));
+        assert!(Lux(0.5) < Lux(1.0));
+
+        assert_eq!("10000 cd", intensity.to_string());
+        assert_eq!("800 lm", Lumens(800.0).to_string());
+        assert_eq!("1 lx", Lux(1.0).to_string());
+    }
+
+    #[test]
+    fn test_radiation_units() {
+        assert_eq!(Becquerels(1_500.0), Becquerels(1_000.0) + Becquerels(500.0));
+        assert_eq!("1000 Bq", Becquerels(1_000.0).to_string());
+
+        let rate = SievertsPerSecond(2e-9);
+        assert!((Sieverts(7.2e-6) - rate * Seconds(3_600.0)).0.abs() < 1e-18);
+        assert_eq!("0.000002 Sv", Sieverts(2e-6).to_string());
+        assert_eq!("0.000000002 Sv/s", rate.to_string());
     }
 }
@@ -0,0 +1,301 @@
+// Copyright (c) 2024 Ken Barker
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"),
+// to deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+// THE SOFTWARE.
+
+//! Single precision (`f32`) variants of the unit types, for embedded
+//! and GPU applications.
+//!
+//! Each type has the same name as its double precision (`f64`) equivalent with
+//! a `32` suffix, e.g. `Metres32`, and can be converted to and from it using
+//! the `From` trait. Conversion from double to single precision rounds to the
+//! nearest `f32` value.
+//!
+//! The non-SI types can be converted to and from their SI equivalents
+//! using the same conversion factors as the double precision types.
+
+#![allow(clippy::cast_possible_truncation)]
+
+use crate::{non_si, si};
+use core::convert::From;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Defines a single precision unit type and the conversions to and from
+/// its double precision equivalent.
+macro_rules! single_precision_unit {
+    ($(#[$attr:meta])* $t:ident, $f64_t:ty) => {
+        $(#[$attr])*
+        #[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+        #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+        #[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+        #[repr(transparent)]
+        pub struct $t(pub f32);
+
+        impl crate::Unit for $t {
+            const SYMBOL: &'static str = <$f64_t as crate::Unit>::SYMBOL;
+            const SI_FACTOR: f64 = <$f64_t as crate::Unit>::SI_FACTOR;
+
+            fn new(value: f64) -> Self {
+                Self(value as f32)
+            }
+
+            fn value(self) -> f64 {
+                f64::from(self.0)
+            }
+        }
+
+        impl core::fmt::Display for $t {
+            /// Formats the value followed by the unit symbol, e.g. "1000 ft".
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                crate::__private::fmt_unit(self.0, <Self as crate::Unit>::SYMBOL, f)
+            }
+        }
+
+        impl $t {
+            /// Zero.
+            pub const ZERO: Self = Self(0.0);
+
+            /// The smallest finite value.
+            pub const MIN: Self = Self(f32::MIN);
+
+            /// The largest finite value.
+            pub const MAX: Self = Self(f32::MAX);
+
+            /// Convert to the unit type `T`, e.g. `value.convert::<Feet32>()`.
+            #[must_use]
+            pub fn convert<T>(self) -> T
+            where
+                Self: crate::ConvertTo<T>,
+            {
+                crate::ConvertTo::convert(self)
+            }
+        }
+
+        impl From<$f64_t> for $t {
+            fn from(a: $f64_t) -> Self {
+                Self(a.0 as f32)
+            }
+        }
+
+        impl From<$t> for $f64_t {
+            fn from(a: $t) -> Self {
+                Self::new(f64::from(a.0))
+            }
+        }
+    };
+}
+
+single_precision_unit!(
+    /// A single precision `Metres` `newtype` for representing distance.
+    Metres32,
+    si::Metres
+);
+impl_additive!(Metres32);
+
+single_precision_unit!(
+    /// A single precision `MetresPerSecond` `newtype` for representing speed.
+    MetresPerSecond32,
+    si::MetresPerSecond
+);
+impl_additive!(MetresPerSecond32);
+
+single_precision_unit!(
+    /// A single precision `MetresPerSecondSquared` `newtype` for representing acceleration.
+    MetresPerSecondSquared32,
+    si::MetresPerSecondSquared
+);
+impl_additive!(MetresPerSecondSquared32);
+
+single_precision_unit!(
+    /// A single precision `Kelvin` `newtype` for representing temperature.
+    Kelvin32,
+    si::Kelvin
+);
+
+single_precision_unit!(
+    /// A single precision `Pascals` `newtype` for representing pressure.
+    Pascals32,
+    si::Pascals
+);
+impl_additive!(Pascals32);
+
+single_precision_unit!(
+    /// A single precision `Kilograms` `newtype` for representing mass.
+    Kilograms32,
+    si::Kilograms
+);
+impl_additive!(Kilograms32);
+
+single_precision_unit!(
+    /// A single precision `KilogramsPerCubicMetre` `newtype` for representing density.
+    KilogramsPerCubicMetre32,
+    si::KilogramsPerCubicMetre
+);
+impl_additive!(KilogramsPerCubicMetre32);
+
+single_precision_unit!(
+    /// A single precision `Radians` `newtype` for representing plane angles.
+    Radians32,
+    si::Radians
+);
+impl_additive!(Radians32);
+
+single_precision_unit!(
+    /// A single precision `Seconds` `newtype` for representing time.
+    Seconds32,
+    si::Seconds
+);
+impl_additive!(Seconds32);
+
+single_precision_unit!(
+    /// A single precision `NauticalMiles` `newtype` for representing distance.
+    NauticalMiles32,
+    non_si::NauticalMiles
+);
+impl_additive!(NauticalMiles32);
+
+single_precision_unit!(
+    /// A single precision `Feet` `newtype` for representing altitude.
+    Feet32,
+    non_si::Feet
+);
+impl_additive!(Feet32);
+
+single_precision_unit!(
+    /// A single precision `Knots` `newtype` for representing speed.
+    Knots32,
+    non_si::Knots
+);
+impl_additive!(Knots32);
+
+single_precision_unit!(
+    /// A single precision `Degrees` `newtype` for representing plane angles.
+    Degrees32,
+    non_si::Degrees
+);
+impl_additive!(Degrees32);
+
+/// Implements the conversions between a single precision non-SI unit type
+/// and its SI equivalent using a conversion factor.
+macro_rules! impl_single_precision_conversion {
+    ($t:ty, $si:ty, $factor:expr) => {
+        impl From<$si> for $t {
+            fn from(a: $si) -> Self {
+                Self(a.0 / ($factor as f32))
+            }
+        }
+
+        impl From<$t> for $si {
+            fn from(a: $t) -> Self {
+                Self(a.0 * ($factor as f32))
+            }
+        }
+    };
+}
+
+impl_single_precision_conversion!(NauticalMiles32, Metres32, non_si::METRES_PER_NAUTICAL_MILE);
+impl_single_precision_conversion!(Feet32, Metres32, non_si::METRES_PER_FOOT);
+impl_single_precision_conversion!(
+    Knots32,
+    MetresPerSecond32,
+    non_si::METRES_PER_SECOND_TO_KNOTS
+);
+
+impl From<Radians32> for Degrees32 {
+    fn from(a: Radians32) -> Self {
+        Self(a.0.to_degrees())
+    }
+}
+
+impl From<Degrees32> for Radians32 {
+    fn from(a: Degrees32) -> Self {
+        Self(a.0.to_radians())
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::float_cmp)]
+mod tests {
+    use super::*;
+    use crate::Unit;
+
+    #[test]
+    fn test_metres32() {
+        let one_m = Metres32(1.0);
+        let two_m = Metres32(2.0);
+        assert!(one_m < two_m);
+        assert_eq!(Metres32(3.0), one_m + two_m);
+        assert_eq!("m", Metres32::SYMBOL);
+        assert_eq!(1.0, one_m.value());
+        assert_eq!(one_m, Metres32::new(1.0));
+        assert_eq!(one_m, two_m.half());
+        assert_eq!(two_m, one_m.double());
+        assert_eq!(-1.0, (-one_m).signum());
+        assert!(Metres32::default().is_zero());
+
+        #[cfg(feature = "serde")]
+        {
+            let serialized = serde_json::to_string(&one_m).unwrap();
+            let deserialized: Metres32 = serde_json::from_str(&serialized).unwrap();
+            assert_eq!(one_m, deserialized);
+        }
+
+        print!("Metres32: {one_m:?}");
+    }
+
+    #[test]
+    fn test_convert_precision() {
+        let metres = si::Metres(1852.0);
+        let result = Metres32::from(metres);
+        assert_eq!(1852.0, result.0);
+        assert_eq!(metres, si::Metres::from(result));
+
+        let knots = non_si::Knots(0.1);
+        let result = Knots32::from(knots);
+        assert_eq!(0.1_f32, result.0);
+        // 0.1 cannot be represented exactly
+        assert_ne!(knots, non_si::Knots::from(result));
+    }
+
+    #[test]
+    fn test_convert_single_precision() {
+        let one_nm = NauticalMiles32(1.0);
+        let metres = Metres32::from(one_nm);
+        assert_eq!(1852.0, metres.0);
+        assert_eq!(one_nm, NauticalMiles32::from(metres));
+
+        let one_foot = Feet32(1.0);
+        let metres = Metres32::from(one_foot);
+        assert_eq!(0.304_8, metres.0);
+        assert_eq!(one_foot, Feet32::from(metres));
+
+        let one_knot = Knots32(1.0);
+        let metres_per_second = MetresPerSecond32::from(one_knot);
+        assert_eq!(
+            non_si::METRES_PER_SECOND_TO_KNOTS as f32,
+            metres_per_second.0
+        );
+        assert_eq!(one_knot, Knots32::from(metres_per_second));
+
+        let degrees = Degrees32(180.0);
+        let radians = Radians32::from(degrees);
+        assert_eq!(core::f32::consts::PI, radians.0);
+        assert_eq!(degrees, Degrees32::from(radians));
+    }
+}
@@ -0,0 +1,139 @@
+// Copyright (c) 2024 Ken Barker
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"),
+// to deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+// THE SOFTWARE.
+
+//! [sqlx](https://crates.io/crates/sqlx) `Type`, `Encode` and `Decode`
+//! implementations for the unit types.
+//!
+//! The units are stored in the same column type as an `f64`,
+//! e.g. `DOUBLE PRECISION` in a `PostgreSQL` database.
+
+use crate::quantity::{Quantity, UnitMarker};
+use crate::{non_si, si};
+use ::sqlx::{
+    database::Database,
+    decode::Decode,
+    encode::{Encode, IsNull},
+    error::BoxDynError,
+    types::Type,
+};
+
+/// Implements the `sqlx` traits for the type `$t` with the generic
+/// parameters `$g` and the constructor `$new` from an `f64`.
+macro_rules! impl_sqlx {
+    (impl<$($g:ident: $bound:path),*> $t:ty, $new:path) => {
+        impl<$($g: $bound,)* DB: Database> Type<DB> for $t
+        where
+            f64: Type<DB>,
+        {
+            fn type_info() -> DB::TypeInfo {
+                <f64 as Type<DB>>::type_info()
+            }
+
+            fn compatible(ty: &DB::TypeInfo) -> bool {
+                <f64 as Type<DB>>::compatible(ty)
+            }
+        }
+
+        impl<'q, $($g: $bound,)* DB: Database> Encode<'q, DB> for $t
+        where
+            f64: Encode<'q, DB>,
+        {
+            fn encode_by_ref(
+                &self,
+                buf: &mut <DB as Database>::ArgumentBuffer<'q>,
+            ) -> Result<IsNull, BoxDynError> {
+                <f64 as Encode<'q, DB>>::encode_by_ref(&self.0, buf)
+            }
+
+            fn size_hint(&self) -> usize {
+                <f64 as Encode<'q, DB>>::size_hint(&self.0)
+            }
+        }
+
+        impl<'r, $($g: $bound,)* DB: Database> Decode<'r, DB> for $t
+        where
+            f64: Decode<'r, DB>,
+        {
+            fn decode(value: <DB as Database>::ValueRef<'r>) -> Result<Self, BoxDynError> {
+                <f64 as Decode<'r, DB>>::decode(value).map($new)
+            }
+        }
+    };
+    ($t:ty, $new:path) => {
+        impl_sqlx!(impl<> $t, $new);
+    };
+}
+
+impl_sqlx!(impl<U: UnitMarker> Quantity<U>, Quantity::new);
+impl_sqlx!(non_si::Fahrenheit, non_si::Fahrenheit);
+impl_sqlx!(si::Celsius, si::Celsius);
+
+#[cfg(test)]
+#[allow(clippy::float_cmp)]
+mod tests {
+    use super::*;
+    use ::sqlx::{
+        sqlite::{Sqlite, SqliteArgumentValue},
+        Connection, SqliteConnection,
+    };
+
+    fn assert_sqlx<T>()
+    where
+        T: Type<Sqlite> + for<'q> Encode<'q, Sqlite> + for<'r> Decode<'r, Sqlite>,
+    {
+    }
+
+    #[test]
+    fn test_sqlx_traits() {
+        assert_sqlx::<si::Metres>();
+        assert_sqlx::<si::Kelvin>();
+        assert_sqlx::<non_si::Feet>();
+        assert_sqlx::<non_si::Knots>();
+        assert_sqlx::<non_si::Fahrenheit>();
+        assert_sqlx::<si::Celsius>();
+        assert_eq!(
+            <f64 as Type<Sqlite>>::type_info(),
+            <non_si::Feet as Type<Sqlite>>::type_info()
+        );
+
+        let mut buf = Vec::new();
+        let is_null = non_si::Feet(1000.0).encode_by_ref(&mut buf).unwrap();
+        assert!(matches!(is_null, IsNull::No));
+        assert!(matches!(buf[..], [SqliteArgumentValue::Double(v)] if v == 1000.0));
+    }
+
+    #[tokio::test]
+    async fn test_sqlx_round_trip() {
+        let mut conn = SqliteConnection::connect("sqlite::memory:").await.unwrap();
+        let altitude: non_si::Feet = ::sqlx::query_scalar("SELECT ?")
+            .bind(non_si::Feet(35_000.0))
+            .fetch_one(&mut conn)
+            .await
+            .unwrap();
+        assert_eq!(non_si::Feet(35_000.0), altitude);
+
+        let temperature: non_si::Fahrenheit = ::sqlx::query_scalar("SELECT ?")
+            .bind(non_si::Fahrenheit(59.0))
+            .fetch_one(&mut conn)
+            .await
+            .unwrap();
+        assert_eq!(non_si::Fahrenheit(59.0), temperature);
+    }
+}
@@ -0,0 +1,342 @@
+// Copyright (c) 2024 Ken Barker
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"),
+// to deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+// THE SOFTWARE.
+
+//! Descriptive statistics of additive unit values.
+//!
+//! The functions take iterators or slices of an additive unit type and
+//! return results of the same unit type, e.g. for the quality control of
+//! recorded altitudes or speeds:
+//!
+//! ```
+//! # #[cfg(any(feature = "std", feature = "libm"))]
+//! # {
+//! use icao_units::non_si::Feet;
+//! use icao_units::stats::{max, mean, median, min, stddev};
+//!
+//! let mut altitudes = [Feet(1_000.0), Feet(1_020.0), Feet(990.0), Feet(1_010.0)];
+//! assert_eq!(Some(Feet(1_005.0)), mean(altitudes));
+//! assert_eq!(Some(Feet(990.0)), min(altitudes));
+//! assert_eq!(Some(Feet(1_020.0)), max(altitudes));
+//! assert_eq!(13.0, stddev(altitudes).unwrap().0.round());
+//! assert_eq!(Some(Feet(1_005.0)), median(&mut altitudes));
+//! # }
+//! ```
+//!
+//! The functions return `None` if there are too few values.
+//!
+//! The `rayon` feature adds parallel versions of the `mean`, `stddev`, `min`
+//! and `max` functions for very large slices of values.
+
+#[cfg(any(feature = "std", feature = "libm"))]
+use crate::math;
+use crate::Unit;
+use core::ops::Add;
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+/// Add a value to a count and mean.
+fn accumulate_mean((count, mean): (usize, f64), value: f64) -> (usize, f64) {
+    let count = count + 1;
+    #[allow(clippy::cast_precision_loss)]
+    let mean = mean + (value - mean) / count as f64;
+    (count, mean)
+}
+
+/// Add a value to a count, mean and sum of squared deviations, using
+/// Welford's algorithm to avoid cancellation.
+#[cfg(any(feature = "std", feature = "libm"))]
+fn accumulate_moments((count, mean, squares): (usize, f64, f64), value: f64) -> (usize, f64, f64) {
+    let (count, new_mean) = accumulate_mean((count, mean), value);
+    (
+        count,
+        new_mean,
+        math::mul_add(value - mean, value - new_mean, squares),
+    )
+}
+
+/// The count, mean and sum of squared deviations of `values`.
+#[cfg(any(feature = "std", feature = "libm"))]
+fn moments<U, I>(values: I) -> (usize, f64, f64)
+where
+    U: Unit + Add<Output = U>,
+    I: IntoIterator<Item = U>,
+{
+    values
+        .into_iter()
+        .map(Unit::value)
+        .fold((0, 0.0, 0.0), accumulate_moments)
+}
+
+/// The arithmetic mean of `values`, or `None` if there are none.
+#[must_use]
+pub fn mean<U, I>(values: I) -> Option<U>
+where
+    U: Unit + Add<Output = U>,
+    I: IntoIterator<Item = U>,
+{
+    let (count, mean) = values
+        .into_iter()
+        .map(Unit::value)
+        .fold((0, 0.0), accumulate_mean);
+    (count > 0).then(|| U::new(mean))
+}
+
+/// The sample standard deviation of `values`, or `None` if there are
+/// fewer than two.
+#[cfg(any(feature = "std", feature = "libm"))]
+#[must_use]
+pub fn stddev<U, I>(values: I) -> Option<U>
+where
+    U: Unit + Add<Output = U>,
+    I: IntoIterator<Item = U>,
+{
+    let (count, _, squares) = moments(values);
+    #[allow(clippy::cast_precision_loss)]
+    (count > 1).then(|| U::new(math::sqrt(squares / (count - 1) as f64)))
+}
+
+/// The smallest of `values`, ignoring NaNs, or `None` if there are none.
+#[must_use]
+pub fn min<U, I>(values: I) -> Option<U>
+where
+    U: Unit + Add<Output = U>,
+    I: IntoIterator<Item = U>,
+{
+    values
+        .into_iter()
+        .map(Unit::value)
+        .reduce(f64::min)
+        .map(U::new)
+}
+
+/// The largest of `values`, ignoring NaNs, or `None` if there are none.
+#[must_use]
+pub fn max<U, I>(values: I) -> Option<U>
+where
+    U: Unit + Add<Output = U>,
+    I: IntoIterator<Item = U>,
+{
+    values
+        .into_iter()
+        .map(Unit::value)
+        .reduce(f64::max)
+        .map(U::new)
+}
+
+/// The median of `values`, or `None` if there are none.
+///
+/// The values are partially reordered in place, so that the median can be
+/// found without allocating. The median of an even number of values is the
+/// mean of the middle two values.
+#[must_use]
+pub fn median<U>(values: &mut [U]) -> Option<U>
+where
+    U: Unit + Add<Output = U>,
+{
+    let n = values.len();
+    if n == 0 {
+        return None;
+    }
+    let (lower, middle, _) =
+        values.select_nth_unstable_by(n / 2, |a, b| a.value().total_cmp(&b.value()));
+    let middle = middle.value();
+    Some(U::new(if n.is_multiple_of(2) {
+        // the largest value below the middle
+        let below = lower
+            .iter()
+            .map(|value| value.value())
+            .fold(f64::NEG_INFINITY, f64::max);
+        0.5 * (below + middle)
+    } else {
+        middle
+    }))
+}
+
+/// Combine the counts and means of two sets of values.
+#[cfg(all(feature = "rayon", any(feature = "std", feature = "libm")))]
+#[allow(clippy::cast_precision_loss)]
+fn merge_means((count_a, mean_a): (usize, f64), (count_b, mean_b): (usize, f64)) -> (usize, f64) {
+    let count = count_a + count_b;
+    if count == 0 {
+        (0, 0.0)
+    } else {
+        (
+            count,
+            math::mul_add(mean_b - mean_a, count_b as f64 / count as f64, mean_a),
+        )
+    }
+}
+
+/// Combine the counts, means and sums of squared deviations of two sets of
+/// values, using Chan's parallel algorithm.
+#[cfg(all(feature = "rayon", any(feature = "std", feature = "libm")))]
+#[allow(clippy::cast_precision_loss)]
+fn merge_moments(
+    (count_a, mean_a, squares_a): (usize, f64, f64),
+    (count_b, mean_b, squares_b): (usize, f64, f64),
+) -> (usize, f64, f64) {
+    let (count, mean) = merge_means((count_a, mean_a), (count_b, mean_b));
+    let delta = mean_b - mean_a;
+    let squares = if count == 0 {
+        0.0
+    } else {
+        math::mul_add(
+            delta * delta,
+            count_a as f64 * (count_b as f64 / count as f64),
+            squares_a + squares_b,
+        )
+    };
+    (count, mean, squares)
+}
+
+/// The arithmetic mean of `values` calculated in parallel, or `None` if
+/// there are none.
+#[cfg(all(feature = "rayon", any(feature = "std", feature = "libm")))]
+#[must_use]
+pub fn par_mean<U>(values: &[U]) -> Option<U>
+where
+    U: Unit + Add<Output = U> + Sync,
+{
+    let (count, mean) = values
+        .par_iter()
+        .map(|value| value.value())
+        .fold(|| (0, 0.0), accumulate_mean)
+        .reduce(|| (0, 0.0), merge_means);
+    (count > 0).then(|| U::new(mean))
+}
+
+/// The sample standard deviation of `values` calculated in parallel, or
+/// `None` if there are fewer than two.
+#[cfg(all(feature = "rayon", any(feature = "std", feature = "libm")))]
+#[must_use]
+pub fn par_stddev<U>(values: &[U]) -> Option<U>
+where
+    U: Unit + Add<Output = U> + Sync,
+{
+    let (count, _, squares) = values
+        .par_iter()
+        .map(|value| value.value())
+        .fold(|| (0, 0.0, 0.0), accumulate_moments)
+        .reduce(|| (0, 0.0, 0.0), merge_moments);
+    #[allow(clippy::cast_precision_loss)]
+    (count > 1).then(|| U::new(math::sqrt(squares / (count - 1) as f64)))
+}
+
+/// The smallest of `values` found in parallel, ignoring NaNs, or `None` if
+/// there are none.
+#[cfg(feature = "rayon")]
+#[must_use]
+pub fn par_min<U>(values: &[U]) -> Option<U>
+where
+    U: Unit + Add<Output = U> + Sync,
+{
+    values
+        .par_iter()
+        .map(|value| value.value())
+        .reduce_with(f64::min)
+        .map(U::new)
+}
+
+/// The largest of `values` found in parallel, ignoring NaNs, or `None` if
+/// there are none.
+#[cfg(feature = "rayon")]
+#[must_use]
+pub fn par_max<U>(values: &[U]) -> Option<U>
+where
+    U: Unit + Add<Output = U> + Sync,
+{
+    values
+        .par_iter()
+        .map(|value| value.value())
+        .reduce_with(f64::max)
+        .map(U::new)
+}
+
+#[cfg(test)]
+#[allow(clippy::float_cmp)]
+mod tests {
+    use super::*;
+    use crate::non_si::Knots;
+    #[cfg(any(feature = "std", feature = "libm"))]
+    use crate::si::Metres;
+
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[test]
+    fn test_statistics() {
+        let speeds = [Knots(250.0), Knots(260.0), Knots(240.0)];
+        assert_eq!(Some(Knots(250.0)), mean(speeds));
+        assert_eq!(Some(Knots(10.0)), stddev(speeds));
+        assert_eq!(Some(Knots(240.0)), min(speeds.iter().copied()));
+        assert_eq!(Some(Knots(260.0)), max(speeds));
+
+        // a large offset does not reduce the precision
+        let altitudes = [
+            Metres(1e9 + 4.0),
+            Metres(1e9 + 7.0),
+            Metres(1e9 + 13.0),
+            Metres(1e9 + 16.0),
+        ];
+        assert_eq!(Some(Metres(1e9 + 10.0)), mean(altitudes));
+        assert_eq!(
+            Some(Metres(30.0)),
+            stddev(altitudes).map(|s| Metres(s.0 * s.0))
+        );
+
+        assert_eq!(None, mean::<Knots, _>([]));
+        assert_eq!(None, stddev([Knots(1.0)]));
+        assert_eq!(None, min::<Knots, _>([]));
+        assert_eq!(Some(Knots(1.0)), max([Knots(f64::NAN), Knots(1.0)]));
+    }
+
+    #[test]
+    fn test_median() {
+        let mut odd = [Knots(3.0), Knots(1.0), Knots(2.0)];
+        assert_eq!(Some(Knots(2.0)), median(&mut odd));
+        let mut even = [Knots(4.0), Knots(1.0), Knots(3.0), Knots(2.0)];
+        assert_eq!(Some(Knots(2.5)), median(&mut even));
+        let mut single = [Knots(5.0)];
+        assert_eq!(Some(Knots(5.0)), median(&mut single));
+        assert_eq!(None, median::<Knots>(&mut []));
+    }
+
+    #[cfg(all(feature = "rayon", any(feature = "std", feature = "libm")))]
+    #[test]
+    fn test_par_statistics() {
+        let altitudes: Vec<Metres> = (0..10_001)
+            .map(|i| Metres(1e9 + f64::from(i % 97) * 0.5))
+            .collect();
+        let expected = mean(altitudes.iter().copied()).unwrap();
+        assert!((expected.0 - par_mean(&altitudes).unwrap().0).abs() < 1e-5);
+        let expected = stddev(altitudes.iter().copied()).unwrap();
+        assert!((expected.0 - par_stddev(&altitudes).unwrap().0).abs() < 1e-6);
+        assert_eq!(min(altitudes.iter().copied()), par_min(&altitudes));
+        assert_eq!(max(altitudes.iter().copied()), par_max(&altitudes));
+
+        let speeds = [Knots(250.0), Knots(260.0), Knots(240.0)];
+        assert_eq!(Some(Knots(250.0)), par_mean(&speeds));
+        assert_eq!(Some(Knots(10.0)), par_stddev(&speeds));
+        assert_eq!(Some(Knots(1.0)), par_max(&[Knots(f64::NAN), Knots(1.0)]));
+
+        assert_eq!(None, par_mean::<Knots>(&[]));
+        assert_eq!(None, par_stddev(&[Knots(1.0)]));
+        assert_eq!(None, par_min::<Knots>(&[]));
+        assert_eq!(None, par_max::<Knots>(&[]));
+    }
+}
@@ -0,0 +1,255 @@
+// Copyright (c) 2024 Ken Barker
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"),
+// to deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+// THE SOFTWARE.
+
+//! Serialize and deserialize unit values in a tagged form, e.g.:
+//! `{"value":1000.0,"unit":"ft"}`.
+//!
+//! The unit `newtypes` serialize as plain numbers by default.
+//! This module can be used with the serde `with` attribute to store values
+//! together with their unit symbol, e.g.:
+//!
+//! ```
+//! use icao_units::non_si::Feet;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Aircraft {
+//!     #[serde(with = "icao_units::tagged")]
+//!     altitude: Feet,
+//! }
+//! ```
+//!
+//! The [`deserialize`] function accepts **either** the plain number form
+//! **or** the tagged form, so data stored before a field was tagged
+//! can still be read.
+//! It can also be used on its own with the serde `deserialize_with` attribute
+//! for fields that are still serialized as plain numbers.
+//!
+//! Note: accepting either form requires a self-describing format, e.g. JSON.
+
+use crate::Unit;
+use core::{fmt, marker::PhantomData};
+use serde::{
+    de::{self, DeserializeSeed, MapAccess, Unexpected, Visitor},
+    ser::SerializeStruct,
+    Deserialize, Deserializer, Serializer,
+};
+
+/// Serialize a unit value in the tagged form.
+///
+/// # Errors
+///
+/// Returns the `serializer` error if the value cannot be serialized.
+pub fn serialize<T: Unit, S: Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
+    let mut state = serializer.serialize_struct("Tagged", 2)?;
+    state.serialize_field("value", &value.value())?;
+    state.serialize_field("unit", T::SYMBOL)?;
+    state.end()
+}
+
+/// Deserialize a unit value from either a plain number or the tagged form.
+///
+/// # Errors
+///
+/// Returns a `deserializer` error if the value is neither a number nor
+/// a map containing a `value` and a `unit` matching the symbol of `T`.
+pub fn deserialize<'de, T: Unit, D: Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> {
+    deserializer.deserialize_any(TaggedVisitor(PhantomData))
+}
+
+#[derive(Deserialize)]
+#[serde(field_identifier, rename_all = "lowercase")]
+enum Field {
+    Value,
+    Unit,
+}
+
+/// Deserializes a unit symbol, checking that it is the expected symbol.
+struct SymbolSeed(&'static str);
+
+impl<'de> DeserializeSeed<'de> for SymbolSeed {
+    type Value = ();
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<(), D::Error> {
+        deserializer.deserialize_str(self)
+    }
+}
+
+impl Visitor<'_> for SymbolSeed {
+    type Value = ();
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "the unit symbol \"{}\"", self.0)
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<(), E> {
+        if v == self.0 {
+            Ok(())
+        } else {
+            Err(E::invalid_value(Unexpected::Str(v), &self))
+        }
+    }
+}
+
+struct TaggedVisitor<T>(PhantomData<T>);
+
+impl<'de, T: Unit> Visitor<'de> for TaggedVisitor<T> {
+    type Value = T;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("a number or a map with `value` and `unit` fields")
+    }
+
+    fn visit_f64<E: de::Error>(self, v: f64) -> Result<T, E> {
+        Ok(T::new(v))
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<T, E> {
+        Ok(T::new(v as f64))
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<T, E> {
+        Ok(T::new(v as f64))
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<T, A::Error> {
+        let mut value = None;
+        let mut unit = false;
+        while let Some(key) = map.next_key()? {
+            match key {
+                Field::Value => {
+                    if value.is_some() {
+                        return Err(de::Error::duplicate_field("value"));
+                    }
+                    value = Some(map.next_value()?);
+                }
+                Field::Unit => {
+                    if unit {
+                        return Err(de::Error::duplicate_field("unit"));
+                    }
+                    map.next_value_seed(SymbolSeed(T::SYMBOL))?;
+                    unit = true;
+                }
+            }
+        }
+        if !unit {
+            return Err(de::Error::missing_field("unit"));
+        }
+        value
+            .map(T::new)
+            .ok_or_else(|| de::Error::missing_field("value"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{non_si, si};
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Tagged {
+        #[serde(with = "crate::tagged")]
+        altitude: non_si::Feet,
+        #[serde(with = "crate::tagged")]
+        speed: si::MetresPerSecond,
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Plain {
+        #[serde(deserialize_with = "crate::tagged::deserialize")]
+        altitude: non_si::Feet,
+    }
+
+    #[test]
+    fn test_tagged_round_trip() {
+        let value = Tagged {
+            altitude: non_si::Feet(1000.0),
+            speed: si::MetresPerSecond(2.5),
+        };
+        let serialized = serde_json::to_string(&value).unwrap();
+        assert_eq!(
+            r#"{"altitude":{"value":1000.0,"unit":"ft"},"speed":{"value":2.5,"unit":"m/s"}}"#,
+            serialized
+        );
+        let deserialized: Tagged = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(value, deserialized);
+
+        // the fields may be in any order
+        let deserialized: Tagged = serde_json::from_str(
+            r#"{"altitude":{"unit":"ft","value":1000},"speed":{"value":2.5,"unit":"m/s"}}"#,
+        )
+        .unwrap();
+        assert_eq!(value, deserialized);
+    }
+
+    #[test]
+    fn test_tagged_accepts_plain() {
+        let deserialized: Tagged =
+            serde_json::from_str(r#"{"altitude":1000.0,"speed":2.5}"#).unwrap();
+        assert_eq!(non_si::Feet(1000.0), deserialized.altitude);
+        assert_eq!(si::MetresPerSecond(2.5), deserialized.speed);
+
+        let deserialized: Tagged = serde_json::from_str(r#"{"altitude":-50,"speed":3}"#).unwrap();
+        assert_eq!(non_si::Feet(-50.0), deserialized.altitude);
+        assert_eq!(si::MetresPerSecond(3.0), deserialized.speed);
+
+        let value = Plain {
+            altitude: non_si::Feet(1000.0),
+        };
+        let serialized = serde_json::to_string(&value).unwrap();
+        assert_eq!(r#"{"altitude":1000.0}"#, serialized);
+        let deserialized: Plain = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(value, deserialized);
+        let deserialized: Plain =
+            serde_json::from_str(r#"{"altitude":{"value":1000.0,"unit":"ft"}}"#).unwrap();
+        assert_eq!(value, deserialized);
+    }
+
+    #[test]
+    fn test_tagged_errors() {
+        // wrong unit
+        let _serde_error =
+            serde_json::from_str::<Plain>(r#"{"altitude":{"value":1000.0,"unit":"m"}}"#)
+                .unwrap_err();
+        // missing unit
+        let _serde_error =
+            serde_json::from_str::<Plain>(r#"{"altitude":{"value":1000.0}}"#).unwrap_err();
+        // missing value
+        let _serde_error =
+            serde_json::from_str::<Plain>(r#"{"altitude":{"unit":"ft"}}"#).unwrap_err();
+        // duplicate fields
+        let _serde_error =
+            serde_json::from_str::<Plain>(r#"{"altitude":{"value":1.0,"value":2.0,"unit":"ft"}}"#)
+                .unwrap_err();
+        let _serde_error =
+            serde_json::from_str::<Plain>(r#"{"altitude":{"value":1.0,"unit":"ft","unit":"ft"}}"#)
+                .unwrap_err();
+        // unknown field
+        let _serde_error =
+            serde_json::from_str::<Plain>(r#"{"altitude":{"value":1.0,"unit":"ft","other":"ft"}}"#)
+                .unwrap_err();
+        // not a number
+        let _serde_error = serde_json::from_str::<Plain>(r#"{"altitude":"junk"}"#).unwrap_err();
+        let _serde_error =
+            serde_json::from_str::<Plain>(r#"{"altitude":{"value":1.0,"unit":1}}"#).unwrap_err();
+    }
+}
@@ -0,0 +1,205 @@
+// Copyright (c) 2024 Ken Barker
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"),
+// to deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+// THE SOFTWARE.
+
+//! Finite differences and integration of trajectory samples.
+//!
+//! The functions derive speeds from successive positions and accelerations
+//! from successive speeds, and integrate them back with the trapezoidal
+//! rule, keeping the units of each derivative correct, e.g.:
+//!
+//! ```
+//! use icao_units::si::{Metres, MetresPerSecond, Seconds};
+//! use icao_units::trajectory::{integrate_speeds, speeds};
+//!
+//! let times = [Seconds(0.0), Seconds(2.0), Seconds(4.0)];
+//! let positions = [Metres(0.0), Metres(100.0), Metres(300.0)];
+//! let mut velocities = [MetresPerSecond(0.0); 2];
+//! speeds(&times, &positions, &mut velocities);
+//! assert_eq!([MetresPerSecond(50.0), MetresPerSecond(100.0)], velocities);
+//!
+//! let mut distances = [Metres(0.0); 3];
+//! integrate_speeds(&times, &[MetresPerSecond(50.0); 3], Metres(0.0), &mut distances);
+//! assert_eq!([Metres(0.0), Metres(100.0), Metres(200.0)], distances);
+//! ```
+//!
+//! The samples need not be evenly spaced, but their times must increase:
+//! the difference of two samples at the same time is not finite.
+
+use crate::si::{Metres, MetresPerSecond, MetresPerSecondSquared, Seconds};
+use crate::Unit;
+
+/// The forward differences of `values` with respect to `times`.
+fn differentiate<T: Unit, U: Unit>(times: &[Seconds], values: &[T], rates: &mut [U]) {
+    assert_eq!(
+        times.len(),
+        values.len(),
+        "slices must have the same length"
+    );
+    assert_eq!(
+        times.len().saturating_sub(1),
+        rates.len(),
+        "rates must have one fewer element than times"
+    );
+
+    for ((t, v), rate) in times.windows(2).zip(values.windows(2)).zip(rates) {
+        *rate = U::new((v[1].value() - v[0].value()) / (t[1].0 - t[0].0));
+    }
+}
+
+/// The trapezoidal integrals of `rates` with respect to `times`,
+/// starting from `initial`.
+fn integrate<T: Unit, U: Unit>(times: &[Seconds], rates: &[T], initial: U, values: &mut [U]) {
+    assert_eq!(times.len(), rates.len(), "slices must have the same length");
+    assert_eq!(
+        times.len(),
+        values.len(),
+        "slices must have the same length"
+    );
+
+    let mut value = initial.value();
+    if let Some(first) = values.first_mut() {
+        *first = initial;
+    }
+    for ((t, r), v) in times
+        .windows(2)
+        .zip(rates.windows(2))
+        .zip(values.iter_mut().skip(1))
+    {
+        value += 0.5 * (r[0].value() + r[1].value()) * (t[1].0 - t[0].0);
+        *v = U::new(value);
+    }
+}
+
+/// The mean speeds between successive `positions` sampled at `times`.
+///
+/// # Panics
+///
+/// If `positions` and `times` have different lengths or `speeds` does not
+/// have one fewer element than `times`.
+pub fn speeds(times: &[Seconds], positions: &[Metres], speeds: &mut [MetresPerSecond]) {
+    differentiate(times, positions, speeds);
+}
+
+/// The mean accelerations between successive `speeds` sampled at `times`.
+///
+/// # Panics
+///
+/// If `speeds` and `times` have different lengths or `accelerations` does
+/// not have one fewer element than `times`.
+pub fn accelerations(
+    times: &[Seconds],
+    speeds: &[MetresPerSecond],
+    accelerations: &mut [MetresPerSecondSquared],
+) {
+    differentiate(times, speeds, accelerations);
+}
+
+/// The positions reached by integrating `speeds` sampled at `times` with
+/// the trapezoidal rule, starting from the `initial` position.
+///
+/// # Panics
+///
+/// If `speeds`, `times` and `positions` have different lengths.
+pub fn integrate_speeds(
+    times: &[Seconds],
+    speeds: &[MetresPerSecond],
+    initial: Metres,
+    positions: &mut [Metres],
+) {
+    integrate(times, speeds, initial, positions);
+}
+
+/// The speeds reached by integrating `accelerations` sampled at `times`
+/// with the trapezoidal rule, starting from the `initial` speed.
+///
+/// # Panics
+///
+/// If `accelerations`, `times` and `speeds` have different lengths.
+pub fn integrate_accelerations(
+    times: &[Seconds],
+    accelerations: &[MetresPerSecondSquared],
+    initial: MetresPerSecond,
+    speeds: &mut [MetresPerSecond],
+) {
+    integrate(times, accelerations, initial, speeds);
+}
+
+#[cfg(test)]
+#[allow(clippy::float_cmp)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_differentiate_and_integrate() {
+        // constant acceleration of 2 m/s² from 10 m/s, sampled unevenly
+        let times = [Seconds(0.0), Seconds(1.0), Seconds(3.0), Seconds(4.0)];
+        let speed_samples = [
+            MetresPerSecond(10.0),
+            MetresPerSecond(12.0),
+            MetresPerSecond(16.0),
+            MetresPerSecond(18.0),
+        ];
+        let position_samples = [Metres(0.0), Metres(11.0), Metres(39.0), Metres(56.0)];
+
+        let mut mean_speeds = [MetresPerSecond(0.0); 3];
+        speeds(&times, &position_samples, &mut mean_speeds);
+        assert_eq!(
+            [
+                MetresPerSecond(11.0),
+                MetresPerSecond(14.0),
+                MetresPerSecond(17.0)
+            ],
+            mean_speeds
+        );
+
+        let mut mean_accelerations = [MetresPerSecondSquared(0.0); 3];
+        accelerations(&times, &speed_samples, &mut mean_accelerations);
+        assert_eq!([MetresPerSecondSquared(2.0); 3], mean_accelerations);
+
+        // the trapezoidal rule is exact for a linear speed
+        let mut positions = [Metres(0.0); 4];
+        integrate_speeds(&times, &speed_samples, Metres(0.0), &mut positions);
+        assert_eq!(position_samples, positions);
+
+        let mut integrated_speeds = [MetresPerSecond(0.0); 4];
+        integrate_accelerations(
+            &times,
+            &[MetresPerSecondSquared(2.0); 4],
+            MetresPerSecond(10.0),
+            &mut integrated_speeds,
+        );
+        assert_eq!(speed_samples, integrated_speeds);
+
+        let mut empty: [MetresPerSecond; 0] = [];
+        speeds(&[], &[], &mut empty);
+        integrate_speeds(&[], &[], Metres(0.0), &mut []);
+    }
+
+    #[test]
+    #[should_panic(expected = "rates must have one fewer element than times")]
+    fn test_speeds_length() {
+        let mut speeds_out = [MetresPerSecond(0.0); 2];
+        speeds(
+            &[Seconds(0.0), Seconds(1.0)],
+            &[Metres(0.0), Metres(1.0)],
+            &mut speeds_out,
+        );
+    }
+}
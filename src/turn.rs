@@ -0,0 +1,253 @@
+// Copyright (c) 2024 Ken Barker
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"),
+// to deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+// THE SOFTWARE.
+
+//! Coordinated turns.
+//!
+//! The radius and rate of a coordinated (balanced) level turn depend on the
+//! true airspeed (TAS) and the bank angle, e.g.:
+//!
+//! ```
+//! # #[cfg(any(feature = "std", feature = "libm"))]
+//! # {
+//! use icao_units::non_si::{Degrees, DegreesPerSecond, Knots, NauticalMiles};
+//! use icao_units::turn;
+//!
+//! let radius = NauticalMiles::from(turn::radius(Knots(250.0), Degrees(25.0)));
+//! assert!((radius.0 - 1.95).abs() < 0.01);
+//!
+//! let bank = turn::standard_rate_bank(Knots(120.0));
+//! assert!((bank.0 - 18.2).abs() < 0.1);
+//! # }
+//! ```
+//!
+//! The functions use standard gravity, see `MetresPerSecondSquared::STANDARD_GRAVITY`.
+//!
+//! A [`BankAngle`] is a validated bank angle of less than 90 degrees either
+//! side of wings level, which determines the [`GLoad`] of a level turn
+//! and the increase in stall speed, e.g.:
+//!
+//! ```
+//! # #[cfg(any(feature = "std", feature = "libm"))]
+//! # {
+//! use icao_units::non_si::{Degrees, Knots};
+//! use icao_units::turn::{BankAngle, GLoad};
+//!
+//! let bank = BankAngle::try_from(Degrees(60.0)).unwrap();
+//! let load = bank.load_factor();
+//! assert!((load.0 - 2.0).abs() < 1e-12);
+//! assert!((load.stall_speed(Knots(100.0)).0 - 141.4).abs() < 0.1);
+//! # }
+//! ```
+
+use crate::exact::OutOfRange;
+use crate::math;
+use crate::non_si::Degrees;
+#[cfg(any(feature = "std", feature = "libm"))]
+use crate::non_si::DegreesPerSecond;
+#[cfg(any(feature = "std", feature = "libm"))]
+use crate::si::{Metres, MetresPerSecond, MetresPerSecondSquared, RadiansPerSecond};
+#[cfg(any(feature = "std", feature = "libm"))]
+use crate::{ConvertTo, Unit};
+use core::fmt;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+#[cfg(any(feature = "std", feature = "libm"))]
+const G: f64 = MetresPerSecondSquared::STANDARD_GRAVITY.0;
+
+/// The radius of a turn at true airspeed `tas` and `bank` angle:
+/// TAS² / (g tan(bank)).
+#[cfg(any(feature = "std", feature = "libm"))]
+#[must_use]
+pub fn radius<V: ConvertTo<MetresPerSecond>>(tas: V, bank: Degrees) -> Metres {
+    let tas = tas.convert().0;
+    Metres(tas * tas / (G * math::tan(bank.to_radians().0)))
+}
+
+/// The rate of a turn at true airspeed `tas` and `bank` angle:
+/// g tan(bank) / TAS.
+#[cfg(any(feature = "std", feature = "libm"))]
+#[must_use]
+pub fn rate<V: ConvertTo<MetresPerSecond>>(tas: V, bank: Degrees) -> DegreesPerSecond {
+    let tas = tas.convert().0;
+    DegreesPerSecond::from(RadiansPerSecond(G * math::tan(bank.to_radians().0) / tas))
+}
+
+/// The bank angle required for a turn at `rate` at true airspeed `tas`:
+/// atan(rate TAS / g).
+#[cfg(any(feature = "std", feature = "libm"))]
+#[must_use]
+pub fn bank_for_rate<V: ConvertTo<MetresPerSecond>>(tas: V, rate: DegreesPerSecond) -> Degrees {
+    let tas = tas.convert().0;
+    let rate = RadiansPerSecond::from(rate).0;
+    Degrees(math::atan(rate * tas / G).to_degrees())
+}
+
+/// The bank angle required for a standard rate turn of 3 degrees per
+/// second at true airspeed `tas`.
+#[cfg(any(feature = "std", feature = "libm"))]
+#[must_use]
+pub fn standard_rate_bank<V: ConvertTo<MetresPerSecond>>(tas: V) -> Degrees {
+    bank_for_rate(tas, DegreesPerSecond::STANDARD_RATE)
+}
+
+/// A bank angle, positive to the right, of less than 90 degrees either side
+/// of wings level.
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(try_from = "Degrees", into = "Degrees")
+)]
+pub struct BankAngle(Degrees);
+
+impl BankAngle {
+    /// Construct a bank angle, if it is less than 90 degrees either side of
+    /// wings level.
+    #[must_use]
+    pub fn new(angle: Degrees) -> Option<Self> {
+        (math::abs(angle.0) < 90.0).then_some(Self(angle))
+    }
+
+    /// The bank angle in degrees.
+    #[must_use]
+    pub const fn degrees(self) -> Degrees {
+        self.0
+    }
+
+    /// The load factor of a coordinated level turn at the bank angle:
+    /// 1 / cos(bank).
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[must_use]
+    pub fn load_factor(self) -> GLoad {
+        GLoad(1.0 / math::cos(self.0.to_radians().0))
+    }
+}
+
+impl TryFrom<Degrees> for BankAngle {
+    type Error = OutOfRange;
+
+    /// Fails if the angle is not less than 90 degrees either side of
+    /// wings level.
+    fn try_from(angle: Degrees) -> Result<Self, Self::Error> {
+        Self::new(angle).ok_or(OutOfRange(angle.0))
+    }
+}
+
+impl From<BankAngle> for Degrees {
+    fn from(a: BankAngle) -> Self {
+        a.0
+    }
+}
+
+impl fmt::Display for BankAngle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+quantity!(
+    /// A load factor: the ratio of the lift of an aircraft to its weight.
+    pub GLoad, GLoadUnit, "g", 1.0
+);
+
+impl GLoad {
+    /// The load factor of unaccelerated flight: 1 g.
+    pub const ONE: Self = Self::new(1.0);
+
+    /// The factor that the stall speed increases by at the load factor:
+    /// √n.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[must_use]
+    pub fn stall_speed_factor(self) -> f64 {
+        math::sqrt(self.0)
+    }
+
+    /// The stall speed at the load factor, given the 1 g `stall_speed`.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[must_use]
+    pub fn stall_speed<V: Unit>(self, stall_speed: V) -> V {
+        V::new(stall_speed.value() * self.stall_speed_factor())
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::float_cmp)]
+mod tests {
+    #[cfg(any(feature = "std", feature = "libm"))]
+    use super::*;
+    #[cfg(any(feature = "std", feature = "libm"))]
+    use crate::non_si::{Knots, NauticalMiles};
+
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[test]
+    fn test_turn() {
+        // 100 m/s at 45° bank
+        let radius = radius(MetresPerSecond(100.0), Degrees(45.0));
+        assert!((radius.0 - 10_000.0 / G).abs() < 1e-9);
+        let rate = rate(MetresPerSecond(100.0), Degrees(45.0));
+        assert!((rate.0 - (G / 100.0).to_degrees()).abs() < 1e-12);
+
+        // radius = TAS / rate
+        let tas = Knots(250.0);
+        let bank = Degrees(25.0);
+        let radius = super::radius(tas, bank);
+        let rate = RadiansPerSecond::from(super::rate(tas, bank));
+        assert!((radius.0 - MetresPerSecond::from(tas).0 / rate.0).abs() < 1e-9);
+        assert!((NauticalMiles::from(radius).0 - 1.953).abs() < 0.001);
+
+        // the bank for the rate of a turn is the bank of the turn
+        let rate = super::rate(tas, bank);
+        assert!((bank_for_rate(tas, rate).0 - bank.0).abs() < 1e-12);
+
+        // rule of thumb: standard rate bank ≈ TAS / 10 + 7
+        let bank = standard_rate_bank(Knots(120.0));
+        assert!((bank.0 - 19.0).abs() < 1.0);
+        assert_eq!(Degrees(0.0), standard_rate_bank(Knots(0.0)));
+    }
+
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[test]
+    fn test_bank_angle_and_load_factor() {
+        let bank = BankAngle::new(Degrees(-45.0)).unwrap();
+        assert_eq!(Degrees(-45.0), bank.degrees());
+        assert_eq!(Degrees(-45.0), Degrees::from(bank));
+        assert!((bank.load_factor().0 - core::f64::consts::SQRT_2).abs() < 1e-12);
+        assert_eq!(GLoad::ONE, BankAngle::default().load_factor());
+        assert_eq!(None, BankAngle::new(Degrees(90.0)));
+        assert_eq!(None, BankAngle::new(Degrees(f64::NAN)));
+        assert_eq!(Err(OutOfRange(-95.0)), BankAngle::try_from(Degrees(-95.0)));
+        assert_eq!("-45°", bank.to_string());
+
+        let load = GLoad(4.0);
+        assert_eq!(2.0, load.stall_speed_factor());
+        assert_eq!(Knots(200.0), load.stall_speed(Knots(100.0)));
+        assert_eq!("4 g", load.to_string());
+
+        #[cfg(feature = "serde")]
+        {
+            let serialized = serde_json::to_string(&bank).unwrap();
+            assert_eq!("-45.0", serialized);
+            let deserialized: BankAngle = serde_json::from_str(&serialized).unwrap();
+            assert_eq!(bank, deserialized);
+            assert!(serde_json::from_str::<BankAngle>("90.0").is_err());
+        }
+    }
+}
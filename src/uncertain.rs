@@ -0,0 +1,224 @@
+// Copyright (c) 2024 Ken Barker
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"),
+// to deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+// THE SOFTWARE.
+
+//! Unit values with an uncertainty, e.g. an altitude of 1000 ft ± 25 ft.
+//!
+//! An [`Uncertain`] value combines a unit value with its standard deviation
+//! in the same unit. The standard deviation is propagated through addition
+//! and subtraction, assuming that the values are independent, and through
+//! multiplication and division by a scalar, e.g.:
+//!
+//! ```
+//! # #[cfg(any(feature = "std", feature = "libm"))]
+//! # {
+//! use icao_units::non_si::Feet;
+//! use icao_units::uncertain::Uncertain;
+//!
+//! let altitude = Uncertain::new(Feet(10_000.0), Feet(30.0));
+//! let height = altitude - Uncertain::new(Feet(2_000.0), Feet(40.0));
+//! assert_eq!(Uncertain::new(Feet(8_000.0), Feet(50.0)), height);
+//! # }
+//! ```
+
+use crate::{math, Unit};
+#[cfg(any(feature = "std", feature = "libm"))]
+use core::ops::{Add, Sub};
+use core::ops::{Div, Mul, Neg};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// A unit value and its standard deviation in the same unit.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Uncertain<T> {
+    /// The value.
+    pub value: T,
+    /// The standard deviation of the value.
+    pub std_dev: T,
+}
+
+impl<T: Unit> Uncertain<T> {
+    /// Construct an uncertain value from a value and its standard deviation.
+    #[must_use]
+    pub const fn new(value: T, std_dev: T) -> Self {
+        Self { value, std_dev }
+    }
+
+    /// Construct an uncertain value from an exact value.
+    #[must_use]
+    pub fn exact(value: T) -> Self {
+        Self::new(value, T::new(0.0))
+    }
+
+    /// The variance of the value, in the unit squared.
+    #[must_use]
+    pub fn variance(self) -> f64 {
+        self.std_dev.value() * self.std_dev.value()
+    }
+
+    /// The interval of `coverage_factor` standard deviations either side of
+    /// the value, e.g. a coverage factor of 2.0 for a 95% confidence interval.
+    #[must_use]
+    pub fn interval(self, coverage_factor: f64) -> (T, T) {
+        let half_width = coverage_factor * self.std_dev.value();
+        (
+            T::new(self.value.value() - half_width),
+            T::new(self.value.value() + half_width),
+        )
+    }
+
+    /// Convert to an uncertain value in the unit type `U`.
+    /// The standard deviation is scaled by the ratio of the unit SI factors.
+    #[must_use]
+    pub fn convert<U: Unit + From<T>>(self) -> Uncertain<U> {
+        Uncertain::new(
+            U::from(self.value),
+            U::from_si_value(self.std_dev.si_value()),
+        )
+    }
+
+    /// The combined standard deviation of two independent values.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn combined_std_dev(self, rhs: Self) -> T {
+        T::new(math::sqrt(self.variance() + rhs.variance()))
+    }
+}
+
+impl<T: Unit> From<T> for Uncertain<T> {
+    fn from(a: T) -> Self {
+        Self::exact(a)
+    }
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+impl<T: Unit> Add for Uncertain<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self::new(
+            T::new(self.value.value() + rhs.value.value()),
+            self.combined_std_dev(rhs),
+        )
+    }
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+impl<T: Unit> Sub for Uncertain<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self::new(
+            T::new(self.value.value() - rhs.value.value()),
+            self.combined_std_dev(rhs),
+        )
+    }
+}
+
+impl<T: Unit> Neg for Uncertain<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(T::new(-self.value.value()), self.std_dev)
+    }
+}
+
+impl<T: Unit> Mul<f64> for Uncertain<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: f64) -> Self {
+        Self::new(
+            T::new(self.value.value() * rhs),
+            T::new(self.std_dev.value() * math::abs(rhs)),
+        )
+    }
+}
+
+impl<T: Unit> Div<f64> for Uncertain<T> {
+    type Output = Self;
+
+    fn div(self, rhs: f64) -> Self {
+        Self::new(
+            T::new(self.value.value() / rhs),
+            T::new(self.std_dev.value() / math::abs(rhs)),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(any(feature = "std", feature = "libm"))]
+    use super::*;
+    #[cfg(any(feature = "std", feature = "libm"))]
+    use crate::{non_si, si};
+
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[test]
+    fn test_uncertain() {
+        let altitude = Uncertain::new(non_si::Feet(10_000.0), non_si::Feet(30.0));
+        let elevation = Uncertain::new(non_si::Feet(2_000.0), non_si::Feet(40.0));
+        assert_eq!(
+            Uncertain::new(non_si::Feet(12_000.0), non_si::Feet(50.0)),
+            altitude + elevation
+        );
+        assert_eq!(
+            Uncertain::new(non_si::Feet(8_000.0), non_si::Feet(50.0)),
+            altitude - elevation
+        );
+        assert_eq!(
+            Uncertain::new(non_si::Feet(-10_000.0), non_si::Feet(30.0)),
+            -altitude
+        );
+        assert_eq!(
+            Uncertain::new(non_si::Feet(-20_000.0), non_si::Feet(60.0)),
+            altitude * -2.0
+        );
+        assert_eq!(
+            Uncertain::new(non_si::Feet(5_000.0), non_si::Feet(15.0)),
+            altitude / 2.0
+        );
+        assert_eq!(
+            (non_si::Feet(9_940.0), non_si::Feet(10_060.0)),
+            altitude.interval(2.0)
+        );
+        assert_eq!(
+            altitude + Uncertain::exact(non_si::Feet(100.0)),
+            Uncertain::new(non_si::Feet(10_100.0), non_si::Feet(30.0))
+        );
+        assert_eq!(altitude, altitude + non_si::Feet(0.0).into());
+
+        let distance = Uncertain::new(non_si::NauticalMiles(10.0), non_si::NauticalMiles(0.1));
+        let metres: Uncertain<si::Metres> = distance.convert();
+        assert_eq!(
+            Uncertain::new(
+                si::Metres(18_520.0),
+                si::Metres::from(non_si::NauticalMiles(0.1))
+            ),
+            metres
+        );
+
+        #[cfg(feature = "serde")]
+        {
+            let serialized = serde_json::to_string(&altitude).unwrap();
+            assert_eq!(r#"{"value":10000.0,"std_dev":30.0}"#, serialized);
+            let deserialized: Uncertain<non_si::Feet> = serde_json::from_str(&serialized).unwrap();
+            assert_eq!(altitude, deserialized);
+        }
+    }
+}
@@ -0,0 +1,414 @@
+// Copyright (c) 2024 Ken Barker
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"),
+// to deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+// THE SOFTWARE.
+
+//! Velocity and displacement vectors in local north, east, down (NED) axes.
+//!
+//! The components of the vectors are unit types, so magnitudes are returned
+//! in the proper unit and velocities multiplied by a time give displacements,
+//! e.g.:
+//!
+//! ```
+//! # #[cfg(any(feature = "std", feature = "libm"))]
+//! # {
+//! use icao_units::non_si::Degrees;
+//! use icao_units::si::{Metres, MetresPerSecond, Seconds};
+//! use icao_units::vector::Velocity2d;
+//!
+//! let velocity = Velocity2d::new(MetresPerSecond(-30.0), MetresPerSecond(40.0));
+//! assert_eq!(MetresPerSecond(50.0), velocity.magnitude());
+//! assert_eq!(Degrees(127.0), Degrees(velocity.track().0.round()));
+//!
+//! let displacement = velocity * Seconds(10.0);
+//! assert_eq!(Metres(500.0), displacement.magnitude());
+//! # }
+//! ```
+//!
+//! A [`PolarVelocity`], e.g. an ADS-B or ASTERIX ground vector of ground
+//! speed and track, converts to and from a [`Velocity2d`].
+
+#[cfg(any(feature = "std", feature = "libm"))]
+use crate::math;
+use crate::non_si::{Degrees, Knots};
+use crate::si::{Metres, MetresPerSecond, Seconds};
+use core::ops::{Add, Mul, Neg, Sub};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Implements `Add`, `Sub`, `Neg` and `Mul<f64>` component-wise for a vector
+/// type with the given fields of unit type `$u`.
+macro_rules! impl_vector_ops {
+    ($t:ident, $u:ident, $($field:ident),+) => {
+        impl Add for $t {
+            type Output = Self;
+
+            fn add(self, rhs: Self) -> Self {
+                Self { $($field: self.$field + rhs.$field),+ }
+            }
+        }
+
+        impl Sub for $t {
+            type Output = Self;
+
+            fn sub(self, rhs: Self) -> Self {
+                Self { $($field: self.$field - rhs.$field),+ }
+            }
+        }
+
+        impl Neg for $t {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                Self { $($field: -self.$field),+ }
+            }
+        }
+
+        impl Mul<f64> for $t {
+            type Output = Self;
+
+            fn mul(self, rhs: f64) -> Self {
+                Self { $($field: $u(self.$field.0 * rhs)),+ }
+            }
+        }
+    };
+}
+
+/// The track of a vector with `north` and `east` components, in the range
+/// [0, 360).
+#[cfg(any(feature = "std", feature = "libm"))]
+fn track(north: f64, east: f64) -> Degrees {
+    Degrees(math::atan2(east, north).to_degrees()).normalise()
+}
+
+/// A horizontal velocity.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Velocity2d {
+    /// The velocity component towards north.
+    pub north: MetresPerSecond,
+    /// The velocity component towards east.
+    pub east: MetresPerSecond,
+}
+
+impl Velocity2d {
+    /// Construct a horizontal velocity from its components.
+    #[must_use]
+    pub const fn new(north: MetresPerSecond, east: MetresPerSecond) -> Self {
+        Self { north, east }
+    }
+
+    /// The speed of the velocity.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[must_use]
+    pub fn magnitude(self) -> MetresPerSecond {
+        MetresPerSecond(math::hypot(self.north.0, self.east.0))
+    }
+
+    /// The direction of the velocity, in the range [0, 360).
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[must_use]
+    pub fn track(self) -> Degrees {
+        track(self.north.0, self.east.0)
+    }
+}
+
+impl_vector_ops!(Velocity2d, MetresPerSecond, north, east);
+
+impl Mul<Seconds> for Velocity2d {
+    type Output = Displacement2d;
+
+    fn mul(self, rhs: Seconds) -> Displacement2d {
+        Displacement2d::new(self.north * rhs, self.east * rhs)
+    }
+}
+
+/// A horizontal velocity as a speed and track, e.g. a ground vector.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PolarVelocity {
+    /// The speed.
+    pub speed: Knots,
+    /// The direction of travel, clockwise from north.
+    pub track: Degrees,
+}
+
+impl PolarVelocity {
+    /// Construct a polar velocity.
+    #[must_use]
+    pub const fn new(speed: Knots, track: Degrees) -> Self {
+        Self { speed, track }
+    }
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+impl From<PolarVelocity> for Velocity2d {
+    fn from(a: PolarVelocity) -> Self {
+        let speed = MetresPerSecond::from(a.speed).0;
+        let track = a.track.to_radians().0;
+        Self::new(
+            MetresPerSecond(speed * math::cos(track)),
+            MetresPerSecond(speed * math::sin(track)),
+        )
+    }
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+impl From<Velocity2d> for PolarVelocity {
+    /// Convert the components of a velocity to a speed and a track
+    /// in the range [0, 360).
+    fn from(a: Velocity2d) -> Self {
+        Self::new(Knots::from(a.magnitude()), a.track())
+    }
+}
+
+/// A velocity in three dimensions.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Velocity3d {
+    /// The velocity component towards north.
+    pub north: MetresPerSecond,
+    /// The velocity component towards east.
+    pub east: MetresPerSecond,
+    /// The velocity component downwards, negative when climbing.
+    pub down: MetresPerSecond,
+}
+
+impl Velocity3d {
+    /// Construct a velocity from its components.
+    #[must_use]
+    pub const fn new(north: MetresPerSecond, east: MetresPerSecond, down: MetresPerSecond) -> Self {
+        Self { north, east, down }
+    }
+
+    /// The speed of the velocity.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[must_use]
+    pub fn magnitude(self) -> MetresPerSecond {
+        MetresPerSecond(math::hypot(
+            math::hypot(self.north.0, self.east.0),
+            self.down.0,
+        ))
+    }
+
+    /// The horizontal component of the velocity.
+    #[must_use]
+    pub const fn horizontal(self) -> Velocity2d {
+        Velocity2d::new(self.north, self.east)
+    }
+
+    /// The direction of the horizontal component of the velocity,
+    /// in the range [0, 360).
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[must_use]
+    pub fn track(self) -> Degrees {
+        track(self.north.0, self.east.0)
+    }
+}
+
+impl_vector_ops!(Velocity3d, MetresPerSecond, north, east, down);
+
+impl Mul<Seconds> for Velocity3d {
+    type Output = Displacement3d;
+
+    fn mul(self, rhs: Seconds) -> Displacement3d {
+        Displacement3d::new(self.north * rhs, self.east * rhs, self.down * rhs)
+    }
+}
+
+/// A horizontal displacement.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Displacement2d {
+    /// The displacement towards north.
+    pub north: Metres,
+    /// The displacement towards east.
+    pub east: Metres,
+}
+
+impl Displacement2d {
+    /// Construct a horizontal displacement from its components.
+    #[must_use]
+    pub const fn new(north: Metres, east: Metres) -> Self {
+        Self { north, east }
+    }
+
+    /// The distance of the displacement.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[must_use]
+    pub fn magnitude(self) -> Metres {
+        Metres(math::hypot(self.north.0, self.east.0))
+    }
+
+    /// The direction of the displacement, in the range [0, 360).
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[must_use]
+    pub fn track(self) -> Degrees {
+        track(self.north.0, self.east.0)
+    }
+}
+
+impl_vector_ops!(Displacement2d, Metres, north, east);
+
+/// A displacement in three dimensions.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Displacement3d {
+    /// The displacement towards north.
+    pub north: Metres,
+    /// The displacement towards east.
+    pub east: Metres,
+    /// The displacement downwards, negative for a gain in height.
+    pub down: Metres,
+}
+
+impl Displacement3d {
+    /// Construct a displacement from its components.
+    #[must_use]
+    pub const fn new(north: Metres, east: Metres, down: Metres) -> Self {
+        Self { north, east, down }
+    }
+
+    /// The distance of the displacement.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[must_use]
+    pub fn magnitude(self) -> Metres {
+        Metres(math::hypot(
+            math::hypot(self.north.0, self.east.0),
+            self.down.0,
+        ))
+    }
+
+    /// The horizontal component of the displacement.
+    #[must_use]
+    pub const fn horizontal(self) -> Displacement2d {
+        Displacement2d::new(self.north, self.east)
+    }
+
+    /// The direction of the horizontal component of the displacement,
+    /// in the range [0, 360).
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[must_use]
+    pub fn track(self) -> Degrees {
+        track(self.north.0, self.east.0)
+    }
+}
+
+impl_vector_ops!(Displacement3d, Metres, north, east, down);
+
+#[cfg(test)]
+#[allow(clippy::float_cmp)]
+mod tests {
+    #[cfg(any(feature = "std", feature = "libm"))]
+    use super::*;
+
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[test]
+    fn test_velocity() {
+        let a = Velocity2d::new(MetresPerSecond(3.0), MetresPerSecond(4.0));
+        assert_eq!(MetresPerSecond(5.0), a.magnitude());
+        let b = Velocity2d::new(MetresPerSecond(0.0), MetresPerSecond(-10.0));
+        assert_eq!(Degrees(270.0), b.track());
+        assert_eq!(
+            Velocity2d::new(MetresPerSecond(3.0), MetresPerSecond(-6.0)),
+            a + b
+        );
+        assert_eq!(
+            Velocity2d::new(MetresPerSecond(3.0), MetresPerSecond(14.0)),
+            a - b
+        );
+        assert_eq!(a * -1.0, -a);
+        assert_eq!(Degrees(0.0), Velocity2d::default().track());
+
+        let c = Velocity3d::new(
+            MetresPerSecond(2.0),
+            MetresPerSecond(3.0),
+            MetresPerSecond(-6.0),
+        );
+        assert_eq!(MetresPerSecond(7.0), c.magnitude());
+        assert_eq!(c.horizontal().track(), c.track());
+        assert_eq!(
+            Velocity3d::new(
+                MetresPerSecond(4.0),
+                MetresPerSecond(6.0),
+                MetresPerSecond(-12.0)
+            ),
+            c + c
+        );
+        assert_eq!(Velocity3d::default(), c - c);
+
+        #[cfg(feature = "serde")]
+        {
+            let serialized = serde_json::to_string(&c).unwrap();
+            let deserialized: Velocity3d = serde_json::from_str(&serialized).unwrap();
+            assert_eq!(c, deserialized);
+        }
+    }
+
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[test]
+    fn test_polar_velocity() {
+        let polar = PolarVelocity::new(Knots(200.0), Degrees(90.0));
+        let velocity = Velocity2d::from(polar);
+        assert!(velocity.north.0.abs() < 1e-12);
+        assert_eq!(MetresPerSecond::from(Knots(200.0)), velocity.east);
+
+        let velocity = Velocity2d::new(MetresPerSecond(-100.0), MetresPerSecond(-100.0));
+        let polar = PolarVelocity::from(velocity);
+        assert!((polar.speed.0 - 274.901).abs() < 1e-3);
+        assert_eq!(Degrees(225.0), polar.track);
+
+        let round_trip = Velocity2d::from(polar);
+        assert!((round_trip.north.0 - velocity.north.0).abs() < 1e-12);
+        assert!((round_trip.east.0 - velocity.east.0).abs() < 1e-12);
+
+        #[cfg(feature = "serde")]
+        {
+            let serialized = serde_json::to_string(&polar).unwrap();
+            let deserialized: PolarVelocity = serde_json::from_str(&serialized).unwrap();
+            assert_eq!(polar, deserialized);
+        }
+    }
+
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[test]
+    fn test_displacement() {
+        let velocity = Velocity3d::new(
+            MetresPerSecond(2.0),
+            MetresPerSecond(3.0),
+            MetresPerSecond(-6.0),
+        );
+        let a = velocity * Seconds(10.0);
+        assert_eq!(
+            Displacement3d::new(Metres(20.0), Metres(30.0), Metres(-60.0)),
+            a
+        );
+        assert_eq!(Metres(70.0), a.magnitude());
+        assert_eq!(velocity.track(), a.track());
+
+        let b = a.horizontal() - Displacement2d::new(Metres(20.0), Metres(0.0));
+        assert_eq!(Displacement2d::new(Metres(0.0), Metres(30.0)), b);
+        assert_eq!(Metres(30.0), b.magnitude());
+        assert_eq!(Degrees(90.0), b.track());
+        assert_eq!(
+            b,
+            velocity.horizontal() * Seconds(10.0) + -Displacement2d::new(Metres(20.0), Metres(0.0))
+        );
+        assert_eq!(Displacement3d::default(), a + -a);
+    }
+}
@@ -0,0 +1,196 @@
+// Copyright (c) 2024 Ken Barker
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"),
+// to deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+// THE SOFTWARE.
+
+//! [wasm-bindgen](https://crates.io/crates/wasm-bindgen) bindings for the
+//! distance, speed and angle unit types, for web based applications.
+//!
+//! The types are exported as JavaScript classes with a constructor,
+//! a `value` property and conversion methods, e.g.:
+//!
+//! ```js
+//! const distance = new NauticalMiles(10.0);
+//! const metres = distance.toMetres();
+//! console.log(metres.value); // 18520
+//! ```
+//!
+//! wasm-bindgen cannot export the generic `Quantity` type that the unit types
+//! are aliases of, so each JavaScript class wraps a unit value.
+
+// wasm-bindgen does not support const functions
+#![allow(clippy::missing_const_for_fn)]
+
+use crate::{
+    non_si::{Degrees, Feet, Knots, NauticalMiles},
+    si::{Metres, MetresPerSecond, Radians},
+};
+use wasm_bindgen::prelude::*;
+
+/// Defines a JavaScript class named `$t` wrapping a unit type, with a
+/// constructor and a `value` property.
+macro_rules! impl_wasm {
+    ($($js:ident($t:ident)),*) => {
+        $(
+            #[doc = concat!("The JavaScript `", stringify!($t), "` class.")]
+            #[wasm_bindgen(js_name = $t)]
+            #[derive(Clone, Copy, Debug, PartialEq)]
+            pub struct $js($t);
+
+            #[wasm_bindgen(js_class = $t)]
+            impl $js {
+                /// Construct a unit value from a JavaScript number.
+                #[wasm_bindgen(constructor)]
+                #[must_use]
+                pub fn js_new(value: f64) -> Self {
+                    Self($t(value))
+                }
+
+                /// The value of the unit as a JavaScript number.
+                #[wasm_bindgen(getter = value)]
+                #[must_use]
+                pub fn js_value(&self) -> f64 {
+                    self.0 .0
+                }
+            }
+
+            impl From<$t> for $js {
+                fn from(a: $t) -> Self {
+                    Self(a)
+                }
+            }
+
+            impl From<$js> for $t {
+                fn from(a: $js) -> Self {
+                    a.0
+                }
+            }
+        )*
+    };
+}
+
+impl_wasm!(
+    JsMetres(Metres),
+    JsMetresPerSecond(MetresPerSecond),
+    JsRadians(Radians),
+    JsNauticalMiles(NauticalMiles),
+    JsFeet(Feet),
+    JsKnots(Knots),
+    JsDegrees(Degrees)
+);
+
+/// Implements JavaScript conversion methods between a non-SI unit class and
+/// its SI equivalent.
+macro_rules! impl_wasm_conversion {
+    (
+        $js:ident($t:ident),
+        $js_si:ident($si:ident),
+        $to_si:ident,
+        $js_to_si:literal,
+        $to_non_si:ident,
+        $js_to_non_si:literal
+    ) => {
+        #[wasm_bindgen(js_class = $t)]
+        impl $js {
+            #[doc = concat!("Convert to `", stringify!($si), "`.")]
+            #[wasm_bindgen(js_name = $js_to_si)]
+            #[must_use]
+            pub fn $to_si(&self) -> $js_si {
+                $js_si($si::from(self.0))
+            }
+        }
+
+        #[wasm_bindgen(js_class = $si)]
+        impl $js_si {
+            #[doc = concat!("Convert to `", stringify!($t), "`.")]
+            #[wasm_bindgen(js_name = $js_to_non_si)]
+            #[must_use]
+            pub fn $to_non_si(&self) -> $js {
+                $js($t::from(self.0))
+            }
+        }
+    };
+}
+
+impl_wasm_conversion!(
+    JsNauticalMiles(NauticalMiles),
+    JsMetres(Metres),
+    js_to_metres,
+    "toMetres",
+    js_to_nautical_miles,
+    "toNauticalMiles"
+);
+impl_wasm_conversion!(
+    JsFeet(Feet),
+    JsMetres(Metres),
+    js_to_metres,
+    "toMetres",
+    js_to_feet,
+    "toFeet"
+);
+impl_wasm_conversion!(
+    JsKnots(Knots),
+    JsMetresPerSecond(MetresPerSecond),
+    js_to_metres_per_second,
+    "toMetresPerSecond",
+    js_to_knots,
+    "toKnots"
+);
+impl_wasm_conversion!(
+    JsDegrees(Degrees),
+    JsRadians(Radians),
+    js_to_radians,
+    "toRadians",
+    js_to_degrees,
+    "toDegrees"
+);
+
+#[cfg(test)]
+#[allow(clippy::float_cmp)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_wasm() {
+        let distance = JsNauticalMiles::js_new(10.0);
+        assert_eq!(10.0, distance.js_value());
+        let metres = distance.js_to_metres();
+        assert_eq!(18_520.0, metres.js_value());
+        assert_eq!(distance, metres.js_to_nautical_miles());
+
+        let altitude = JsFeet::js_new(1000.0);
+        assert_eq!(Metres(304.8), altitude.js_to_metres().into());
+        assert_eq!(
+            Feet(999.999_999_999_999_9),
+            JsMetres::from(Metres(304.8)).js_to_feet().into()
+        );
+
+        let speed = JsKnots::js_new(300.0);
+        assert_eq!(
+            Knots(300.0),
+            speed.js_to_metres_per_second().js_to_knots().into()
+        );
+
+        let angle = JsDegrees::js_new(180.0);
+        assert_eq!(Radians(core::f64::consts::PI), angle.js_to_radians().into());
+        assert_eq!(
+            angle,
+            JsRadians::from(Radians(core::f64::consts::PI)).js_to_degrees()
+        );
+    }
+}
@@ -0,0 +1,241 @@
+// Copyright (c) 2024 Ken Barker
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"),
+// to deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+// THE SOFTWARE.
+
+//! The wind triangle.
+//!
+//! The wind triangle relates an aircraft's heading and true airspeed (TAS),
+//! its track and ground speed and the wind. Wind directions are the
+//! direction that the wind blows from, as reported in meteorological
+//! messages, and all directions must be referenced to the same north, e.g.:
+//!
+//! ```
+//! # #[cfg(any(feature = "std", feature = "libm"))]
+//! # {
+//! use icao_units::non_si::{Degrees, Knots};
+//! use icao_units::wind::{wind_triangle, Wind};
+//!
+//! // a 20 kt headwind
+//! let wind = Wind { direction: Degrees(90.0), speed: Knots(20.0) };
+//! let solution = wind_triangle(Knots(250.0), Degrees(90.0), wind).unwrap();
+//! assert_eq!(Degrees(90.0), solution.heading);
+//! assert_eq!(Knots(230.0), solution.ground_speed);
+//! # }
+//! ```
+
+#[cfg(any(feature = "std", feature = "libm"))]
+use crate::math;
+use crate::non_si::{Degrees, Knots};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// A wind: the direction that it blows from and its speed.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Wind {
+    /// The direction that the wind blows from.
+    pub direction: Degrees,
+    /// The wind speed.
+    pub speed: Knots,
+}
+
+/// The heading and ground speed required to fly a track.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct WindTriangle {
+    /// The heading to fly, in the range [0, 360).
+    pub heading: Degrees,
+    /// The ground speed along the track.
+    pub ground_speed: Knots,
+    /// The wind correction angle: the heading minus the track,
+    /// positive to the right of the track.
+    pub wind_correction_angle: Degrees,
+}
+
+impl WindTriangle {
+    /// The drift angle: the track minus the heading,
+    /// positive if the wind drifts the aircraft to the right of its heading.
+    #[must_use]
+    pub fn drift_angle(self) -> Degrees {
+        -self.wind_correction_angle
+    }
+}
+
+/// Solve the wind triangle for the heading to fly and the ground speed
+/// along a `track` at true airspeed `tas` in a `wind`.
+///
+/// The `track` may be any angle type that converts to `Degrees`, e.g.
+/// `DegreesTrue` or an `angle_sc::Angle`.
+///
+/// Returns `None` if `tas` is not positive or if the aircraft cannot
+/// maintain the track because the crosswind or headwind exceeds its airspeed.
+#[cfg(any(feature = "std", feature = "libm"))]
+#[must_use]
+pub fn wind_triangle(tas: Knots, track: impl Into<Degrees>, wind: Wind) -> Option<WindTriangle> {
+    if tas.0 <= 0.0 || tas.0.is_nan() {
+        return None;
+    }
+
+    let track = track.into();
+    let wind_angle = (wind.direction - track).to_radians().0;
+    let crosswind = wind.speed.0 * math::sin(wind_angle);
+    let headwind = wind.speed.0 * math::cos(wind_angle);
+    if math::abs(crosswind) > tas.0 {
+        return None;
+    }
+
+    let correction = math::asin(crosswind / tas.0);
+    let ground_speed = math::mul_add(tas.0, math::cos(correction), -headwind);
+    if ground_speed < 0.0 {
+        return None;
+    }
+
+    let wind_correction_angle = Degrees(correction.to_degrees());
+    Some(WindTriangle {
+        heading: (track + wind_correction_angle).normalise(),
+        ground_speed: Knots(ground_speed),
+        wind_correction_angle,
+    })
+}
+
+/// Solve the wind triangle for the wind, given the aircraft's `heading`
+/// and true airspeed `tas` and its `track` and `ground_speed`.
+///
+/// The `heading` and `track` may be any angle types that convert to
+/// `Degrees`, e.g. `DegreesTrue` or an `angle_sc::Angle`.
+#[cfg(any(feature = "std", feature = "libm"))]
+#[must_use]
+pub fn solve_wind(
+    tas: Knots,
+    heading: impl Into<Degrees>,
+    ground_speed: Knots,
+    track: impl Into<Degrees>,
+) -> Wind {
+    let heading = heading.into().to_radians().0;
+    let track = track.into().to_radians().0;
+    // the components of the vector that the wind blows towards
+    let north = math::mul_add(
+        ground_speed.0,
+        math::cos(track),
+        -tas.0 * math::cos(heading),
+    );
+    let east = math::mul_add(
+        ground_speed.0,
+        math::sin(track),
+        -tas.0 * math::sin(heading),
+    );
+    Wind {
+        direction: Degrees(math::atan2(-east, -north).to_degrees()).normalise(),
+        speed: Knots(math::hypot(north, east)),
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::float_cmp)]
+mod tests {
+    #[cfg(any(feature = "std", feature = "libm"))]
+    use super::*;
+    #[cfg(any(feature = "std", feature = "libm"))]
+    use crate::heading::DegreesTrue;
+
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[test]
+    fn test_wind_triangle() {
+        // a 50 kt wind from the right at 100 kt
+        let wind = Wind {
+            direction: Degrees(90.0),
+            speed: Knots(50.0),
+        };
+        let solution = wind_triangle(Knots(100.0), Degrees(0.0), wind).unwrap();
+        assert!((solution.wind_correction_angle.0 - 30.0).abs() < 1e-12);
+        assert!((solution.heading.0 - 30.0).abs() < 1e-12);
+        assert!((solution.ground_speed.0 - 86.602_540_378_443_86).abs() < 1e-12);
+        assert_eq!(-solution.wind_correction_angle, solution.drift_angle());
+
+        // a wind from the left, across north
+        let wind = Wind {
+            direction: Degrees(270.0),
+            speed: Knots(50.0),
+        };
+        let solution = wind_triangle(Knots(100.0), Degrees(0.0), wind).unwrap();
+        assert!((solution.heading.0 - 330.0).abs() < 1e-12);
+
+        // a tailwind
+        let wind = Wind {
+            direction: Degrees(180.0),
+            speed: Knots(20.0),
+        };
+        let solution = wind_triangle(Knots(100.0), Degrees(0.0), wind).unwrap();
+        assert!(solution.heading.difference(Degrees(0.0)).0.abs() < 1e-12);
+        assert!((solution.ground_speed.0 - 120.0).abs() < 1e-12);
+
+        // calm
+        let solution = wind_triangle(Knots(100.0), Degrees(45.0), Wind::default()).unwrap();
+        assert_eq!(Degrees(45.0), solution.heading);
+        assert_eq!(Knots(100.0), solution.ground_speed);
+
+        // too strong
+        let wind = Wind {
+            direction: Degrees(90.0),
+            speed: Knots(101.0),
+        };
+        assert_eq!(None, wind_triangle(Knots(100.0), Degrees(0.0), wind));
+        let wind = Wind {
+            direction: Degrees(0.0),
+            speed: Knots(101.0),
+        };
+        assert_eq!(None, wind_triangle(Knots(100.0), Degrees(0.0), wind));
+
+        // no airspeed
+        assert_eq!(
+            None,
+            wind_triangle(Knots(0.0), Degrees(0.0), Wind::default())
+        );
+        assert_eq!(None, wind_triangle(Knots(f64::NAN), Degrees(0.0), wind));
+
+        // a true track
+        let solution = wind_triangle(Knots(100.0), DegreesTrue(45.0), Wind::default()).unwrap();
+        assert_eq!(Degrees(45.0), solution.heading);
+    }
+
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[test]
+    fn test_solve_wind() {
+        let wind = Wind {
+            direction: Degrees(240.0),
+            speed: Knots(35.0),
+        };
+        let track = Degrees(310.0);
+        let solution = wind_triangle(Knots(180.0), track, wind).unwrap();
+        let result = solve_wind(Knots(180.0), solution.heading, solution.ground_speed, track);
+        assert!((wind.direction.0 - result.direction.0).abs() < 1e-9);
+        assert!((wind.speed.0 - result.speed.0).abs() < 1e-9);
+
+        let calm = solve_wind(Knots(180.0), track, Knots(180.0), track);
+        assert!(calm.speed.0 < 1e-12);
+
+        let calm = solve_wind(
+            Knots(180.0),
+            DegreesTrue(310.0),
+            Knots(180.0),
+            DegreesTrue(310.0),
+        );
+        assert!(calm.speed.0 < 1e-12);
+    }
+}